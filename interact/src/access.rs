@@ -1,7 +1,10 @@
 /// This module defines the main traits used to dynamically operate and reflect on Rust types using Interact.
+use std::any::Any;
+use std::fmt;
 use std::sync::Arc;
 
 use crate::deser::Deser;
+use crate::schema::{AccessType, Schema, SchemaKind, Schemas};
 use crate::{deser, ClimbError, Climber, NodeTree, Reflector};
 
 /// The indirect Reflect allows indirect climber or reflector access, and meant to be used as a
@@ -36,6 +39,12 @@ pub trait ReflectDirect {
         &mut self,
         _climber: &mut Climber<'a>,
     ) -> Result<Option<NodeTree>, ClimbError>;
+
+    /// Expose the value as a single byte. This allows the `Reflector` to render buffers of `u8`
+    /// in one of the modes of `BytesMode`.
+    fn as_byte(&self) -> Option<u8> {
+        None
+    }
 }
 
 /// An arbitrar between the two possible way to climb into an immutable value.
@@ -46,7 +55,48 @@ pub enum Reflect<'a> {
 
 pub struct Function {
     pub name: &'static str,
-    pub args: &'static [&'static str],
+    pub args: &'static [Argument],
+
+    /// The name of the return type, if known.
+    pub ret: &'static str,
+
+    /// The doc comment of the method, if any.
+    pub doc: &'static str,
+}
+
+/// Describes an argument of a `Function`.
+pub struct Argument {
+    pub name: &'static str,
+
+    /// The name of the argument type, if known.
+    pub ty: &'static str,
+
+    /// An Interact expression for the value of the argument when it is omitted.
+    pub default: Option<&'static str>,
+}
+
+impl fmt::Display for Function {
+    /// Format the signature of the function, e.g. `add(param_a: u32) -> ()`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", arg.name)?;
+            if !arg.ty.is_empty() {
+                write!(f, ": {}", arg.ty)?;
+            }
+            if let Some(default) = arg.default {
+                write!(f, " = {}", default)?;
+            }
+        }
+        write!(f, ")")?;
+        if !self.ret.is_empty() {
+            write!(f, " -> {}", self.ret)?;
+        }
+        Ok(())
+    }
 }
 
 /// MutAccess adds function call information over `ReflectMut`.
@@ -98,6 +148,14 @@ pub enum AssignError {
 
     /// Other values are immutable, such as reference values.
     Immutable,
+
+    /// The value was rejected by the validation of the field, given by the `validate` or `range`
+    /// attributes.
+    Invalid(String),
+
+    /// The field is validated, and can only be modified by assigning it as a whole, as modifying
+    /// a part of it would bypass the validation.
+    Checked,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -111,13 +169,42 @@ pub enum CallError {
 
     /// The called function does not exist.
     NoSuchFunction,
+
+    /// The path given for the argument does not lead to an object.
+    ObjectNotFound(&'static str),
+
+    /// The path given for the argument leads into the object whose function is called.
+    ObjectBorrowed(&'static str),
+
+    /// The object given for the argument is not of the argument type.
+    ObjectType(&'static str),
+
+    /// The argument is taken by value, and its type does not implement `Clone`.
+    ObjectNotCloneable(&'static str),
+
+    /// The argument type cannot be parsed from a value, and a path to an object is required.
+    ObjectRequired(&'static str),
+
+    /// An index given as an argument, or implied by the function such as for `first()` of an
+    /// empty collection, is out of range.
+    OutOfRange,
+
+    /// The function would modify a validated field, which can only be modified by assigning it as
+    /// a whole.
+    Checked,
+}
+
+impl From<deser::DeserError> for CallError {
+    fn from(err: deser::DeserError) -> Self {
+        CallError::Deser(err)
+    }
 }
 
 pub type RetValCallback<'a> = Box<dyn FnMut(&dyn Access, &mut Climber<'a>)>;
 
 /// The `Access` trait, meant to be used as a trait object, provides methods that
 /// dynamically expose read&write access to the underlying objects.
-pub trait Access {
+pub trait Access: AccessType {
     /// Expose an immmutable accessor, used when `Access` is immutable or mutable.
     fn immut_access(&self) -> ImmutAccess;
 
@@ -163,6 +250,56 @@ pub trait Access {
     ) -> Result<(), AssignError> {
         Err(AssignError::Unbuildable)
     }
+
+    /// Build a new value of the type, for assigning or inserting into a collection of it, such as
+    /// a `Vec<Self>`. This lets collections be assigned without requiring their items to implement
+    /// `Deser`. Implemented by `#[derive(Interact)]` and by the built-in types that implement
+    /// `Deser`; values of other types are unbuildable.
+    fn deser_new<'a, 'b>(_tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self>
+    where
+        Self: Sized,
+    {
+        Err(deser::DeserError::Unbuildable)
+    }
+
+    /// Expose the object as `Any`, so that it can be passed as an argument to functions by
+    /// reference or clone. Implemented by `#[derive(Interact)]` for types without generics.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// Describe the type, without reading any value of it. Types that are not described
+    /// otherwise are opaque. Implemented by `#[derive(Interact)]`.
+    fn describe(_schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        Schema::of::<Self>(SchemaKind::Opaque)
+    }
+}
+
+/// `Methods` exposes the methods of a type to Interact. It is implemented by the
+/// `#[interact::methods]` attribute on an `impl` block, and used by `#[derive(Interact)]` for
+/// types having the `#[interact(methods)]` attribute.
+pub trait Methods {
+    /// The description of the exposed methods.
+    fn functions() -> &'static [Function];
+
+    /// Call a method taking `&self`, like `Access::immut_call` does.
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError>;
+
+    /// Call a method taking either `&self` or `&mut self`, like `Access::mut_call` does.
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError>;
 }
 
 macro_rules! mut_assign_deser {
@@ -174,7 +311,35 @@ macro_rules! mut_assign_deser {
         ) -> Result<(), AssignError> {
             crate::access::deser_assign(self, tracker, probe_only)
         }
-    }
+
+        fn deser_new<'x, 'y>(tracker: &mut deser::Tracker<'x, 'y>) -> deser::Result<Self>
+        where
+            Self: Sized,
+        {
+            <Self as deser::Deser>::deser(tracker)
+        }
+    };
+}
+
+/// Like `mut_assign_deser!`, for collections whose items are built using `Access::deser_new`.
+macro_rules! mut_assign_new {
+    () => {
+        fn mut_assign<'x, 'y>(
+            &mut self,
+            tracker: &mut deser::Tracker<'x, 'y>,
+            probe_only: bool,
+        ) -> Result<(), AssignError> {
+            match Self::deser_new(tracker) {
+                Ok(v) => {
+                    if !probe_only {
+                        *self = v;
+                    }
+                    Ok(())
+                }
+                Err(e) => Err(AssignError::Deser(e)),
+            }
+        }
+    };
 }
 
 /// A helper for the specific implementations of `Access` to use with `mut_assign` methods
@@ -196,14 +361,24 @@ pub fn deser_assign<'a, 'b, T: Deser>(
 
 mod basic;
 mod btreemap;
+mod builtin;
+pub mod bytes;
+pub mod check;
 mod derefs;
 pub mod derive;
 mod explicit;
+pub mod format;
 mod hashmap;
 mod hashset;
 mod instant;
 pub mod iter;
+pub mod leaf;
 mod mutex;
+pub mod object;
 mod refcell;
+pub mod remote;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod tuple;
 pub mod vec;
+pub mod view;
@@ -8,8 +8,24 @@ use crate::reflector::Reflector;
 
 use interact_derive::derive_interact_basic;
 
+/// Render a string as the reflection of `String` does.
+pub(crate) fn render_str(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+/// Render a character as the reflection of `char` does.
+pub(crate) fn render_char(c: char) -> String {
+    format!("{:?}", c)
+}
+
 macro_rules! simple {
     ($a:tt, $fmt:expr) => {
+        simple!($a, $fmt, {});
+    };
+    ($a:tt, $fmt:expr, { $($extra:tt)* }) => {
+        simple!(@render $a, |v: &$a| format!($fmt, v), { $($extra)* });
+    };
+    (@render $a:tt, $render:expr, { $($extra:tt)* }) => {
         derive_interact_basic! {
             #[interact(mut_assign)]
             struct $a;
@@ -22,7 +38,7 @@ macro_rules! simple {
                     Ok(v) => return v,
                     Err(meta) => meta,
                 };
-                NodeInfo::Leaf(Cow::Owned(format!($fmt, self))).with_meta(meta)
+                NodeInfo::Leaf(Cow::Owned(($render)(self))).with_meta(meta)
             }
 
             fn immut_climber<'a>(
@@ -38,6 +54,8 @@ macro_rules! simple {
             ) -> Result<Option<NodeTree>, ClimbError> {
                 Ok(None)
             }
+
+            $($extra)*
         }
     };
 }
@@ -46,11 +64,15 @@ simple!(usize, "{}");
 simple!(u64, "{}");
 simple!(u32, "{}");
 simple!(u16, "{}");
-simple!(u8, "{}");
+simple!(u8, "{}", {
+    fn as_byte(&self) -> Option<u8> {
+        Some(*self)
+    }
+});
 simple!(isize, "{}");
 simple!(bool, "{}");
-simple!(String, "{:?}");
-simple!(char, "{:?}");
+simple!(@render String, |v: &String| render_str(v), {});
+simple!(@render char, |v: &char| render_char(*v), {});
 simple!(i64, "{}");
 simple!(i32, "{}");
 simple!(i16, "{}");
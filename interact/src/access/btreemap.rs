@@ -1,11 +1,17 @@
+use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use crate::access::{iter::ReflectIter, Access, ReflectDirect};
+use crate::access::builtin::{self, Item, MAP_FUNCTIONS};
+use crate::access::{
+    iter::ReflectIter, Access, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect,
+    ReflectMut, RetValCallback,
+};
 use crate::climber::{ClimbError, Climber};
 use crate::deser;
 use crate::node_tree::NodeTree;
 use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
 
 impl<'a, K, V> ReflectIter<(&'a dyn Access, &'a dyn Access)>
     for std::collections::btree_map::Iter<'a, K, V>
@@ -35,12 +41,13 @@ where
         if !climber.open_bracket() {
             return Ok(None);
         }
+        climber.complete_keys(self.keys().map(|key| key as &dyn Access))?;
 
         let v = K::deser(&mut climber.borrow_tracker())
             .map(|x| <BTreeMap<K, V>>::get(self, &x))
             .map(|x| x.map(|y| y as &dyn Access));
         let v = match v {
-            Ok(None) => return Err(ClimbError::NotFound),
+            Ok(None) => return Err(climber.missing_immut()),
             Err(err) => return Err(ClimbError::DeserError(err)),
             Ok(Some(v)) => v,
         };
@@ -57,18 +64,21 @@ where
         if !climber.open_bracket() {
             return Ok(None);
         }
+        climber.complete_keys(self.keys().map(|key| key as &dyn Access))?;
 
-        let v = match K::deser(&mut climber.borrow_tracker()) {
-            Ok(x) => Ok(match <BTreeMap<K, V>>::get_mut(self, &x) {
-                None => None,
-                Some(x) => Some(x),
-            }),
-            Err(e) => Err(e),
-        };
-        let v = match v {
-            Ok(None) => return Err(ClimbError::NotFound),
+        let key = match K::deser(&mut climber.borrow_tracker()) {
+            Ok(key) => key,
             Err(err) => return Err(ClimbError::DeserError(err)),
-            Ok(Some(v)) => v,
+        };
+        let v = match self.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                return climber
+                    .missing_mut(|value| {
+                        entry.insert(value);
+                    })
+                    .map(Some);
+            }
         };
 
         climber.close_bracket()?;
@@ -77,11 +87,86 @@ where
     }
 }
 
-use interact_derive::derive_interact_opaque;
+impl<K, V> Access for BTreeMap<K, V>
+where
+    K: Eq + Ord + Access + deser::Deser,
+    V: Access,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess {
+            reflect: Reflect::Direct(self),
+            functions: MAP_FUNCTIONS,
+        }
+    }
 
-derive_interact_opaque! {
-    #[interact(immut_fn(len()))]
-    struct BTreeMap<K, V>
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess {
+            reflect: ReflectMut::Direct(self),
+            functions: MAP_FUNCTIONS,
+        }
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        match func_name {
+            "len" => {
+                builtin::no_args(climber, &builtin::LEN)?;
+                builtin::ret(climber, retcall, || Ok(self.len()))
+            }
+            "is_empty" => {
+                builtin::no_args(climber, &builtin::IS_EMPTY)?;
+                builtin::ret(climber, retcall, || Ok(self.is_empty()))
+            }
+            "contains" => {
+                let key: K = builtin::arg(climber, &builtin::CONTAINS_KEY)?;
+                builtin::ret(climber, retcall, || Ok(self.contains_key(&key)))
+            }
+            "keys" => {
+                builtin::no_args(climber, &builtin::KEYS)?;
+                let keys: Vec<&K> = self.keys().collect();
+                builtin::ret(climber, retcall, || Ok(&keys[..]))
+            }
+            _ if builtin::is_mut(func_name) => Err(CallError::NeedMutable),
+            _ => Err(CallError::NoSuchFunction),
+        }
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        match func_name {
+            "insert" => {
+                let (key, Item(value)) = builtin::args2(climber, &builtin::INSERT_ENTRY)?;
+                builtin::ret(climber, retcall, || Ok(self.insert(key, value).map(Item)))
+            }
+            "remove" => {
+                let key: K = builtin::arg(climber, &builtin::REMOVE_ENTRY)?;
+                builtin::ret(climber, retcall, || Ok(self.remove(&key).map(Item)))
+            }
+            "clear" => {
+                builtin::no_args(climber, &builtin::CLEAR)?;
+                builtin::ret(climber, retcall, || {
+                    self.clear();
+                    Ok(())
+                })
+            }
+            _ => self.immut_call(func_name, climber, retcall),
+        }
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
     where
-        K: Eq + Ord + deser::Deser;
+        Self: Sized,
+    {
+        let keys = Box::new(K::describe(schemas));
+        let values = Box::new(V::describe(schemas));
+        Schema::of::<Self>(SchemaKind::Map(keys, values)).with_functions(MAP_FUNCTIONS)
+    }
 }
@@ -0,0 +1,226 @@
+//! Functions that are provided for standard collections, such as `len()` and `push(value)`.
+
+use std::any::Any;
+
+use crate::access::{
+    Access, Argument, AssignError, CallError, Function, ImmutAccess, MutAccess, RetValCallback,
+};
+use crate::climber::Climber;
+use crate::deser::{self, Deser};
+use crate::schema::{Schema, Schemas};
+
+macro_rules! builtin_fn {
+    ($name:ident, $str:expr, [$($arg:expr => $ty:expr),*], $ret:expr) => {
+        pub(crate) const $name: Function = Function {
+            name: $str,
+            args: &[$(Argument { name: $arg, ty: $ty, default: None }),*],
+            ret: $ret,
+            doc: "",
+        };
+    };
+}
+
+builtin_fn!(LEN, "len", [], "usize");
+builtin_fn!(IS_EMPTY, "is_empty", [], "bool");
+builtin_fn!(FIRST, "first", [], "T");
+builtin_fn!(LAST, "last", [], "T");
+builtin_fn!(CLEAR, "clear", [], "()");
+builtin_fn!(PUSH, "push", ["value" => "T"], "()");
+builtin_fn!(POP, "pop", [], "Option<T>");
+builtin_fn!(INSERT_AT, "insert", ["index" => "usize", "value" => "T"], "()");
+builtin_fn!(REMOVE_AT, "remove", ["index" => "usize"], "T");
+builtin_fn!(INSERT_ENTRY, "insert", ["key" => "K", "value" => "V"], "Option<V>");
+builtin_fn!(REMOVE_ENTRY, "remove", ["key" => "K"], "Option<V>");
+builtin_fn!(CONTAINS_KEY, "contains", ["key" => "K"], "bool");
+builtin_fn!(KEYS, "keys", [], "Vec<K>");
+builtin_fn!(INSERT_VALUE, "insert", ["value" => "T"], "bool");
+builtin_fn!(REMOVE_VALUE, "remove", ["value" => "T"], "bool");
+builtin_fn!(CONTAINS_VALUE, "contains", ["value" => "T"], "bool");
+
+/// The functions of slices and arrays.
+pub(crate) const SLICE_FUNCTIONS: &[Function] = &[LEN, IS_EMPTY, FIRST, LAST];
+
+/// The functions of `Vec`.
+pub(crate) const VEC_FUNCTIONS: &[Function] = &[
+    LEN, IS_EMPTY, FIRST, LAST, PUSH, POP, INSERT_AT, REMOVE_AT, CLEAR,
+];
+
+/// The functions of `HashMap` and `BTreeMap`.
+pub(crate) const MAP_FUNCTIONS: &[Function] = &[
+    LEN,
+    IS_EMPTY,
+    CONTAINS_KEY,
+    KEYS,
+    INSERT_ENTRY,
+    REMOVE_ENTRY,
+    CLEAR,
+];
+
+/// The functions of `HashSet`.
+pub(crate) const SET_FUNCTIONS: &[Function] = &[
+    LEN,
+    IS_EMPTY,
+    CONTAINS_VALUE,
+    INSERT_VALUE,
+    REMOVE_VALUE,
+    CLEAR,
+];
+
+/// An item of a collection, built using `Access::deser_new`, so that it can be parsed as an
+/// argument and returned in an `Option` without its type implementing `Deser`.
+pub(crate) struct Item<T>(pub T);
+
+impl<T: Access> Deser for Item<T> {
+    fn deser<'a, 'b>(tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self> {
+        T::deser_new(tracker).map(Item)
+    }
+}
+
+impl<T: Access> Access for Item<T> {
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        self.0.immut_access()
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        self.0.mut_access()
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.0.immut_call(func_name, climber, retcall)
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.0.mut_call(func_name, climber, retcall)
+    }
+
+    fn mut_assign<'a, 'b>(
+        &mut self,
+        tracker: &mut deser::Tracker<'a, 'b>,
+        probe_only: bool,
+    ) -> Result<(), AssignError> {
+        self.0.mut_assign(tracker, probe_only)
+    }
+
+    fn deser_new<'a, 'b>(tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self> {
+        T::deser_new(tracker).map(Item)
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        self.0.as_any()
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema {
+        T::describe(schemas)
+    }
+}
+
+/// Whether the function modifies the collection, and therefore needs a mutable path.
+pub(crate) fn is_mut(func_name: &str) -> bool {
+    matches!(func_name, "push" | "pop" | "insert" | "remove" | "clear")
+}
+
+/// Parse the single argument of a function.
+pub(crate) fn arg<A: Deser>(
+    climber: &mut Climber<'_>,
+    function: &Function,
+) -> Result<A, CallError> {
+    let mut value = None;
+    deser::deser_args(
+        &mut climber.borrow_tracker(),
+        function.args,
+        &mut |_, tracker| {
+            value = Some(A::deser(tracker)?);
+            Ok(())
+        },
+    )?;
+
+    value.ok_or(CallError::Deser(deser::DeserError::EndOfTokenList))
+}
+
+/// Parse the two arguments of a function.
+pub(crate) fn args2<A: Deser, B: Deser>(
+    climber: &mut Climber<'_>,
+    function: &Function,
+) -> Result<(A, B), CallError> {
+    let mut values = (None, None);
+    deser::deser_args(
+        &mut climber.borrow_tracker(),
+        function.args,
+        &mut |idx, tracker| {
+            match idx {
+                0 => values.0 = Some(A::deser(tracker)?),
+                _ => values.1 = Some(B::deser(tracker)?),
+            }
+            Ok(())
+        },
+    )?;
+
+    match values {
+        (Some(a), Some(b)) => Ok((a, b)),
+        _ => Err(CallError::Deser(deser::DeserError::EndOfTokenList)),
+    }
+}
+
+/// Check that a function is called without arguments.
+pub(crate) fn no_args(climber: &mut Climber<'_>, function: &Function) -> Result<(), CallError> {
+    deser::deser_args(&mut climber.borrow_tracker(), function.args, &mut |_, _| {
+        Ok(())
+    })
+}
+
+/// Unless probing, call `f` and hand its return value to the callback.
+pub(crate) fn ret<'a, R: Access>(
+    climber: &mut Climber<'a>,
+    mut retcall: RetValCallback<'a>,
+    f: impl FnOnce() -> Result<R, CallError>,
+) -> Result<(), CallError> {
+    if !climber.is_probe_only() {
+        let retval = f()?;
+        retcall(&retval, climber);
+    }
+
+    Ok(())
+}
+
+/// Call one of `SLICE_FUNCTIONS`.
+pub(crate) fn slice_call<'a, T: Access>(
+    slice: &[T],
+    func_name: &'static str,
+    climber: &mut Climber<'a>,
+    retcall: RetValCallback<'a>,
+) -> Result<(), CallError> {
+    match func_name {
+        "len" => {
+            no_args(climber, &LEN)?;
+            ret(climber, retcall, || Ok(slice.len()))
+        }
+        "is_empty" => {
+            no_args(climber, &IS_EMPTY)?;
+            ret(climber, retcall, || Ok(slice.is_empty()))
+        }
+        "first" => {
+            no_args(climber, &FIRST)?;
+            ret(climber, retcall, || {
+                slice.first().ok_or(CallError::OutOfRange)
+            })
+        }
+        "last" => {
+            no_args(climber, &LAST)?;
+            ret(climber, retcall, || {
+                slice.last().ok_or(CallError::OutOfRange)
+            })
+        }
+        _ if is_mut(func_name) => Err(CallError::NeedMutable),
+        _ => Err(CallError::NoSuchFunction),
+    }
+}
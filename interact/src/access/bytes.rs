@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use crate::access::view::Render;
+use crate::node_tree::NodeInfo;
+
+/// The ways in which a buffer of bytes, such as `Vec<u8>` or `[u8; 32]`, can be rendered.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum BytesMode {
+    /// A bracketed list of decimal numbers, like any other `Vec` or array.
+    #[default]
+    List,
+
+    /// A hex dump with offsets and printable characters, similar to `hexdump -C`.
+    HexDump,
+
+    /// A compact hex string, e.g. `x"deadbeef"`.
+    Hex,
+
+    /// A base64 encoded string.
+    Base64,
+
+    /// A string, decoded from UTF-8 with invalid sequences replaced.
+    Utf8Lossy,
+}
+
+const MODES: &[(&str, BytesMode)] = &[
+    ("list", BytesMode::List),
+    ("hexdump", BytesMode::HexDump),
+    ("hex", BytesMode::Hex),
+    ("base64", BytesMode::Base64),
+    ("utf8", BytesMode::Utf8Lossy),
+];
+
+const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl BytesMode {
+    /// The names by which the modes are selected, for example in the `bytes` field attribute.
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        MODES.iter().map(|(name, _)| *name)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        MODES
+            .iter()
+            .find(|(mode_name, _)| *mode_name == name)
+            .map(|(_, mode)| *mode)
+    }
+
+    pub fn name(self) -> &'static str {
+        MODES.iter().find(|(_, mode)| *mode == self).unwrap().0
+    }
+
+    pub fn render(self, bytes: &[u8]) -> NodeInfo {
+        match self {
+            BytesMode::List => {
+                let v = bytes
+                    .iter()
+                    .map(|b| NodeInfo::Leaf(Cow::Owned(format!("{}", b))).into_node())
+                    .collect();
+                NodeInfo::Grouped('[', Box::new(NodeInfo::Delimited(',', v).into_node()), ']')
+            }
+            BytesMode::HexDump => {
+                if bytes.is_empty() {
+                    return NodeInfo::Leaf(Cow::Borrowed("[]"));
+                }
+                NodeInfo::Lines(hex_dump(bytes).into_iter().map(Cow::Owned).collect())
+            }
+            BytesMode::Hex => {
+                let mut s = String::from("x\"");
+                for b in bytes {
+                    s.push_str(&format!("{:02x}", b));
+                }
+                s.push('"');
+                NodeInfo::Leaf(Cow::Owned(s))
+            }
+            BytesMode::Base64 => NodeInfo::Leaf(Cow::Owned(format!("{:?}", base64(bytes)))),
+            BytesMode::Utf8Lossy => {
+                NodeInfo::Leaf(Cow::Owned(format!("{:?}", String::from_utf8_lossy(bytes))))
+            }
+        }
+    }
+}
+
+fn hex_dump(bytes: &[u8]) -> Vec<String> {
+    let mut lines = vec![];
+
+    for (idx, chunk) in bytes.chunks(16).enumerate() {
+        let mut line = format!("{:08x} ", idx * 16);
+
+        for i in 0..16 {
+            if i % 8 == 0 {
+                line.push(' ');
+            }
+            match chunk.get(i) {
+                Some(b) => line.push_str(&format!("{:02x} ", b)),
+                None => line.push_str("   "),
+            }
+        }
+
+        line.push_str(" |");
+        for b in chunk {
+            line.push(if b.is_ascii_graphic() || *b == b' ' {
+                *b as char
+            } else {
+                '.'
+            });
+        }
+        line.push('|');
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut s = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(BASE64_CHARS[((n >> (18 - i * 6)) & 0x3f) as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+macro_rules! bytes_render {
+    ($name:ident, $mode:ident) => {
+        /// Marker for rendering of byte buffers via `View`, see `BytesMode`.
+        pub struct $name;
+
+        impl<T> Render<T> for $name
+        where
+            T: AsRef<[u8]>,
+        {
+            fn render(value: &T) -> NodeInfo {
+                BytesMode::$mode.render(value.as_ref())
+            }
+        }
+    };
+}
+
+bytes_render!(HexDump, HexDump);
+bytes_render!(Hex, Hex);
+bytes_render!(Base64, Base64);
+bytes_render!(Utf8Lossy, Utf8Lossy);
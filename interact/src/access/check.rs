@@ -0,0 +1,148 @@
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+use std::sync::Arc;
+
+use crate::access::{
+    Access, AssignError, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+    RetValCallback,
+};
+use crate::climber::{ClimbError, Climber};
+use crate::deser::{self, Deser};
+use crate::node_tree::NodeTree;
+use crate::reflector::Reflector;
+use crate::schema::{Schema, Schemas};
+
+/// `Check` validates the values assigned to a field of type `T`, used by `Checked`.
+pub trait Check<T> {
+    /// Return a message describing why the value is rejected, if it is.
+    fn check(value: &T) -> Result<(), String>;
+
+    /// A hint about the accepted values, shown while the value is being typed.
+    fn hint() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Whether a value is within a range. Used by `#[derive(Interact)]` for fields having the `range`
+/// attribute, letting the type of the field decide the type of the bounds.
+pub fn in_range<T: PartialOrd>(range: impl RangeBounds<T>, value: &T) -> bool {
+    range.contains(value)
+}
+
+/// A transparent wrapper over a mutable value, checking the values assigned to it using `C`
+/// before they replace it. Parts of the value cannot be modified, and neither can functions be
+/// called on it via a mutable path, as the resulting value could not be checked before it
+/// replaces the current one.
+///
+/// This is used by `#[derive(Interact)]` for fields having the `validate` or `range` attributes.
+#[repr(transparent)]
+pub struct Checked<T, C> {
+    value: T,
+    check: PhantomData<fn() -> C>,
+}
+
+impl<T, C> Checked<T, C> {
+    pub fn wrap_mut(value: &mut T) -> &mut Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+impl<T, C> ReflectDirect for Checked<T, C>
+where
+    T: Access + Deser,
+    C: Check<T>,
+{
+    fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
+        Reflector::reflect(reflector, &self.value)
+    }
+
+    fn immut_climber<'a>(&self, climber: &mut Climber<'a>) -> Result<Option<NodeTree>, ClimbError> {
+        match self.value.immut_access().reflect {
+            Reflect::Direct(access) => access.immut_climber(climber),
+            Reflect::Indirect(_) => Ok(None),
+        }
+    }
+
+    fn mut_climber<'a>(
+        &mut self,
+        _climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        Err(ClimbError::AssignError(AssignError::Checked))
+    }
+}
+
+impl<T, C> Access for Checked<T, C>
+where
+    T: Access + Deser,
+    C: Check<T>,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess {
+            reflect: Reflect::Direct(self),
+            functions: self.value.immut_access().functions,
+        }
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        let (functions, immutable) = match self.value.mut_access() {
+            MutAccess {
+                reflect: ReflectMut::Immutable,
+                functions,
+            } => (functions, true),
+            MutAccess { functions, .. } => (functions, false),
+        };
+
+        MutAccess {
+            reflect: if immutable {
+                ReflectMut::Immutable
+            } else {
+                ReflectMut::Direct(self)
+            },
+            functions,
+        }
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.value.immut_call(func_name, climber, retcall)
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        _func_name: &'static str,
+        _climber: &mut Climber<'a>,
+        _retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        Err(CallError::Checked)
+    }
+
+    fn mut_assign<'a, 'b>(
+        &mut self,
+        tracker: &mut deser::Tracker<'a, 'b>,
+        probe_only: bool,
+    ) -> Result<(), AssignError> {
+        if let Some(hint) = C::hint() {
+            tracker.set_hint(hint);
+        }
+
+        let value = T::deser(tracker).map_err(AssignError::Deser)?;
+        C::check(&value).map_err(AssignError::Invalid)?;
+        if !probe_only {
+            self.value = value;
+        }
+
+        Ok(())
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use crate::access::{Access, AssignError, ImmutAccess, MutAccess, ReflectMut};
 use crate::deser::{self, Deser};
+use crate::schema::{Schema, Schemas};
 
 impl<'a, T: 'a> Access for &'a T
 where
@@ -25,6 +26,13 @@ where
     ) -> Result<(), AssignError> {
         Err(AssignError::Immutable)
     }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
 
 impl<'a, T: 'a> Access for &'a mut T
@@ -40,6 +48,13 @@ where
     }
 
     mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
 
 impl<T> Access for Box<T>
@@ -55,6 +70,13 @@ where
     }
 
     mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
 
 impl<T> Access for Rc<T>
@@ -73,6 +95,13 @@ where
     }
 
     mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
 
 impl<T> Access for Arc<T>
@@ -91,4 +120,11 @@ where
     }
 
     mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
@@ -25,10 +25,200 @@ pub trait ReflectStruct {
     fn get_field_by_idx(&self, idx: usize) -> Option<&dyn Access>;
     fn get_field_by_name_mut(&mut self, name: &'static str) -> Option<&mut dyn Access>;
     fn get_field_by_idx_mut(&mut self, idx: usize) -> Option<&mut dyn Access>;
+
+    /// The doc comment of the struct, or of the current variant for an enum.
+    fn get_doc(&self) -> &'static str {
+        ""
+    }
+
+    /// The doc comment of a field, given by its name, or by its index for a tuple struct.
+    fn get_field_doc(&self, _name: &str) -> &'static str {
+        ""
+    }
+
+    /// Whether a named field should be left out of the reflected output, for example when it
+    /// holds its default value and is marked with `#[interact(skip_if_default)]`.
+    fn is_field_hidden(&self, _name: &'static str) -> bool {
+        false
+    }
+
+    /// Names of the computed pseudo-fields, given by `#[interact(getter(name))]`, that follow the
+    /// real fields.
+    fn get_getters(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Call the getter method of a pseudo-field, passing its returned value to `f`. Returns
+    /// `false` if there is no such pseudo-field.
+    fn with_getter(&self, _name: &'static str, _f: &mut dyn FnMut(&dyn Access)) -> bool {
+        false
+    }
+
+    /// The struct held by a field having `#[interact(flatten)]`, whose fields are shown and
+    /// accessed as if they were fields of this struct.
+    fn get_flattened(&self, _name: &'static str) -> Option<&dyn ReflectStruct> {
+        None
+    }
+
+    /// Like `get_flattened`, for modifying the held struct.
+    fn get_flattened_mut(&mut self, _name: &'static str) -> Option<&mut dyn ReflectStruct> {
+        None
+    }
+}
+
+fn field_names(p_struct: &dyn ReflectStruct) -> &'static [&'static str] {
+    match p_struct.get_desc().kind {
+        StructKind::Fields(names) => names,
+        _ => &[],
+    }
+}
+
+/// The names of the fields shown for a struct, where each field having `#[interact(flatten)]`
+/// is replaced by the fields of the struct it holds.
+pub fn inline_field_names(p_struct: &dyn ReflectStruct) -> Vec<&'static str> {
+    let mut names = vec![];
+
+    for name in field_names(p_struct) {
+        match p_struct.get_flattened(name) {
+            Some(inner) => names.extend(inline_field_names(inner)),
+            None => names.push(*name),
+        }
+    }
+
+    names
+}
+
+/// The struct directly holding a field shown for a struct, being either the struct itself or a
+/// struct held by one of its flattened fields.
+pub fn inline_field_owner<'s>(
+    p_struct: &'s dyn ReflectStruct,
+    name: &str,
+) -> Option<&'s dyn ReflectStruct> {
+    for field in field_names(p_struct) {
+        match p_struct.get_flattened(field) {
+            Some(inner) => {
+                if let Some(owner) = inline_field_owner(inner, name) {
+                    return Some(owner);
+                }
+            }
+            None if *field == name => return Some(p_struct),
+            None => {}
+        }
+    }
+
+    None
+}
+
+/// Like `inline_field_owner`, for modifying the field.
+pub fn inline_field_owner_mut<'s>(
+    p_struct: &'s mut dyn ReflectStruct,
+    name: &str,
+) -> Option<&'s mut dyn ReflectStruct> {
+    let field = field_names(p_struct)
+        .iter()
+        .find(|field| match p_struct.get_flattened(field) {
+            Some(inner) => inline_field_owner(inner, name).is_some(),
+            None => **field == name,
+        })?;
+
+    if p_struct.get_flattened(field).is_none() {
+        return Some(p_struct);
+    }
+
+    inline_field_owner_mut(p_struct.get_flattened_mut(field)?, name)
+}
+
+/// The names of the fields of a struct deriving `Interact`, along with those of the structs held
+/// by its fields having `#[interact(flatten)]`.
+pub struct FieldSet {
+    pub names: &'static [&'static str],
+    pub flattened: &'static [&'static FieldSet],
+}
+
+/// Implemented by `#[derive(Interact)]` for structs having named fields, so that the names of
+/// flattened fields can be checked for collisions at compile time.
+pub trait StructFields {
+    const FIELDS: FieldSet;
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn count_fields(fields: &FieldSet) -> usize {
+    let mut count = fields.names.len();
+    let mut i = 0;
+    while i < fields.flattened.len() {
+        count += count_fields(fields.flattened[i]);
+        i += 1;
+    }
+
+    count
+}
+
+const fn nth_field(fields: &FieldSet, mut idx: usize) -> &'static str {
+    if idx < fields.names.len() {
+        return fields.names[idx];
+    }
+    idx -= fields.names.len();
+
+    let mut i = 0;
+    loop {
+        let count = count_fields(fields.flattened[i]);
+        if idx < count {
+            return nth_field(fields.flattened[i], idx);
+        }
+        idx -= count;
+        i += 1;
+    }
+}
+
+/// Whether the fields shown for a struct, including those of flattened structs, have unique
+/// names. Used by `#[derive(Interact)]` in a constant assertion.
+pub const fn has_unique_fields(fields: &FieldSet) -> bool {
+    let count = count_fields(fields);
+
+    let mut i = 0;
+    while i < count {
+        let mut j = i + 1;
+        while j < count {
+            if str_eq(nth_field(fields, i), nth_field(fields, j)) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    true
 }
 
 pub trait ReflectEnum {
     fn get_variant_desc(&self) -> Enum;
     fn get_variant_struct(&self) -> &dyn ReflectStruct;
     fn get_variant_struct_mut(&mut self) -> &mut dyn ReflectStruct;
+
+    /// The doc comment of the enum. That of the current variant is given by `get_doc` of the
+    /// variant struct.
+    fn get_enum_doc(&self) -> &'static str {
+        ""
+    }
+}
+
+/// Used by `#[derive(Interact)]` for fields having the `skip_if_default` attribute.
+pub fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
@@ -0,0 +1,27 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::access::view::Render;
+use crate::node_tree::NodeInfo;
+
+macro_rules! format_render {
+    ($name:ident, $trait:ident, $fmt:expr) => {
+        /// Marker for rendering of a value via `View`, using one of the `std::fmt` traits.
+        pub struct $name;
+
+        impl<T> Render<T> for $name
+        where
+            T: fmt::$trait,
+        {
+            fn render(value: &T) -> NodeInfo {
+                NodeInfo::Leaf(Cow::Owned(format!($fmt, value)))
+            }
+        }
+    };
+}
+
+format_render!(Hex, LowerHex, "{:#x}");
+format_render!(Binary, Binary, "{:#b}");
+format_render!(Octal, Octal, "{:#o}");
+format_render!(Debug, Debug, "{:?}");
+format_render!(Display, Display, "{}");
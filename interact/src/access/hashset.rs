@@ -3,10 +3,16 @@ use std::hash::BuildHasher;
 use std::hash::Hash;
 use std::sync::Arc;
 
-use crate::access::{iter::ReflectIter, Access, ReflectDirect};
+use crate::access::builtin::{self, SET_FUNCTIONS};
+use crate::access::{
+    iter::ReflectIter, Access, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect,
+    ReflectMut, RetValCallback,
+};
 use crate::climber::{ClimbError, Climber};
+use crate::deser;
 use crate::node_tree::NodeTree;
 use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
 
 impl<'a, K> ReflectIter<&'a dyn Access> for std::collections::hash_set::Iter<'a, K>
 where
@@ -22,7 +28,7 @@ where
 
 impl<K, S> ReflectDirect for HashSet<K, S>
 where
-    K: Eq + Hash + Access,
+    K: Eq + Hash + Access + deser::Deser,
     S: BuildHasher,
 {
     fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
@@ -30,28 +36,108 @@ where
         Reflector::reflect_set(reflector, &mut *i, "HashSet")
     }
 
-    fn immut_climber<'a>(
-        &self,
-        _climber: &mut Climber<'a>,
-    ) -> Result<Option<NodeTree>, ClimbError> {
-        return Ok(None);
+    fn immut_climber<'a>(&self, climber: &mut Climber<'a>) -> Result<Option<NodeTree>, ClimbError> {
+        if !climber.open_bracket() {
+            return Ok(None);
+        }
+        climber.complete_keys(self.iter().map(|value| value as &dyn Access))?;
+
+        let v = match K::deser(&mut climber.borrow_tracker()) {
+            Ok(value) => match self.get(&value) {
+                None => return Err(ClimbError::NotFound),
+                Some(v) => v,
+            },
+            Err(err) => return Err(ClimbError::DeserError(err)),
+        };
+
+        climber.close_bracket()?;
+
+        return climber.general_access_immut(v).map(Some);
     }
 
     fn mut_climber<'a>(
         &mut self,
-        _climber: &mut Climber<'a>,
+        climber: &mut Climber<'a>,
     ) -> Result<Option<NodeTree>, ClimbError> {
-        return Ok(None);
+        // Values of a set cannot be modified in place.
+        self.immut_climber(climber)
     }
 }
 
-use interact_derive::derive_interact_opaque;
+impl<K, S> Access for HashSet<K, S>
+where
+    K: Eq + Hash + Access + deser::Deser,
+    S: BuildHasher,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess {
+            reflect: Reflect::Direct(self),
+            functions: SET_FUNCTIONS,
+        }
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess {
+            reflect: ReflectMut::Direct(self),
+            functions: SET_FUNCTIONS,
+        }
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        match func_name {
+            "len" => {
+                builtin::no_args(climber, &builtin::LEN)?;
+                builtin::ret(climber, retcall, || Ok(self.len()))
+            }
+            "is_empty" => {
+                builtin::no_args(climber, &builtin::IS_EMPTY)?;
+                builtin::ret(climber, retcall, || Ok(self.is_empty()))
+            }
+            "contains" => {
+                let value: K = builtin::arg(climber, &builtin::CONTAINS_VALUE)?;
+                builtin::ret(climber, retcall, || Ok(self.contains(&value)))
+            }
+            _ if builtin::is_mut(func_name) => Err(CallError::NeedMutable),
+            _ => Err(CallError::NoSuchFunction),
+        }
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        match func_name {
+            "insert" => {
+                let value = builtin::arg(climber, &builtin::INSERT_VALUE)?;
+                builtin::ret(climber, retcall, || Ok(self.insert(value)))
+            }
+            "remove" => {
+                let value: K = builtin::arg(climber, &builtin::REMOVE_VALUE)?;
+                builtin::ret(climber, retcall, || Ok(self.remove(&value)))
+            }
+            "clear" => {
+                builtin::no_args(climber, &builtin::CLEAR)?;
+                builtin::ret(climber, retcall, || {
+                    self.clear();
+                    Ok(())
+                })
+            }
+            _ => self.immut_call(func_name, climber, retcall),
+        }
+    }
 
-derive_interact_opaque! {
-    #[interact(skip_bound(S))]
-    #[interact(immut_fn(len()))]
-    struct HashSet<K, S>
+    fn describe(schemas: &mut Schemas) -> Schema
     where
-        K: Eq + std::hash::Hash,
-        S: std::hash::BuildHasher;
+        Self: Sized,
+    {
+        Schema::of::<Self>(SchemaKind::Seq(Box::new(K::describe(schemas))))
+            .with_functions(SET_FUNCTIONS)
+    }
 }
@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::access::view::Render;
+use crate::access::{
+    Access, AssignError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+};
+use crate::climber::{ClimbError, Climber};
+use crate::deser::{self, DeserError, Tracker};
+use crate::node_tree::{NodeInfo, NodeTree};
+use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
+use crate::TokenKind;
+
+/// `Parse` determines whether and how a `Leaf` can be assigned a new value.
+pub trait Parse<T> {
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> deser::Result<T>;
+}
+
+/// Marker for leaves that cannot be assigned to.
+pub struct NoParse;
+
+impl<T> Parse<T> for NoParse {
+    fn deser<'a, 'b>(_tracker: &mut Tracker<'a, 'b>) -> deser::Result<T> {
+        Err(DeserError::Unbuildable)
+    }
+}
+
+/// Marker for leaves that are assigned from a string literal, using `std::str::FromStr`.
+pub struct FromStr;
+
+impl<T> Parse<T> for FromStr
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> deser::Result<T> {
+        if !tracker.has_remaining() {
+            return Err(DeserError::EndOfTokenList);
+        }
+
+        if let TokenKind::String(s) = tracker.top_kind() {
+            let value = s
+                .parse()
+                .map_err(|e: T::Err| DeserError::FromStr(e.to_string()))?;
+            tracker.step();
+            return Ok(value);
+        }
+
+        Err(DeserError::UnexpectedToken)
+    }
+}
+
+/// Marker for leaves that are assigned like values of their type, using `Deser`.
+pub struct ViaDeser;
+
+impl<T: crate::Deser> Parse<T> for ViaDeser {
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> deser::Result<T> {
+        T::deser(tracker)
+    }
+}
+
+/// Marker for rendering a secret value as `<redacted>`, whatever it holds.
+///
+/// This is used by `#[derive(Interact)]` for fields having the `redact` attribute.
+pub struct Redacted;
+
+impl<T> Render<T> for Redacted {
+    fn render(_value: &T) -> NodeInfo {
+        NodeInfo::Leaf(Cow::Borrowed(crate::redact::REDACTED))
+    }
+}
+
+/// An opaque leaf over a value whose type does not implement `Access`. It is rendered using `R`,
+/// and optionally assigned using `P`.
+///
+/// This is used by `#[derive(Interact)]` for fields having the `debug`, `display`, or `from_str`
+/// attributes.
+#[repr(transparent)]
+pub struct Leaf<T, R, P> {
+    value: T,
+    marker: PhantomData<fn() -> (R, P)>,
+}
+
+impl<T, R, P> Leaf<T, R, P> {
+    pub fn wrap(value: &T) -> &Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    pub fn wrap_mut(value: &mut T) -> &mut Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+impl<T, R, P> ReflectDirect for Leaf<T, R, P>
+where
+    R: Render<T>,
+    P: Parse<T>,
+{
+    fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
+        let obj_ptr = ((self as *const _) as usize, 0);
+        let meta = match Reflector::seen_ptr(reflector, obj_ptr) {
+            Ok(v) => return v,
+            Err(meta) => meta,
+        };
+        R::render(&self.value).with_meta(meta)
+    }
+
+    fn immut_climber<'a>(
+        &self,
+        _climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        Ok(None)
+    }
+
+    fn mut_climber<'a>(
+        &mut self,
+        _climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        Ok(None)
+    }
+}
+
+impl<T, R, P> Access for Leaf<T, R, P>
+where
+    R: Render<T>,
+    P: Parse<T>,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Direct(self))
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Direct(self))
+    }
+
+    fn mut_assign<'a, 'b>(
+        &mut self,
+        tracker: &mut Tracker<'a, 'b>,
+        probe_only: bool,
+    ) -> Result<(), AssignError> {
+        match P::deser(tracker) {
+            Ok(v) => {
+                if !probe_only {
+                    self.value = v;
+                }
+                Ok(())
+            }
+            Err(DeserError::Unbuildable) => Err(AssignError::Unbuildable),
+            Err(e) => Err(AssignError::Deser(e)),
+        }
+    }
+
+    fn describe(_schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        Schema::of::<T>(SchemaKind::Leaf)
+    }
+}
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 use std::sync::Mutex;
 
-use crate::access::{Access, ReflectDirect};
+use crate::access::{Access, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut};
 use crate::climber::{ClimbError, Climber};
+use crate::deser::Deser;
 use crate::node_tree::{NodeInfo, NodeTree};
 use crate::reflector::Reflector;
+use crate::schema::{Schema, Schemas};
 
 impl<T> ReflectDirect for Mutex<T>
 where
@@ -29,8 +31,23 @@ where
     }
 }
 
-use interact_derive::derive_interact_opaque;
+// Written out rather than derived as opaque, so that the type is described by the value it holds.
+impl<T> Access for Mutex<T>
+where
+    T: Access + Deser,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Direct(self))
+    }
 
-derive_interact_opaque! {
-    struct Mutex<T>;
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Direct(self))
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 }
@@ -0,0 +1,170 @@
+//! Function arguments given as paths to other objects of the `Root`, such as
+//! `scheduler.assign(state.workers[3])`, instead of values parsed by `Deser`.
+
+use std::any::Any;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::access::{Access, Argument, CallError};
+use crate::deser::{Deser, Tracker};
+use crate::Token;
+
+/// An argument given as a path to an object, by the index of the argument.
+pub(crate) type ObjectPath = (usize, Vec<Token<'static>>);
+
+/// Resolves paths to the objects of a `Root`, other than the one whose function is called.
+pub trait ResolveObject {
+    /// Whether the name is that of an object in the root, making an argument starting with it a
+    /// path.
+    fn is_object(&self, name: &str) -> bool;
+
+    /// Climb to the object at the given path, and hand it over to the callback.
+    fn resolve(
+        &self,
+        arg: &'static str,
+        path: &[Token<'static>],
+        f: &mut dyn FnMut(&dyn Access) -> Result<(), CallError>,
+    ) -> Result<(), CallError>;
+}
+
+/// The arguments of a function call that were given as paths to objects, returned from
+/// `Climber::parse_args`.
+pub struct ObjectArgs<'a> {
+    resolver: Option<Rc<dyn ResolveObject + 'a>>,
+    args: &'static [Argument],
+    paths: Vec<ObjectPath>,
+}
+
+impl<'a> ObjectArgs<'a> {
+    pub(crate) fn new(
+        resolver: Option<Rc<dyn ResolveObject + 'a>>,
+        args: &'static [Argument],
+        paths: Vec<ObjectPath>,
+    ) -> Self {
+        ObjectArgs {
+            resolver,
+            args,
+            paths,
+        }
+    }
+
+    /// Resolve all the objects given as arguments, and hand them over to the callback together.
+    pub fn with(
+        &self,
+        f: &mut dyn FnMut(&ObjectRefs) -> Result<(), CallError>,
+    ) -> Result<(), CallError> {
+        let refs = ObjectRefs {
+            args: self.args,
+            refs: vec![],
+        };
+
+        self.resolve_from(0, &refs, f)
+    }
+
+    fn resolve_from(
+        &self,
+        i: usize,
+        refs: &ObjectRefs,
+        f: &mut dyn FnMut(&ObjectRefs) -> Result<(), CallError>,
+    ) -> Result<(), CallError> {
+        let (idx, path) = match self.paths.get(i) {
+            None => return f(refs),
+            Some(object) => object,
+        };
+        let arg = self.args[*idx].name;
+        let resolver = match &self.resolver {
+            None => return Err(CallError::ObjectNotFound(arg)),
+            Some(resolver) => resolver,
+        };
+
+        resolver.resolve(arg, path, &mut |access| {
+            let mut inner = ObjectRefs {
+                args: self.args,
+                refs: refs.refs.clone(),
+            };
+            inner.refs.push((*idx, access));
+            self.resolve_from(i + 1, &inner, f)
+        })
+    }
+}
+
+/// The objects that were given as arguments of a function call.
+pub struct ObjectRefs<'r> {
+    args: &'static [Argument],
+    refs: Vec<(usize, &'r dyn Access)>,
+}
+
+impl<'r> ObjectRefs<'r> {
+    /// The object given for the argument of the given index, if any.
+    pub fn get(&self, idx: usize) -> Result<&'r dyn Access, CallError> {
+        for (i, access) in self.refs.iter() {
+            if *i == idx {
+                return Ok(*access);
+            }
+        }
+
+        Err(CallError::ObjectRequired(self.args[idx].name))
+    }
+
+    /// A reference to the object given for the argument of the given index, if it is of type `T`.
+    pub fn get_ref<T: Any>(&self, idx: usize) -> Result<&'r T, CallError> {
+        self.get(idx)?
+            .as_any()
+            .and_then(|any| any.downcast_ref())
+            .ok_or(CallError::ObjectType(self.args[idx].name))
+    }
+
+    fn arg(&self, idx: usize) -> &'static str {
+        self.args[idx].name
+    }
+}
+
+/// Selects how a function argument of type `T` is obtained, depending on the traits that `T`
+/// implements. This is done using the traits below, which are implemented both for `Arg<T>` and
+/// `&Arg<T>`, so that method resolution on `&Arg<T>` prefers the former when `T` has the required
+/// traits, and otherwise falls back to reporting an error.
+pub struct Arg<T>(PhantomData<fn() -> T>);
+
+impl<T> Arg<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Arg(PhantomData)
+    }
+}
+
+pub trait DeserArg<T> {
+    fn deser_arg(&self, arg: &'static str, tracker: &mut Tracker) -> Result<T, CallError>;
+}
+
+impl<T: Deser> DeserArg<T> for Arg<T> {
+    fn deser_arg(&self, _arg: &'static str, tracker: &mut Tracker) -> Result<T, CallError> {
+        T::deser(tracker).map_err(CallError::Deser)
+    }
+}
+
+pub trait NoDeserArg<T> {
+    fn deser_arg(&self, arg: &'static str, _tracker: &mut Tracker) -> Result<T, CallError> {
+        Err(CallError::ObjectRequired(arg))
+    }
+}
+
+impl<T> NoDeserArg<T> for &Arg<T> {}
+
+pub trait CloneArg<T> {
+    fn clone_arg(&self, refs: &ObjectRefs, idx: usize) -> Result<T, CallError>;
+}
+
+impl<T: Any + Clone> CloneArg<T> for Arg<T> {
+    fn clone_arg(&self, refs: &ObjectRefs, idx: usize) -> Result<T, CallError> {
+        refs.get_ref::<T>(idx).cloned()
+    }
+}
+
+pub trait NoCloneArg<T> {
+    fn clone_arg(&self, refs: &ObjectRefs, idx: usize) -> Result<T, CallError> {
+        refs.get(idx)?;
+        Err(CallError::ObjectNotCloneable(refs.arg(idx)))
+    }
+}
+
+impl<T> NoCloneArg<T> for &Arg<T> {}
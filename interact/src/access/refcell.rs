@@ -1,10 +1,14 @@
 use std::cell::RefCell;
 use std::sync::Arc;
 
-use crate::access::{Access, ReflectDirect};
+use crate::access::{
+    Access, AssignError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+};
 use crate::climber::{ClimbError, Climber};
+use crate::deser::{self, Deser};
 use crate::node_tree::{NodeInfo, NodeTree};
 use crate::reflector::Reflector;
+use crate::schema::{Schema, Schemas};
 
 impl<T> ReflectDirect for RefCell<T>
 where
@@ -29,9 +33,25 @@ where
     }
 }
 
-use interact_derive::derive_interact_opaque;
+// Written out rather than derived as opaque, so that the type is described by the value it holds.
+impl<T> Access for RefCell<T>
+where
+    T: Access + Deser,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Direct(self))
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Direct(self))
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
 
-derive_interact_opaque! {
-    #[interact(mut_assign)]
-    struct RefCell<T>;
+    mut_assign_deser!();
 }
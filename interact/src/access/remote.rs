@@ -0,0 +1,187 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use crate::access::derive::{ReflectStruct, Struct};
+use crate::access::{
+    Access, AssignError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+};
+use crate::climber::{ClimbError, Climber, EnumOrStruct, EnumOrStructMut};
+use crate::deser::{self, Deser, Tracker};
+use crate::node_tree::NodeTree;
+use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
+
+/// `Mirror` describes the fields of a struct type `T` from another crate, on behalf of that type.
+///
+/// It is implemented by `#[derive(Interact)]` on a local mirror struct that has the
+/// `#[interact(remote = "path::to::T")]` attribute, in a similar way to what `serde` does for
+/// remote types. Because of the orphan rule, `Access` cannot be implemented directly for `T`,
+/// and instead the value is accessed via `Remote<T, M>`.
+pub trait Mirror<T> {
+    fn get_desc(value: &T) -> Struct;
+    fn get_field_by_name<'a>(value: &'a T, name: &'static str) -> Option<&'a dyn Access>;
+    fn get_field_by_idx(value: &T, idx: usize) -> Option<&dyn Access>;
+    fn get_field_by_name_mut<'a>(
+        value: &'a mut T,
+        name: &'static str,
+    ) -> Option<&'a mut dyn Access>;
+    fn get_field_by_idx_mut(value: &mut T, idx: usize) -> Option<&mut dyn Access>;
+
+    fn is_field_hidden(_value: &T, _name: &'static str) -> bool {
+        false
+    }
+
+    /// The doc comment of the mirror type.
+    fn get_doc() -> &'static str {
+        ""
+    }
+
+    /// The doc comment of a field of the mirror type.
+    fn get_field_doc(_name: &str) -> &'static str {
+        ""
+    }
+
+    /// Describe the type `T` by the fields of the mirror type, as `Access::describe` does.
+    fn describe(_schemas: &mut Schemas) -> Schema {
+        Schema::of::<T>(SchemaKind::Opaque)
+    }
+
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> deser::Result<T>;
+}
+
+/// A transparent wrapper that makes a value of a type `T` from another crate accessible from
+/// Interact, using the fields described by the mirror type `M`.
+///
+/// It can be used directly as the type of a field or a root value, or via the
+/// `#[interact(remote = "Mirror")]` field attribute of `#[derive(Interact)]`.
+#[repr(transparent)]
+pub struct Remote<T, M> {
+    value: T,
+    mirror: PhantomData<fn() -> M>,
+}
+
+impl<T, M> Remote<T, M> {
+    pub fn new(value: T) -> Self {
+        Remote {
+            value,
+            mirror: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    pub fn wrap(value: &T) -> &Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    pub fn wrap_mut(value: &mut T) -> &mut Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+impl<T, M> Deref for Remote<T, M> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, M> DerefMut for Remote<T, M> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T, M> ReflectStruct for Remote<T, M>
+where
+    M: Mirror<T>,
+{
+    fn get_desc(&self) -> Struct {
+        M::get_desc(&self.value)
+    }
+
+    fn get_field_by_name(&self, name: &'static str) -> Option<&dyn Access> {
+        M::get_field_by_name(&self.value, name)
+    }
+
+    fn get_field_by_idx(&self, idx: usize) -> Option<&dyn Access> {
+        M::get_field_by_idx(&self.value, idx)
+    }
+
+    fn get_field_by_name_mut(&mut self, name: &'static str) -> Option<&mut dyn Access> {
+        M::get_field_by_name_mut(&mut self.value, name)
+    }
+
+    fn get_field_by_idx_mut(&mut self, idx: usize) -> Option<&mut dyn Access> {
+        M::get_field_by_idx_mut(&mut self.value, idx)
+    }
+
+    fn is_field_hidden(&self, name: &'static str) -> bool {
+        M::is_field_hidden(&self.value, name)
+    }
+
+    fn get_doc(&self) -> &'static str {
+        M::get_doc()
+    }
+
+    fn get_field_doc(&self, name: &str) -> &'static str {
+        M::get_field_doc(name)
+    }
+}
+
+impl<T, M> ReflectDirect for Remote<T, M>
+where
+    M: Mirror<T>,
+{
+    fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
+        Reflector::reflect_struct(reflector, &self.get_desc(), self, false)
+    }
+
+    fn immut_climber<'a>(&self, climber: &mut Climber<'a>) -> Result<Option<NodeTree>, ClimbError> {
+        climber.check_field_access_immut(&EnumOrStruct::Struct(self))
+    }
+
+    fn mut_climber<'a>(
+        &mut self,
+        climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        climber.check_field_access_mut(EnumOrStructMut::Struct(self))
+    }
+}
+
+impl<T, M> Access for Remote<T, M>
+where
+    M: Mirror<T>,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Direct(self))
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Direct(self))
+    }
+
+    mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        M::describe(schemas)
+    }
+}
+
+impl<T, M> Deser for Remote<T, M>
+where
+    M: Mirror<T>,
+{
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> deser::Result<Self> {
+        Ok(Remote::new(M::deser(tracker)?))
+    }
+}
@@ -0,0 +1,450 @@
+use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::ser::{self, Serialize};
+
+use crate::access::basic;
+use crate::access::bytes::BytesMode;
+use crate::access::{
+    Access, AssignError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+};
+use crate::climber::{ClimbError, Climber};
+use crate::deser::{self, serde::Error};
+use crate::node_tree::{NodeInfo, NodeTree};
+use crate::redact;
+use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
+
+/// A transparent wrapper that makes any value implementing `serde::Serialize` and
+/// `serde::Deserialize` accessible from Interact.
+///
+/// The value is reflected by serializing it into a `NodeTree`, and assigned by deserializing it
+/// from the Interact tokens. It can be used directly as the type of a field or a root value, or
+/// via the `#[interact(serde)]` field attribute of `#[derive(Interact)]`.
+#[repr(transparent)]
+pub struct Serde<T>(pub T);
+
+impl<T> Serde<T> {
+    pub fn wrap(value: &T) -> &Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    pub fn wrap_mut(value: &mut T) -> &mut Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+impl<T> Deref for Serde<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Serde<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> ReflectDirect for Serde<T>
+where
+    T: Serialize,
+{
+    fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
+        let obj_ptr = ((self as *const _) as usize, 0);
+        let meta = match Reflector::seen_ptr(reflector, obj_ptr) {
+            Ok(v) => return v,
+            Err(meta) => meta,
+        };
+
+        let info = match self.0.serialize(NodeSerializer) {
+            Ok(node) => node.info,
+            Err(e) => NodeInfo::Leaf(Cow::Owned(format!("<serde: {}>", e))),
+        };
+
+        info.with_meta(meta)
+    }
+
+    fn immut_climber<'a>(
+        &self,
+        _climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        Ok(None)
+    }
+
+    fn mut_climber<'a>(
+        &mut self,
+        _climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        Ok(None)
+    }
+}
+
+impl<T> Access for Serde<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Direct(self))
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Direct(self))
+    }
+
+    mut_assign_deser!();
+
+    fn describe(_schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        Schema::of::<T>(SchemaKind::Opaque)
+    }
+}
+
+fn leaf(s: String) -> NodeTree {
+    NodeInfo::Leaf(Cow::Owned(s)).into_node()
+}
+
+fn grouped(open: char, items: Vec<NodeTree>, close: char) -> NodeTree {
+    NodeInfo::Grouped(
+        open,
+        Box::new(NodeInfo::Delimited(',', items).into_node()),
+        close,
+    )
+    .into_node()
+}
+
+fn named(name: Option<&'static str>, node: NodeTree) -> NodeTree {
+    match name {
+        Some(name) => NodeInfo::named(name, node).into_node(),
+        None => node,
+    }
+}
+
+/// Serializes values into a `NodeTree`, following the rendering of the equivalent
+/// `#[derive(Interact)]` types.
+struct NodeSerializer;
+
+/// Accumulates the items of sequences, tuples, and tuple structs or variants.
+struct SerializeItems {
+    name: Option<&'static str>,
+    open: char,
+    close: char,
+    items: Vec<NodeTree>,
+}
+
+/// Accumulates the entries of maps, structs, and struct variants.
+struct SerializeEntries {
+    name: Option<&'static str>,
+    key: Option<NodeTree>,
+    entries: Vec<NodeTree>,
+}
+
+impl SerializeItems {
+    fn new(name: Option<&'static str>, open: char, close: char, len: Option<usize>) -> Self {
+        Self {
+            name,
+            open,
+            close,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.items.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<NodeTree, Error> {
+        Ok(named(self.name, grouped(self.open, self.items, self.close)))
+    }
+}
+
+impl SerializeEntries {
+    fn new(name: Option<&'static str>) -> Self {
+        Self {
+            name,
+            key: None,
+            entries: vec![],
+        }
+    }
+
+    fn push(&mut self, key: NodeTree, value: NodeTree) {
+        self.entries
+            .push(NodeInfo::Tuple(Box::new(key), ":", Box::new(value)).into_node());
+    }
+
+    fn push_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        // Fields are redacted by name like those of `#[derive(Interact)]` types.
+        let value = if redact::is_redacted(key) {
+            NodeInfo::Leaf(Cow::Borrowed(redact::REDACTED)).into_node()
+        } else {
+            value.serialize(NodeSerializer)?
+        };
+        self.push(NodeInfo::Leaf(Cow::Borrowed(key)).into_node(), value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<NodeTree, Error> {
+        Ok(named(self.name, grouped('{', self.entries, '}')))
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($t:ty)),*) => {
+        $(
+            fn $method(self, v: $t) -> Result<NodeTree, Error> {
+                Ok(leaf(format!("{}", v)))
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for NodeSerializer {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    type SerializeSeq = SerializeItems;
+    type SerializeTuple = SerializeItems;
+    type SerializeTupleStruct = SerializeItems;
+    type SerializeTupleVariant = SerializeItems;
+    type SerializeMap = SerializeEntries;
+    type SerializeStruct = SerializeEntries;
+    type SerializeStructVariant = SerializeEntries;
+
+    serialize_display!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64)
+    );
+
+    fn serialize_char(self, v: char) -> Result<NodeTree, Error> {
+        Ok(leaf(basic::render_char(v)))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<NodeTree, Error> {
+        Ok(leaf(basic::render_str(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<NodeTree, Error> {
+        Ok(BytesMode::List.render(v).into_node())
+    }
+
+    fn serialize_none(self) -> Result<NodeTree, Error> {
+        Ok(NodeInfo::Leaf(Cow::Borrowed("None")).into_node())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<NodeTree, Error> {
+        self.serialize_newtype_struct("Some", value)
+    }
+
+    fn serialize_unit(self) -> Result<NodeTree, Error> {
+        Ok(NodeInfo::Leaf(Cow::Borrowed("()")).into_node())
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<NodeTree, Error> {
+        Ok(NodeInfo::Leaf(Cow::Borrowed(name)).into_node())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<NodeTree, Error> {
+        Ok(NodeInfo::Leaf(Cow::Borrowed(variant)).into_node())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<NodeTree, Error> {
+        let mut items = SerializeItems::new(Some(name), '(', ')', Some(1));
+        items.push(value)?;
+        items.finish()
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<NodeTree, Error> {
+        self.serialize_newtype_struct(variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeItems, Error> {
+        Ok(SerializeItems::new(None, '[', ']', len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeItems, Error> {
+        Ok(SerializeItems::new(None, '(', ')', Some(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<SerializeItems, Error> {
+        Ok(SerializeItems::new(Some(name), '(', ')', Some(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeItems, Error> {
+        Ok(SerializeItems::new(Some(variant), '(', ')', Some(len)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeEntries, Error> {
+        Ok(SerializeEntries::new(None))
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<SerializeEntries, Error> {
+        Ok(SerializeEntries::new(Some(name)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeEntries, Error> {
+        Ok(SerializeEntries::new(Some(variant)))
+    }
+}
+
+impl ser::SerializeSeq for SerializeItems {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeItems {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeItems {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeItems {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for SerializeEntries {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <Error as ser::Error>::custom("value without a key"))?;
+        let value = value.serialize(NodeSerializer)?;
+        self.push(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeEntries {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeEntries {
+    type Ok = NodeTree;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push_field(key, value)
+    }
+
+    fn end(self) -> Result<NodeTree, Error> {
+        self.finish()
+    }
+}
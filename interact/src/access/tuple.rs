@@ -9,6 +9,7 @@ use crate::climber::{ClimbError, Climber, EnumOrStruct, EnumOrStructMut};
 use crate::deser::{self, Deser};
 use crate::node_tree::{NodeInfo, NodeTree};
 use crate::reflector::Reflector;
+use crate::schema::{Fields, Schema, SchemaKind, Schemas};
 
 macro_rules! tuple {
     ($count:expr; { $(($n:ident, $i:tt)),* }) => {
@@ -75,6 +76,14 @@ macro_rules! tuple {
                 MutAccess::no_funcs(ReflectMut::Direct(self))
             }
 
+            fn describe(schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                let fields = Fields::Tuple(vec![$($n::describe(schemas)),*]);
+                Schema::of::<Self>(SchemaKind::Struct(fields))
+            }
+
             mut_assign_deser!();
         }
     }
@@ -147,6 +156,14 @@ where
     }
 
     mut_assign_deser!();
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        let fields = Fields::Tuple(vec![A::describe(schemas)]);
+        Schema::of::<Self>(SchemaKind::Struct(fields))
+    }
 }
 
 impl ReflectDirect for () {
@@ -184,6 +201,13 @@ impl Access for () {
     }
 
     mut_assign_deser!();
+
+    fn describe(_schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        Schema::of::<Self>(SchemaKind::Leaf)
+    }
 }
 
 tuple!(2; {(A, 0), (B, 1)});
@@ -1,15 +1,45 @@
+use std::any;
+use std::convert::TryFrom;
 use std::sync::Arc;
 
-use crate::access::{Access, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut};
+use crate::access::builtin::{self, Item, SLICE_FUNCTIONS, VEC_FUNCTIONS};
+use crate::access::{
+    Access, AssignError, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+    RetValCallback,
+};
 use crate::climber::{ClimbError, Climber};
-use crate::deser::Deser;
+use crate::deser::{self, Deser};
 use crate::node_tree::NodeTree;
 use crate::reflector::Reflector;
+use crate::schema::{Schema, SchemaKind, Schemas};
 
 pub trait ReflectVec {
     fn get_len(&self) -> usize;
     fn get_item(&self, idx: usize) -> Option<&dyn Access>;
     fn get_item_mut(&mut self, _idx: usize) -> Option<&mut dyn Access>;
+
+    /// Whether the items are `u8` values by their type, which tells so even for no items.
+    fn has_byte_items(&self) -> bool {
+        false
+    }
+
+    /// Collect the items as a buffer of bytes, if all of them are `u8` values.
+    fn get_bytes(&self) -> Option<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.get_len());
+
+        for idx in 0..self.get_len() {
+            match self.get_item(idx)?.immut_access().reflect {
+                Reflect::Direct(item) => bytes.push(item.as_byte()?),
+                Reflect::Indirect(_) => return None,
+            }
+        }
+
+        if bytes.is_empty() && !self.has_byte_items() {
+            return None;
+        }
+
+        Some(bytes)
+    }
 }
 
 macro_rules! if_mut {
@@ -22,7 +52,107 @@ macro_rules! if_mut {
 }
 
 macro_rules! sized_iter {
-    ($t:ty, $i:ident, $name:expr) => {
+    ($t:ty, immut, $name:expr) => {
+        sized_iter!(@reflect $t, immut, $name);
+
+        impl<T> Access for $t
+        where
+            T: Access,
+        {
+            fn immut_access(&self) -> ImmutAccess {
+                ImmutAccess {
+                    reflect: Reflect::Direct(self),
+                    functions: SLICE_FUNCTIONS,
+                }
+            }
+
+            fn mut_access(&mut self) -> MutAccess {
+                MutAccess::no_funcs(ReflectMut::Immutable)
+            }
+
+            fn immut_call<'a>(
+                &self,
+                func_name: &'static str,
+                climber: &mut Climber<'a>,
+                retcall: RetValCallback<'a>,
+            ) -> Result<(), CallError> {
+                builtin::slice_call(&self[..], func_name, climber, retcall)
+            }
+
+            fn describe(schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                Schema::of::<Self>(SchemaKind::Seq(Box::new(T::describe(schemas))))
+                    .with_functions(SLICE_FUNCTIONS)
+            }
+        }
+    };
+    ($t:ty, mut, $name:expr) => {
+        sized_iter!(@mut $t, $name, {});
+    };
+    ($t:ty, array, $name:expr) => {
+        sized_iter!(@mut $t, $name, {
+            fn deser_new<'x, 'y>(tracker: &mut deser::Tracker<'x, 'y>) -> deser::Result<Self> {
+                <$t>::try_from(deser_items(tracker)?).map_err(|_| deser::DeserError::WrongLength)
+            }
+        });
+    };
+    (@mut $t:ty, $name:expr, {$($deser_new:tt)*}) => {
+        sized_iter!(@reflect $t, mut, $name);
+
+        impl<T> Access for $t
+        where
+            T: Access,
+        {
+            fn immut_access(&self) -> ImmutAccess {
+                ImmutAccess {
+                    reflect: Reflect::Direct(self),
+                    functions: SLICE_FUNCTIONS,
+                }
+            }
+
+            fn mut_access(&mut self) -> MutAccess {
+                MutAccess {
+                    reflect: ReflectMut::Direct(self),
+                    functions: SLICE_FUNCTIONS,
+                }
+            }
+
+            fn immut_call<'a>(
+                &self,
+                func_name: &'static str,
+                climber: &mut Climber<'a>,
+                retcall: RetValCallback<'a>,
+            ) -> Result<(), CallError> {
+                builtin::slice_call(&self[..], func_name, climber, retcall)
+            }
+
+            fn mut_call<'a>(
+                &mut self,
+                func_name: &'static str,
+                climber: &mut Climber<'a>,
+                retcall: RetValCallback<'a>,
+            ) -> Result<(), CallError> {
+                builtin::slice_call(&self[..], func_name, climber, retcall)
+            }
+
+            fn describe(schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                Schema::of::<Self>(SchemaKind::Seq(Box::new(T::describe(schemas))))
+                    .with_functions(SLICE_FUNCTIONS)
+            }
+
+            mut_assign_new!();
+            $($deser_new)*
+        }
+    };
+    ($t:ty, vec, $name:expr) => {
+        sized_iter!(@reflect $t, mut, $name);
+    };
+    (@reflect $t:ty, $i:ident, $name:expr) => {
         impl<T> ReflectVec for $t
         where
             T: Access,
@@ -31,6 +161,10 @@ macro_rules! sized_iter {
                 self.len()
             }
 
+            fn has_byte_items(&self) -> bool {
+                any::type_name::<T>() == any::type_name::<u8>()
+            }
+
             fn get_item(&self, idx: usize) -> Option<&dyn Access> {
                 if idx >= self.len() {
                     None
@@ -69,6 +203,7 @@ macro_rules! sized_iter {
                 if !climber.open_bracket() {
                     return Ok(None);
                 }
+                climber.complete_subscript((0..self.len()).map(|idx| idx.to_string()))?;
 
                 let v = match usize::deser(&mut climber.borrow_tracker()) {
                     Err(e) => Err(e),
@@ -93,6 +228,7 @@ macro_rules! sized_iter {
                 if !climber.open_bracket() {
                     return Ok(None);
                 }
+                climber.complete_subscript((0..self.len()).map(|idx| idx.to_string()))?;
 
                 let v = match usize::deser(&mut climber.borrow_tracker()) {
                     Err(e) => Err(e),
@@ -111,56 +247,136 @@ macro_rules! sized_iter {
             }
         }
 
-        impl<T> Access for $t
-        where
-            T: Access,
-        {
-            fn immut_access(&self) -> ImmutAccess {
-                ImmutAccess::no_funcs(Reflect::Direct(self))
-            }
+    };
+}
 
-            fn mut_access(&mut self) -> MutAccess {
-                if_mut! {
-                    $i, {
-                        MutAccess::no_funcs(ReflectMut::Direct(self))
-                    } else {
-                        MutAccess::no_funcs(ReflectMut::Immutable)
+sized_iter!(&[T], immut, "");
+sized_iter!(&mut [T], mut, "");
+sized_iter!(Vec<T>, vec, "Vec");
+
+/// Build the items of a collection using `Access::deser_new`, so that they don't need to
+/// implement `Deser`.
+fn deser_items<'a, 'b, T: Access>(tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Vec<T>> {
+    let items: Vec<Item<T>> = Deser::deser(tracker)?;
+    Ok(items.into_iter().map(|Item(item)| item).collect())
+}
+
+impl<T> Access for Vec<T>
+where
+    T: Access,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess {
+            reflect: Reflect::Direct(self),
+            functions: VEC_FUNCTIONS,
+        }
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess {
+            reflect: ReflectMut::Direct(self),
+            functions: VEC_FUNCTIONS,
+        }
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        builtin::slice_call(&self[..], func_name, climber, retcall)
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        match func_name {
+            "push" => {
+                let Item(value) = builtin::arg(climber, &builtin::PUSH)?;
+                builtin::ret(climber, retcall, || {
+                    self.push(value);
+                    Ok(())
+                })
+            }
+            "pop" => {
+                builtin::no_args(climber, &builtin::POP)?;
+                builtin::ret(climber, retcall, || Ok(self.pop().map(Item)))
+            }
+            "insert" => {
+                let (index, Item(value)) = builtin::args2(climber, &builtin::INSERT_AT)?;
+                builtin::ret(climber, retcall, || {
+                    if index > self.len() {
+                        return Err(CallError::OutOfRange);
                     }
-                }
+                    self.insert(index, value);
+                    Ok(())
+                })
+            }
+            "remove" => {
+                let index = builtin::arg(climber, &builtin::REMOVE_AT)?;
+                builtin::ret(climber, retcall, || {
+                    if index >= self.len() {
+                        return Err(CallError::OutOfRange);
+                    }
+                    Ok(self.remove(index))
+                })
+            }
+            "clear" => {
+                builtin::no_args(climber, &builtin::CLEAR)?;
+                builtin::ret(climber, retcall, || {
+                    self.clear();
+                    Ok(())
+                })
             }
+            _ => builtin::slice_call(&self[..], func_name, climber, retcall),
         }
-    };
+    }
+
+    mut_assign_new!();
+
+    fn deser_new<'x, 'y>(tracker: &mut deser::Tracker<'x, 'y>) -> deser::Result<Self> {
+        deser_items(tracker)
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        Schema::of::<Self>(SchemaKind::Seq(Box::new(T::describe(schemas))))
+            .with_functions(VEC_FUNCTIONS)
+    }
 }
 
-sized_iter!(&[T], immut, "");
-sized_iter!(&mut [T], mut, "");
-sized_iter!(Vec<T>, mut, "Vec");
-sized_iter!([T; 1], mut, "");
-sized_iter!([T; 2], mut, "");
-sized_iter!([T; 3], mut, "");
-sized_iter!([T; 4], mut, "");
-sized_iter!([T; 5], mut, "");
-sized_iter!([T; 6], mut, "");
-sized_iter!([T; 7], mut, "");
-sized_iter!([T; 8], mut, "");
-sized_iter!([T; 9], mut, "");
-sized_iter!([T; 10], mut, "");
-sized_iter!([T; 11], mut, "");
-sized_iter!([T; 12], mut, "");
-sized_iter!([T; 13], mut, "");
-sized_iter!([T; 14], mut, "");
-sized_iter!([T; 15], mut, "");
-sized_iter!([T; 16], mut, "");
-sized_iter!([T; 17], mut, "");
-sized_iter!([T; 18], mut, "");
-sized_iter!([T; 19], mut, "");
-sized_iter!([T; 21], mut, "");
-sized_iter!([T; 22], mut, "");
-sized_iter!([T; 23], mut, "");
-sized_iter!([T; 24], mut, "");
-sized_iter!([T; 25], mut, "");
-sized_iter!([T; 27], mut, "");
-sized_iter!([T; 28], mut, "");
-sized_iter!([T; 29], mut, "");
-sized_iter!([T; 31], mut, "");
-sized_iter!([T; 32], mut, "");
+sized_iter!([T; 1], array, "");
+sized_iter!([T; 2], array, "");
+sized_iter!([T; 3], array, "");
+sized_iter!([T; 4], array, "");
+sized_iter!([T; 5], array, "");
+sized_iter!([T; 6], array, "");
+sized_iter!([T; 7], array, "");
+sized_iter!([T; 8], array, "");
+sized_iter!([T; 9], array, "");
+sized_iter!([T; 10], array, "");
+sized_iter!([T; 11], array, "");
+sized_iter!([T; 12], array, "");
+sized_iter!([T; 13], array, "");
+sized_iter!([T; 14], array, "");
+sized_iter!([T; 15], array, "");
+sized_iter!([T; 16], array, "");
+sized_iter!([T; 17], array, "");
+sized_iter!([T; 18], array, "");
+sized_iter!([T; 19], array, "");
+sized_iter!([T; 21], array, "");
+sized_iter!([T; 22], array, "");
+sized_iter!([T; 23], array, "");
+sized_iter!([T; 24], array, "");
+sized_iter!([T; 25], array, "");
+sized_iter!([T; 27], array, "");
+sized_iter!([T; 28], array, "");
+sized_iter!([T; 29], array, "");
+sized_iter!([T; 31], array, "");
+sized_iter!([T; 32], array, "");
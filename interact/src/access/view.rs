@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::access::{
+    Access, AssignError, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect, ReflectMut,
+    RetValCallback,
+};
+use crate::climber::{ClimbError, Climber};
+use crate::deser;
+use crate::node_tree::{NodeInfo, NodeTree};
+use crate::reflector::Reflector;
+use crate::schema::{Schema, Schemas};
+
+/// `Render` provides an alternative reflection for values of type `T`, used by `View`.
+pub trait Render<T: ?Sized> {
+    fn render(value: &T) -> NodeInfo;
+}
+
+/// A transparent view over a value, overriding how it is reflected, while keeping the climbing,
+/// function calls and assignments of the underlying value.
+///
+/// This is used by `#[derive(Interact)]` for fields having rendering attributes.
+#[repr(transparent)]
+pub struct View<T, R> {
+    value: T,
+    render: PhantomData<fn() -> R>,
+}
+
+impl<T, R> View<T, R> {
+    pub fn wrap(value: &T) -> &Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    pub fn wrap_mut(value: &mut T) -> &mut Self {
+        // This is safe due to `#[repr(transparent)]`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+impl<T, R> ReflectDirect for View<T, R>
+where
+    T: Access,
+    R: Render<T>,
+{
+    fn immut_reflector(&self, reflector: &Arc<Reflector>) -> NodeTree {
+        let obj_ptr = ((self as *const _) as usize, 0);
+        let meta = match Reflector::seen_ptr(reflector, obj_ptr) {
+            Ok(v) => return v,
+            Err(meta) => meta,
+        };
+        R::render(&self.value).with_meta(meta)
+    }
+
+    fn immut_climber<'a>(&self, climber: &mut Climber<'a>) -> Result<Option<NodeTree>, ClimbError> {
+        match self.value.immut_access().reflect {
+            Reflect::Direct(access) => access.immut_climber(climber),
+            Reflect::Indirect(_) => Ok(None),
+        }
+    }
+
+    fn mut_climber<'a>(
+        &mut self,
+        climber: &mut Climber<'a>,
+    ) -> Result<Option<NodeTree>, ClimbError> {
+        match self.value.mut_access().reflect {
+            ReflectMut::Direct(access) => access.mut_climber(climber),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<T, R> Access for View<T, R>
+where
+    T: Access,
+    R: Render<T>,
+{
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess {
+            reflect: Reflect::Direct(self),
+            functions: self.value.immut_access().functions,
+        }
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        let (functions, immutable) = match self.value.mut_access() {
+            MutAccess {
+                reflect: ReflectMut::Immutable,
+                functions,
+            } => (functions, true),
+            MutAccess { functions, .. } => (functions, false),
+        };
+
+        MutAccess {
+            reflect: if immutable {
+                ReflectMut::Immutable
+            } else {
+                ReflectMut::Direct(self)
+            },
+            functions,
+        }
+    }
+
+    fn immut_call<'a>(
+        &self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.value.immut_call(func_name, climber, retcall)
+    }
+
+    fn mut_call<'a>(
+        &mut self,
+        func_name: &'static str,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.value.mut_call(func_name, climber, retcall)
+    }
+
+    fn mut_assign<'a, 'b>(
+        &mut self,
+        tracker: &mut deser::Tracker<'a, 'b>,
+        probe_only: bool,
+    ) -> Result<(), AssignError> {
+        self.value.mut_assign(tracker, probe_only)
+    }
+
+    fn describe(schemas: &mut Schemas) -> Schema
+    where
+        Self: Sized,
+    {
+        T::describe(schemas)
+    }
+}
@@ -2,14 +2,24 @@ use std::borrow::Cow;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::mpsc::{channel, Sender};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
-use crate::access::derive::{ReflectEnum, ReflectStruct, StructKind};
+use crate::access::derive::{
+    inline_field_names, inline_field_owner, inline_field_owner_mut, Enum, ReflectEnum,
+    ReflectStruct, Struct, StructKind,
+};
+use crate::access::leaf::{Leaf, NoParse, Redacted};
+use crate::access::object::{ObjectArgs, ResolveObject};
 use crate::deser;
+use crate::deser::args::{parse_args, ArgCallback};
+use crate::interrupt;
+use crate::redact;
 use crate::reflector::Reflector;
+use crate::root::RootFunction;
+use crate::schema::TypeInfo;
 use crate::{
-    Access, CallError, ExpectTree, Function, NodeInfo, NodeTree, ReflectMut, Token, TokenKind,
-    TokenVec,
+    Access, Argument, BytesMode, CallError, ExpectTree, Function, NodeInfo, NodeTree, ReflectMut,
+    Token, TokenKind, TokenVec,
 };
 use crate::{Assist, NextOptions};
 
@@ -22,9 +32,13 @@ pub enum ClimbError {
     DeserError(crate::deser::DeserError),
     TokenError(crate::tokens::Error),
     Indirect,
+    Interrupted,
     Locked,
     MissingStartComponent,
     NeedMutPath,
+    /// An assignment was skipped, as it followed `?` on a value having no single inner value,
+    /// e.g. `opt?.x = 1` where `opt` is `None`.
+    NotAssigned,
     NotFound,
     NullPath,
     UnattainedMutability,
@@ -32,6 +46,19 @@ pub enum ClimbError {
     UnexpectedToken,
 }
 
+/// The doc comments describing the value at the end of an expression, as far as they are known.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Docs {
+    /// The doc comment of the field holding the value.
+    pub field: &'static str,
+
+    /// The doc comment of the type of the value.
+    pub ty: &'static str,
+
+    /// The doc comment of the current variant, if the value is an enum.
+    pub variant: &'static str,
+}
+
 /// Climber represents the full state of evaluation of Interact expressions.  It is used within the
 /// impls of the `Access` trait, and most likely does not require direct references from Interact's
 /// crate users, unless manually providing impls of `Access` not via the `#[derive(Interact)]`.
@@ -42,7 +69,86 @@ pub struct Climber<'a> {
     expect: ExpectTree<Token<'static>>,
     tokenvec: TokenVec<'a>,
     valid_pos: usize,
-    sender: Option<Sender<(Arc<Mutex<Climber<'static>>>, Result<NodeTree, ClimbError>)>>,
+    sender: Option<IndirectSender>,
+    call_hint: Option<&'static Function>,
+    assign_hint: Option<&'static str>,
+    docs: Docs,
+    type_info: Option<TypeInfo>,
+    objects: ObjectContext<'a>,
+
+    /// The number of modifications made, by assignments and by calls of functions via a mutable
+    /// path.
+    changes: usize,
+}
+
+/// The maximum number of subscripts offered for completion, such as the keys of a map.
+const MAX_SUBSCRIPT_COMPLETIONS: usize = 20;
+
+type Capture<'a> = dyn FnMut(&dyn Access) -> Result<(), CallError> + 'a;
+
+/// Allows paths given as function arguments to resolve to other objects of the `Root`.
+#[derive(Clone, Default)]
+struct ObjectContext<'a> {
+    resolver: Option<Rc<dyn ResolveObject + 'a>>,
+
+    /// Receives the object at the end of the path, instead of reflecting it.
+    capture: Option<Rc<RefCell<Capture<'a>>>>,
+}
+
+/// Sends the result of an indirect access back to the thread that asked for it.
+type IndirectSender = Sender<(Detached, Result<NodeTree, ClimbError>)>;
+
+/// The state of a `Climber` that is handed over to the thread serving an indirect access. It leaves
+/// out the object context, which can only be used on the thread that evaluates the expression.
+struct Detached {
+    probe_only: bool,
+    reflector: Arc<Reflector>,
+    expect: ExpectTree<Token<'static>>,
+    tokenvec: TokenVec<'static>,
+    valid_pos: usize,
+    sender: Option<IndirectSender>,
+    call_hint: Option<&'static Function>,
+    assign_hint: Option<&'static str>,
+    docs: Docs,
+    type_info: Option<TypeInfo>,
+    changes: usize,
+}
+
+impl Detached {
+    fn attach(self) -> Climber<'static> {
+        Climber {
+            probe_only: self.probe_only,
+            reflector: self.reflector,
+            expect: self.expect,
+            tokenvec: self.tokenvec,
+            valid_pos: self.valid_pos,
+            sender: self.sender,
+            call_hint: self.call_hint,
+            assign_hint: self.assign_hint,
+            docs: self.docs,
+            type_info: self.type_info,
+            objects: ObjectContext::default(),
+            changes: self.changes,
+        }
+    }
+}
+
+impl Climber<'static> {
+    fn detach(self) -> Detached {
+        Detached {
+            probe_only: self.probe_only,
+            reflector: self.reflector,
+            expect: self.expect,
+            tokenvec: self.tokenvec,
+            valid_pos: self.valid_pos,
+            sender: self.sender,
+            call_hint: self.call_hint,
+            assign_hint: self.assign_hint,
+            docs: self.docs,
+            type_info: self.type_info,
+            changes: self.changes,
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -72,6 +178,24 @@ macro_rules! if_mut {
     };
 }
 
+/// Whether the variant holds a single value that `?` and field accesses can climb into, as
+/// opposed to variants like `None` and `Err(e)` that short-circuit `?`.
+fn is_transparent(enum_desc: &Enum, desc: &Struct) -> bool {
+    match desc.kind {
+        StructKind::Tuple(1) => !(enum_desc.name == "Result" && desc.name == "Err"),
+        _ => false,
+    }
+}
+
+/// Whether accessing into the variant without naming it short-circuits like `?` does, as for a
+/// `None` of an `Option` that is implicitly unwrapped.
+fn is_implicitly_skipped(enum_desc: &Enum, desc: &Struct, prefix: &str) -> bool {
+    let unwrapped = enum_desc.name == "Option" || enum_desc.name == "Result";
+    let names_variant = enum_desc.opts.iter().any(|opt| opt.starts_with(prefix));
+
+    unwrapped && !names_variant && !is_transparent(enum_desc, desc)
+}
+
 macro_rules! climber_impl {
     (check_field_access, $mut:ident, $recurse:ident, $get_field_by_idx:ident,
      $get_field_by_name:ident, $general_access:ident, $get_variant_struct:ident, $EnumOrStruct:ident,
@@ -82,11 +206,40 @@ macro_rules! climber_impl {
         enum T<A, B> { Struct(A), Enum(B) };
 
         let p_match = match if_mut!($mut, { &mut $reflect } else { $reflect }) {
-            $EnumOrStruct::Struct(p_struct) => { T::Struct(p_struct) }
-            $EnumOrStruct::Enum(p_enum) => { T::Enum(p_enum) }
+            $EnumOrStruct::Struct(p_struct) => {
+                $self.docs.ty = p_struct.get_doc();
+                T::Struct(p_struct)
+            }
+            $EnumOrStruct::Enum(p_enum) => {
+                $self.docs.ty = p_enum.get_enum_doc();
+                $self.docs.variant = p_enum.get_variant_struct().get_doc();
+                T::Enum(p_enum)
+            }
         };
 
+        let field_access_pos = $self.tokenvec.pos();
+
         if !$self.tokenvec.is_empty() {
+            if let TokenKind::Question = &$self.tokenvec.top_kind() {
+                $self.tokenvec.advance(1);
+                let p_enum = match p_match {
+                    T::Enum(p_enum) => p_enum,
+                    T::Struct(_) => return Err(ClimbError::UnexpectedToken),
+                };
+
+                let enum_desc = p_enum.get_variant_desc();
+                let desc = p_enum.get_variant_struct().get_desc();
+                if is_transparent(&enum_desc, &desc) {
+                    let p_struct = p_enum.$get_variant_struct();
+                    let field = p_struct.$get_field_by_idx(0).unwrap();
+                    return $self.$general_access(
+                        if_mut!($mut, { &mut *field } else { &*field })
+                    ).map(Some)
+                }
+
+                return $self.skip_variant(&desc, p_enum.get_variant_struct()).map(Some);
+            }
+
             if let TokenKind::FieldAccess = &$self.tokenvec.top_kind() {
                 $self.tokenvec.advance(1);
             } else {
@@ -116,6 +269,10 @@ macro_rules! climber_impl {
                         for j in 0..i {
                             let name = format!("{}", j);
                             if name == prefix {
+                                $self.docs = Docs {
+                                    field: p_struct.get_field_doc(&name),
+                                    ..Docs::default()
+                                };
                                 let field =
                                     p_struct.$get_field_by_idx(j).unwrap();
                                 $self.tokenvec.advance(1);
@@ -129,12 +286,32 @@ macro_rules! climber_impl {
                             }
                         }
                     },
-                    StructKind::Fields(names) => {
-                        for name in names {
-                            if *name == prefix {
+                    StructKind::Fields(_) => {
+                        for name in inline_field_names(&**p_struct) {
+                            if name == prefix {
+                                let owner = if_mut!($mut, {
+                                    inline_field_owner_mut(&mut **p_struct, name)
+                                } else {
+                                    inline_field_owner(&**p_struct, name)
+                                }).unwrap();
+                                $self.docs = Docs {
+                                    field: owner.get_field_doc(name),
+                                    ..Docs::default()
+                                };
                                 let field =
-                                    p_struct.$get_field_by_name(name).unwrap();
+                                    owner.$get_field_by_name(name).unwrap();
                                 $self.tokenvec.advance(1);
+                                if redact::is_redacted(name) {
+                                    // Neither climbed into nor assigned, like a field having
+                                    // `#[interact(redact)]`.
+                                    return $self.$general_access(
+                                        if_mut!($mut, {
+                                            Leaf::<(), Redacted, NoParse>::wrap_mut(&mut ())
+                                        } else {
+                                            Leaf::<(), Redacted, NoParse>::wrap(&())
+                                        })
+                                    ).map(Some)
+                                }
                                 return $self.$general_access(
                                     if_mut!($mut, { &mut *field } else { &*field })
                                 ).map(Some)
@@ -147,8 +324,30 @@ macro_rules! climber_impl {
                     }
                     StructKind::Unit => {},
                 }
+
+                for getter in p_struct.get_getters() {
+                    if *getter == prefix {
+                        $self.docs = Docs::default();
+                        $self.tokenvec.advance(1);
+                        if redact::is_redacted(getter) {
+                            return $self.general_access_immut(
+                                Leaf::<(), Redacted, NoParse>::wrap(&())
+                            ).map(Some)
+                        }
+                        let mut res = None;
+                        p_struct.with_getter(getter, &mut |value| {
+                            res = Some($self.general_access_immut(value));
+                        });
+                        return res.unwrap_or(Err(ClimbError::NotFound)).map(Some);
+                    }
+                    if getter.starts_with(prefix.as_str()) {
+                        $self.expect_token(TokenKind::Ident, Cow::Borrowed(getter));
+                        $self.expect.retract_one();
+                    }
+                }
             }
             T::Enum(p_enum) => {
+                let enum_desc = p_enum.get_variant_desc();
                 let desc = p_enum.get_variant_struct().get_desc();
                 let p_struct = p_enum.$get_variant_struct();
                 if desc.name == prefix {
@@ -164,11 +363,19 @@ macro_rules! climber_impl {
                     } else {
                         return Ok(Some(Reflector::reflect_struct(&$self.reflector, &desc, p_struct, true)))
                     }
-                } else {
-                    if desc.name.starts_with(prefix.as_str()) {
-                        $self.expect_token(TokenKind::Ident, Cow::Borrowed(desc.name));
-                        $self.expect.retract_one();
-                    }
+                } else if desc.name.starts_with(prefix.as_str()) {
+                    $self.expect_token(TokenKind::Ident, Cow::Borrowed(desc.name));
+                    $self.expect.retract_one();
+                } else if is_transparent(&enum_desc, &desc) {
+                    // Not naming the variant, so the access is into the value it holds.
+                    $self.tokenvec.take_pos(field_access_pos);
+                    let field = p_struct.$get_field_by_idx(0).unwrap();
+                    return $self.$general_access(
+                        if_mut!($mut, { &mut *field } else { &*field })
+                    ).map(Some)
+                } else if is_implicitly_skipped(&enum_desc, &desc, &prefix) {
+                    $self.tokenvec.take_pos(field_access_pos);
+                    return $self.skip_variant(&desc, &*p_struct).map(Some);
                 }
             }
         }
@@ -203,6 +410,7 @@ macro_rules! climber_impl {
 
         for function in $functions {
             if function.name == prefix {
+                $self.call_hint = Some(function);
                 $self.tokenvec.advance(1);
                 if $self.tokenvec.is_empty() {
                     $self.expect_token(TokenKind::TupleOpen, Cow::Borrowed("("));
@@ -233,10 +441,17 @@ macro_rules! climber_impl {
                             return Err(ClimbError::CallError(e));
                         }
                         Ok(()) => {
+                            $self.call_hint = None;
+                            if_mut!($mut, { {
+                                if !$self.probe_only {
+                                    $self.changes += 1;
+                                }
+                            } } else { {} });
                             match retval.borrow_mut().take(){
                                 Some(retval) => { return retval }
                                 None => {}
                             }
+                            $self.type_info = None;
                             return Ok(None);
                         }
                     }
@@ -259,13 +474,18 @@ macro_rules! climber_impl {
     (indirect_call, $mut:ident, $self:ident, $access:ident, $indirect:ident, $fname:ident) =>
     { {
           let mut climber =
-              Climber {
+              Detached {
                   probe_only: $self.probe_only,
                   reflector: $self.reflector.clone(),
                   expect: $self.expect.clone(),
                   valid_pos: $self.valid_pos,
                   sender: None,
                   tokenvec: $self.tokenvec.clone_owned(),
+                  call_hint: $self.call_hint,
+                  assign_hint: $self.assign_hint,
+                  docs: $self.docs,
+                  type_info: $self.type_info,
+                  changes: $self.changes,
               };
 
           let recv = if $self.sender.is_none() {
@@ -277,27 +497,36 @@ macro_rules! climber_impl {
               None
           };
 
-          let clone_ref = Arc::new(Mutex::new(climber));
+          let mut climber = Some(climber);
 
           $access.$indirect(Box::new(move |access| {
-              let clone_send = clone_ref.clone();
-              let mut clone = clone_ref.lock().unwrap();
+              let mut clone = match climber.take() {
+                  Some(climber) => climber.attach(),
+                  None => return,
+              };
               let res = clone.$fname(access);
               if let Err(ClimbError::Indirect) = res {
                   return;
               } else {
                   let sender = clone.sender.take().unwrap();
-                  let _ = sender.send((clone_send, res));
+                  let _ = sender.send((clone.detach(), res));
               }
           }));
 
           match recv {
               Some(recv) => {
-                  let (clone_ref, res) = recv.recv().unwrap();
-                  let mut clone = clone_ref.lock().unwrap();
+                  let (mut clone, res) = match interrupt::recv(&recv, $self.reflector.interrupt()) {
+                      Some(reply) => reply,
+                      None => return Err(ClimbError::Interrupted),
+                  };
                   std::mem::swap(&mut $self.expect, &mut clone.expect);
                   $self.tokenvec.take_pos(clone.tokenvec.pos());
                   $self.valid_pos = clone.valid_pos;
+                  $self.call_hint = clone.call_hint;
+                  $self.assign_hint = clone.assign_hint;
+                  $self.docs = clone.docs;
+                  $self.type_info = clone.type_info;
+                  $self.changes = clone.changes;
                   return res;
               }
               None => {
@@ -316,17 +545,176 @@ impl<'a> Climber<'a> {
             expect: ExpectTree::new(),
             valid_pos: 0,
             sender: None,
+            call_hint: None,
+            assign_hint: None,
+            docs: Docs::default(),
+            type_info: None,
+            objects: ObjectContext::default(),
+            changes: 0,
         }
     }
 
+    /// Short-circuit the rest of the expression, like `None` does for `?` in Rust, showing the
+    /// variant in angle brackets, e.g. `<None>` or `<Err ( 5 )>`. An assignment in the rest of it
+    /// is not made, which is reported rather than ignored.
+    fn skip_variant(
+        &mut self,
+        desc: &Struct,
+        p_struct: &dyn ReflectStruct,
+    ) -> Result<NodeTree, ClimbError> {
+        if self.tokenvec.has_assign() {
+            return Err(ClimbError::NotAssigned);
+        }
+
+        let remaining = self.tokenvec.remaining();
+        self.tokenvec.advance(remaining);
+        self.valid_pos = self.tokenvec.pos();
+
+        let variant = Reflector::reflect_struct(&self.reflector, desc, p_struct, false);
+        let skipped = format!("<{}>", variant.info);
+        Ok(NodeInfo::Leaf(Cow::Owned(skipped)).into_node())
+    }
+
+    pub(crate) fn set_resolver(&mut self, resolver: Rc<dyn ResolveObject + 'a>) {
+        self.objects.resolver = Some(resolver);
+    }
+
+    pub(crate) fn set_capture(&mut self, capture: Rc<RefCell<Capture<'a>>>) {
+        self.objects.capture = Some(capture);
+    }
+
+    /// Parse the arguments of a function call like `deser::deser_args`, where arguments can also
+    /// be paths to other objects of the `Root`, returned for resolving them.
+    pub fn parse_args(
+        &mut self,
+        args: &'static [Argument],
+        f: &mut ArgCallback,
+    ) -> Result<ObjectArgs<'a>, CallError> {
+        let resolver = self.objects.resolver.clone();
+        let is_object = |name: &str| match &resolver {
+            Some(resolver) => resolver.is_object(name),
+            None => false,
+        };
+        let paths = parse_args(&mut self.borrow_tracker(), args, &is_object, f)?;
+
+        Ok(ObjectArgs::new(self.objects.resolver.clone(), args, paths))
+    }
+
+    /// Call a function of the `Root` that is not a method of any object, whose arguments and the
+    /// rest of the expression remain to parse.
+    pub(crate) fn call_root_function(
+        &mut self,
+        function: &dyn RootFunction,
+    ) -> Result<NodeTree, ClimbError> {
+        self.call_hint = Some(function.function());
+        if self.tokenvec.is_empty() {
+            self.expect_token(TokenKind::TupleOpen, Cow::Borrowed("("));
+            return Err(ClimbError::UnexpectedExpressionEnd);
+        }
+        match &self.tokenvec.top_kind() {
+            TokenKind::TupleOpen => {}
+            _ => return Err(ClimbError::UnexpectedToken),
+        }
+
+        let retval: Rc<RefCell<Option<Result<NodeTree, ClimbError>>>> = Rc::new(RefCell::new(None));
+        let retval2 = retval.clone();
+
+        function
+            .call(
+                self,
+                Box::new(move |access, climber| {
+                    *retval2.borrow_mut() = Some(climber.general_access_immut(access));
+                }),
+            )
+            .map_err(ClimbError::CallError)?;
+
+        // When probing, the function is not called.
+        self.call_hint = None;
+        let retval = retval.borrow_mut().take();
+        retval.unwrap_or_else(|| Ok(NodeInfo::Leaf(Cow::Borrowed("")).into_node()))
+    }
+
+    /// Hand over the value at the end of the path to the capture callback, if there is one.
+    fn capture(&self, dynvalue: &dyn Access) -> Option<Result<NodeTree, ClimbError>> {
+        let capture = self.objects.capture.as_ref()?;
+        let res = (*capture.borrow_mut())(dynvalue);
+
+        Some(
+            res.map(|()| NodeInfo::Leaf(Cow::Borrowed("")).into_node())
+                .map_err(ClimbError::CallError),
+        )
+    }
+
+    /// The function whose arguments are given at the end of the expression, if the call is not
+    /// complete.
+    pub fn call_hint(&self) -> Option<&'static Function> {
+        self.call_hint
+    }
+
+    /// A hint about the values accepted by the field being assigned at the end of the expression,
+    /// if it has one.
+    pub fn assign_hint(&self) -> Option<&'static str> {
+        self.assign_hint
+    }
+
+    /// The doc comments describing the value at the end of the expression, given by the field
+    /// holding it and by its type.
+    pub fn docs(&self) -> Docs {
+        self.docs
+    }
+
+    /// The type of the value at the end of the expression, unless it is the result of a function
+    /// call that was not made.
+    pub fn type_info(&self) -> Option<TypeInfo> {
+        self.type_info
+    }
+
+    /// The number of modifications made so far, by assignments and by calls of functions via a
+    /// mutable path. Used by `#[derive(Interact)]` for fields having the `on_change` attribute.
+    pub fn changes(&self) -> usize {
+        self.changes
+    }
+
+    /// The name of the field accessed next, e.g. `port` for the remaining expression `.port = 1`.
+    pub fn peek_field(&self) -> Option<String> {
+        if self.tokenvec.remaining() < 2 {
+            return None;
+        }
+        match (self.tokenvec.top_kind(), self.tokenvec.peek(1)) {
+            (TokenKind::FieldAccess, Some(token)) => match token.kind {
+                TokenKind::Ident => Some(String::from(token.text.as_ref())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub(crate) fn interrupted(&self) -> bool {
+        self.reflector.interrupted()
+    }
+
+    /// Select how buffers of `u8` values are rendered in the reflection of the result.
+    pub fn set_bytes_mode(&mut self, bytes_mode: BytesMode) {
+        self.reflector = Reflector::with_interrupt(
+            self.reflector.limit(),
+            bytes_mode,
+            self.reflector.interrupt().cloned(),
+        );
+    }
+
     pub fn general_access_immut<'b>(
         &mut self,
         dynvalue: &'b dyn Access,
     ) -> Result<NodeTree, ClimbError> {
         use crate::Reflect::*;
 
+        if self.reflector.interrupted() {
+            return Err(ClimbError::Interrupted);
+        }
+
         self.expect = ExpectTree::new();
         self.valid_pos = self.tokenvec.pos();
+        self.type_info = Some(dynvalue.type_info());
 
         if !self.tokenvec.is_empty() {
             if let TokenKind::Assign = &self.tokenvec.top_kind() {
@@ -368,6 +756,10 @@ impl<'a> Climber<'a> {
             return Err(ClimbError::UnexpectedToken);
         }
 
+        if let Some(res) = self.capture(dynvalue) {
+            return res;
+        }
+
         Ok(Reflector::reflect(&self.reflector, dynvalue))
     }
 
@@ -375,8 +767,13 @@ impl<'a> Climber<'a> {
         &mut self,
         dynvalue: &'b mut dyn Access,
     ) -> Result<NodeTree, ClimbError> {
+        if self.reflector.interrupted() {
+            return Err(ClimbError::Interrupted);
+        }
+
         self.expect = ExpectTree::new();
         self.valid_pos = self.tokenvec.pos();
+        self.type_info = Some(dynvalue.type_info());
 
         if !self.tokenvec.is_empty() {
             if let TokenKind::Assign = &self.tokenvec.top_kind() {
@@ -385,8 +782,12 @@ impl<'a> Climber<'a> {
                 let probe_only = self.probe_only;
                 let mut tracker = self.borrow_tracker();
                 let res = dynvalue.mut_assign(&mut tracker, probe_only);
+                self.assign_hint = tracker.hint();
                 return match res {
                     Ok(()) => {
+                        if !probe_only {
+                            self.changes += 1;
+                        }
                         self.valid_pos = self.tokenvec.pos();
                         Ok(NodeInfo::Leaf(Cow::Borrowed("")).into_node())
                     }
@@ -567,6 +968,140 @@ impl<'a> Climber<'a> {
         }
     }
 
+    /// Climb into the value of a transparent newtype that keeps its own reflection, such as one
+    /// having `#[interact(transparent = "named")]`. If the expression ends at the newtype, it
+    /// reflects itself.
+    pub fn newtype_immut(&mut self, inner: &dyn Access) -> Result<Option<NodeTree>, ClimbError> {
+        if self.tokenvec.is_empty() {
+            return Ok(None);
+        }
+
+        self.general_access_immut(inner).map(Some)
+    }
+
+    /// Like `newtype_immut`, for a mutable path.
+    pub fn newtype_mut(&mut self, inner: &mut dyn Access) -> Result<Option<NodeTree>, ClimbError> {
+        if self.tokenvec.is_empty() {
+            return Ok(None);
+        }
+
+        self.general_access_mut(inner).map(Some)
+    }
+
+    /// Offer the keys of a collection for completion after an opened subscript, e.g. `map[`,
+    /// rendered as literals and filtered by the partially typed key, if any. Keys are only
+    /// rendered when the expression ends inside the subscript, and at most
+    /// `MAX_SUBSCRIPT_COMPLETIONS` of them, which are then offered in sorted order.
+    pub fn complete_keys<'b>(
+        &mut self,
+        keys: impl Iterator<Item = &'b dyn Access>,
+    ) -> Result<(), ClimbError> {
+        let typed = match self.typed_subscript() {
+            Some(typed) => typed,
+            None => return Ok(()),
+        };
+
+        // Keys are rendered in full, as a cut-off rendering is not a valid literal.
+        let mut matching: Vec<_> = keys
+            .map(|key| {
+                Reflector::reflect(&Reflector::new(usize::MAX), key)
+                    .info
+                    .to_string()
+            })
+            .filter(|key| key.starts_with(&typed))
+            .take(MAX_SUBSCRIPT_COMPLETIONS)
+            .collect();
+        matching.sort();
+
+        self.expect_subscripts(matching)
+    }
+
+    /// Offer the given subscripts for completion after an opened subscript, e.g. the valid
+    /// indices of a `Vec`. If any matches, the expression is incomplete and an error is returned.
+    pub fn complete_subscript(
+        &mut self,
+        subscripts: impl Iterator<Item = String>,
+    ) -> Result<(), ClimbError> {
+        let typed = match self.typed_subscript() {
+            Some(typed) => typed,
+            None => return Ok(()),
+        };
+
+        let matching: Vec<_> = subscripts
+            .filter(|subscript| subscript.starts_with(&typed))
+            .take(MAX_SUBSCRIPT_COMPLETIONS)
+            .collect();
+
+        self.expect_subscripts(matching)
+    }
+
+    /// The partially typed subscript, if the expression ends inside an opened subscript.
+    fn typed_subscript(&self) -> Option<String> {
+        match self.tokenvec.remaining() {
+            0 => Some(String::new()),
+            1 => Some(self.tokenvec.top().text.to_string()),
+            _ => None,
+        }
+    }
+
+    fn expect_subscripts(&mut self, subscripts: Vec<String>) -> Result<(), ClimbError> {
+        if subscripts.is_empty() {
+            return Ok(());
+        }
+
+        for subscript in subscripts {
+            self.expect_token(TokenKind::Ident, Cow::Owned(subscript));
+            self.expect_token(TokenKind::SubscriptClose, Cow::Borrowed("]"));
+            self.expect.retract_one();
+            self.expect.retract_one();
+        }
+
+        Err(ClimbError::UnexpectedExpressionEnd)
+    }
+
+    /// Whether the subscript of an element ends with an assignment, e.g. `["key"] = value`.
+    fn is_subscript_assign(&self) -> bool {
+        match (self.tokenvec.peek(0), self.tokenvec.peek(1)) {
+            (Some(close), Some(assign)) => matches!(
+                (&close.kind, &assign.kind),
+                (TokenKind::SubscriptClose, TokenKind::Assign)
+            ),
+            _ => false,
+        }
+    }
+
+    /// The error for a subscripted element that is missing, such as an absent key of a map. If it
+    /// is being assigned to, a mutable path is needed for inserting it.
+    pub fn missing_immut(&self) -> ClimbError {
+        if self.is_subscript_assign() {
+            ClimbError::NeedMutPath
+        } else {
+            ClimbError::NotFound
+        }
+    }
+
+    /// Handle a subscripted element that is missing. If it is being assigned to, the value is
+    /// parsed, and unless probing, handed over to `insert`.
+    pub fn missing_mut<T: Access>(
+        &mut self,
+        insert: impl FnOnce(T),
+    ) -> Result<NodeTree, ClimbError> {
+        if !self.is_subscript_assign() {
+            return Err(ClimbError::NotFound);
+        }
+        self.tokenvec.advance(2);
+
+        let value = T::deser_new(&mut self.borrow_tracker())
+            .map_err(|err| ClimbError::AssignError(crate::access::AssignError::Deser(err)))?;
+        if !self.probe_only {
+            insert(value);
+            self.changes += 1;
+        }
+        self.valid_pos = self.tokenvec.pos();
+
+        Ok(NodeInfo::Leaf(Cow::Borrowed("")).into_node())
+    }
+
     pub fn is_probe_only(&self) -> bool {
         self.probe_only
     }
@@ -7,12 +7,19 @@ pub enum DeserError {
     NumberTooSmall,
     UnexpectedToken,
     Unbuildable,
+    WrongLength,
+    FromStr(String),
+    Custom(String),
+
+    /// A function argument having no default value was not given.
+    MissingArgument(&'static str),
 }
 
 pub struct Tracker<'a, 'b> {
     expect: &'b mut ExpectTree<Token<'static>>,
     tokenvec: &'b mut TokenVec<'a>,
     steps: usize,
+    hint: Option<&'static str>,
 }
 
 pub type Result<T> = std::result::Result<T, DeserError>;
@@ -23,9 +30,20 @@ impl<'a, 'b> Tracker<'a, 'b> {
             expect,
             tokenvec,
             steps: 0,
+            hint: None,
         }
     }
 
+    /// Give a hint about the accepted values, such as the range of a field having the `range`
+    /// attribute.
+    pub fn set_hint(&mut self, hint: &'static str) {
+        self.hint = Some(hint);
+    }
+
+    pub fn hint(&self) -> Option<&'static str> {
+        self.hint
+    }
+
     pub fn possible_token(&mut self, token: Token<'static>) {
         self.expect.advance(token);
         self.expect.retract_one();
@@ -68,6 +86,11 @@ impl<'a, 'b> Tracker<'a, 'b> {
         &self.top().kind
     }
 
+    /// Look at a token following the top one, if there is one.
+    pub fn peek(&self, n: usize) -> Option<&Token<'a>> {
+        self.tokenvec.peek(n)
+    }
+
     pub fn step(&mut self) {
         *self.expect = ExpectTree::new();
         self.tokenvec.step();
@@ -81,6 +104,9 @@ pub trait Deser: Sized {
     }
 }
 
+pub(crate) mod args;
+pub use args::{deser_args, ArgCallback};
+
 mod basic;
 mod btreemap;
 mod derefs;
@@ -89,5 +115,7 @@ mod hashset;
 mod instant;
 mod mutex;
 mod refcell;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod tuple;
 mod vec;
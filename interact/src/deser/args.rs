@@ -0,0 +1,218 @@
+use crate::access::object::ObjectPath;
+use crate::access::{Argument, CallError};
+use crate::deser::{DeserError, Result, Tracker};
+use crate::tokens::{parse_to_tokens, Token, TokenKind, TokenVec};
+use crate::ExpectTree;
+
+/// A callback that parses the value of the argument at the given index.
+pub type ArgCallback<'c> =
+    dyn for<'x, 'y> FnMut(usize, &mut Tracker<'x, 'y>) -> std::result::Result<(), CallError> + 'c;
+
+/// Parse the arguments of a function call, such as `(1, true)`, calling `f` for the value of each
+/// argument.
+///
+/// Arguments can also be given by name, such as `(capacity: 64)`, after which the rest of them
+/// need to be given by name too. Omitted arguments are parsed from their default values.
+pub fn deser_args<'a, 'b>(
+    tracker: &mut Tracker<'a, 'b>,
+    args: &'static [Argument],
+    f: &mut ArgCallback,
+) -> std::result::Result<(), CallError> {
+    parse_args(tracker, args, &|_| false, f).map(|_| ())
+}
+
+/// Parse the arguments of a function call like `deser_args`, where arguments starting with a name
+/// for which `is_object` returns true are paths to objects. These are not passed to `f`, and are
+/// returned instead.
+pub(crate) fn parse_args<'a, 'b>(
+    tracker: &mut Tracker<'a, 'b>,
+    args: &'static [Argument],
+    is_object: &dyn Fn(&str) -> bool,
+    f: &mut ArgCallback,
+) -> std::result::Result<Vec<ObjectPath>, CallError> {
+    let mut objects = vec![];
+    parse_values(tracker, args, is_object, &mut objects, f)?;
+    Ok(objects)
+}
+
+fn parse_values<'a, 'b>(
+    tracker: &mut Tracker<'a, 'b>,
+    args: &'static [Argument],
+    is_object: &dyn Fn(&str) -> bool,
+    objects: &mut Vec<ObjectPath>,
+    f: &mut ArgCallback,
+) -> std::result::Result<(), CallError> {
+    let open = Token::new_borrowed(TokenKind::TupleOpen, "(");
+    let close = Token::new_borrowed(TokenKind::TupleClose, ")");
+    let comma = Token::new_borrowed(TokenKind::Comma, ", ");
+
+    let mut given = vec![false; args.len()];
+    let mut positional = 0;
+    let mut named = false;
+
+    tracker.try_token(&open)?;
+
+    let mut done = tracker.has_remaining() && *tracker.top_kind() == TokenKind::TupleClose;
+    if done {
+        tracker.step();
+    }
+
+    while !done {
+        let idx = match named_arg(tracker, args, &given)? {
+            Some(idx) => {
+                named = true;
+                idx
+            }
+            None if named || positional >= args.len() => {
+                return Err(CallError::Deser(DeserError::UnexpectedToken));
+            }
+            None => {
+                positional += 1;
+                positional - 1
+            }
+        };
+
+        match object_path(tracker, is_object) {
+            Some(path) => objects.push((idx, path)),
+            None => f(idx, tracker)?,
+        }
+        given[idx] = true;
+
+        if !tracker.has_remaining() {
+            let remaining = args
+                .iter()
+                .zip(given.iter())
+                .any(|(arg, given)| !given && arg.default.is_none());
+            let more = given.iter().any(|given| !given);
+            if more {
+                tracker.possible_token(comma);
+            }
+            if !remaining {
+                tracker.possible_token(close);
+            }
+            return Err(CallError::Deser(DeserError::EndOfTokenList));
+        }
+
+        match tracker.top_kind() {
+            TokenKind::Comma => tracker.step(),
+            TokenKind::TupleClose => {
+                tracker.step();
+                done = true;
+            }
+            _ => return Err(CallError::Deser(DeserError::UnexpectedToken)),
+        }
+    }
+
+    for (idx, arg) in args.iter().enumerate() {
+        if given[idx] {
+            continue;
+        }
+
+        match arg.default {
+            Some(default) => deser_default(default, idx, f)?,
+            None => {
+                return Err(CallError::Deser(DeserError::MissingArgument(arg.name)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// If the next argument is a path to an object, take its tokens up to the end of the argument.
+fn object_path<'a, 'b>(
+    tracker: &mut Tracker<'a, 'b>,
+    is_object: &dyn Fn(&str) -> bool,
+) -> Option<Vec<Token<'static>>> {
+    if !tracker.has_remaining() {
+        return None;
+    }
+    match tracker.top_kind() {
+        TokenKind::Ident if is_object(tracker.top().text.as_ref()) => {}
+        _ => return None,
+    }
+
+    let mut path = vec![];
+    let mut depth = 0;
+    while let Some(token) = tracker.peek(0) {
+        match token.kind {
+            TokenKind::TupleOpen | TokenKind::SubscriptOpen | TokenKind::CurlyOpen => depth += 1,
+            TokenKind::TupleClose | TokenKind::SubscriptClose | TokenKind::CurlyClose
+                if depth > 0 =>
+            {
+                depth -= 1
+            }
+            TokenKind::TupleClose | TokenKind::Comma if depth == 0 => break,
+            _ => {}
+        }
+        path.push(token.clone_owned());
+        tracker.step();
+    }
+
+    Some(path)
+}
+
+/// If the next argument is given by name, return its index.
+fn named_arg<'a, 'b>(
+    tracker: &mut Tracker<'a, 'b>,
+    args: &'static [Argument],
+    given: &[bool],
+) -> Result<Option<usize>> {
+    if !tracker.has_remaining() {
+        return Ok(None);
+    }
+
+    let text = match tracker.top_kind() {
+        TokenKind::Ident => String::from(tracker.top().text.as_ref()),
+        _ => return Ok(None),
+    };
+
+    match tracker.peek(1).map(|token| &token.kind) {
+        Some(TokenKind::Colon) => {
+            let idx = args
+                .iter()
+                .position(|arg| arg.name == text)
+                .ok_or(DeserError::UnexpectedToken)?;
+            if given[idx] {
+                return Err(DeserError::UnexpectedToken);
+            }
+            tracker.step();
+            tracker.step();
+            Ok(Some(idx))
+        }
+        None => {
+            // This may be a partially typed argument name.
+            for (arg, given) in args.iter().zip(given.iter()) {
+                if !given && arg.name.starts_with(text.as_str()) {
+                    tracker.possible_token(Token::new_borrowed(TokenKind::Ident, arg.name));
+                }
+            }
+            Ok(None)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn deser_default(
+    default: &'static str,
+    idx: usize,
+    f: &mut ArgCallback,
+) -> std::result::Result<(), CallError> {
+    let invalid = || {
+        CallError::Deser(DeserError::Custom(format!(
+            "invalid default value `{}`",
+            default
+        )))
+    };
+    let tokens = parse_to_tokens(default).map_err(|_| invalid())?;
+    let mut expect = ExpectTree::new();
+    let mut tokenvec = TokenVec::new(&tokens);
+    let mut tracker = Tracker::new(&mut expect, &mut tokenvec);
+
+    f(idx, &mut tracker)?;
+    if tracker.has_remaining() {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,526 @@
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+
+use crate::access::serde::Serde;
+use crate::deser::{Deser, DeserError, Result, Tracker};
+use crate::tokens::{Token, TokenKind};
+
+/// The error type used when bridging to `serde`. It either originates from the Interact tokens,
+/// or from the `serde` implementation of the type.
+#[derive(Debug)]
+pub enum Error {
+    Deser(DeserError),
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Deser(e) => write!(f, "{:?}", e),
+            Error::Custom(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl From<DeserError> for Error {
+    fn from(e: DeserError) -> Self {
+        Error::Deser(e)
+    }
+}
+
+impl From<Error> for DeserError {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Deser(e) => e,
+            Error::Custom(s) => DeserError::Custom(s),
+        }
+    }
+}
+
+impl<T> Deser for Serde<T>
+where
+    T: DeserializeOwned,
+{
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> Result<Self> {
+        let mut deserializer = TrackerDeserializer { tracker };
+        Ok(Serde(T::deserialize(&mut deserializer)?))
+    }
+}
+
+/// Feeds the tokens of a `Tracker` into `serde`, accepting the same syntax as the equivalent
+/// `#[derive(Interact)]` types, and providing completions where possible.
+struct TrackerDeserializer<'t, 'a, 'b> {
+    tracker: &'t mut Tracker<'a, 'b>,
+}
+
+type DeResult<T> = std::result::Result<T, Error>;
+
+const OPEN_TUPLE: Token<'static> = Token {
+    kind: TokenKind::TupleOpen,
+    text: std::borrow::Cow::Borrowed("("),
+    space_diff: 0,
+};
+
+const CLOSE_TUPLE: Token<'static> = Token {
+    kind: TokenKind::TupleClose,
+    text: std::borrow::Cow::Borrowed(")"),
+    space_diff: 0,
+};
+
+const OPEN_SUBSCRIPT: Token<'static> = Token {
+    kind: TokenKind::SubscriptOpen,
+    text: std::borrow::Cow::Borrowed("["),
+    space_diff: 0,
+};
+
+const CLOSE_SUBSCRIPT: Token<'static> = Token {
+    kind: TokenKind::SubscriptClose,
+    text: std::borrow::Cow::Borrowed("]"),
+    space_diff: 0,
+};
+
+const OPEN_CURLY: Token<'static> = Token {
+    kind: TokenKind::CurlyOpen,
+    text: std::borrow::Cow::Borrowed(" {"),
+    space_diff: 0,
+};
+
+const CLOSE_CURLY: Token<'static> = Token {
+    kind: TokenKind::CurlyClose,
+    text: std::borrow::Cow::Borrowed("}"),
+    space_diff: 0,
+};
+
+const COMMA: Token<'static> = Token {
+    kind: TokenKind::Comma,
+    text: std::borrow::Cow::Borrowed(", "),
+    space_diff: 0,
+};
+
+const COLON: Token<'static> = Token {
+    kind: TokenKind::Colon,
+    text: std::borrow::Cow::Borrowed(": "),
+    space_diff: 0,
+};
+
+impl<'t, 'a, 'b> TrackerDeserializer<'t, 'a, 'b> {
+    fn ident(name: &'static str) -> Token<'static> {
+        Token::new_borrowed(TokenKind::Ident, name)
+    }
+
+    /// Expect one of the given identifiers, offering the ones matching a prefix as completions.
+    fn expect_ident(&mut self, names: &'static [&'static str]) -> DeResult<&'static str> {
+        if !self.tracker.has_remaining() {
+            for name in names {
+                self.tracker.possible_token(Self::ident(name));
+            }
+            return Err(DeserError::EndOfTokenList.into());
+        }
+
+        if let TokenKind::Ident = self.tracker.top_kind() {
+            let text = String::from(self.tracker.top().text.as_ref());
+            if let Some(name) = names.iter().find(|name| **name == text) {
+                self.tracker.step();
+                return Ok(*name);
+            }
+
+            for name in names {
+                if name.starts_with(&text) {
+                    self.tracker.possible_token(Self::ident(name));
+                }
+            }
+        }
+
+        Err(DeserError::UnexpectedToken.into())
+    }
+
+    fn try_token(&mut self, token: &Token<'static>) -> DeResult<()> {
+        self.tracker.try_token(token)?;
+        Ok(())
+    }
+
+    fn top_is(&self, kind: &TokenKind) -> bool {
+        self.tracker.has_remaining() && self.tracker.top_kind() == kind
+    }
+
+    fn items<'de, V: Visitor<'de>>(
+        &mut self,
+        open: Token<'static>,
+        close: Token<'static>,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&open)?;
+
+        let mut items = Items {
+            de: self,
+            close,
+            first: true,
+            done: false,
+        };
+
+        let value = visitor.visit_seq(&mut items)?;
+        if !items.done {
+            let close = items.close.clone();
+            self.try_token(&close)?;
+        }
+
+        Ok(value)
+    }
+
+    fn entries<'de, V: Visitor<'de>>(
+        &mut self,
+        fields: Option<&'static [&'static str]>,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&OPEN_CURLY)?;
+
+        let mut entries = Entries {
+            de: self,
+            fields,
+            seen: vec![],
+            first: true,
+            done: false,
+        };
+
+        let value = visitor.visit_map(&mut entries)?;
+        if !entries.done {
+            self.try_token(&CLOSE_CURLY)?;
+        }
+
+        Ok(value)
+    }
+}
+
+macro_rules! deserialize_deser {
+    ($($method:ident => $visit:ident($t:ty)),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+                visitor.$visit(<$t as Deser>::deser(self.tracker)?)
+            }
+        )*
+    };
+}
+
+impl<'de, 't, 'a, 'b, 'x> de::Deserializer<'de> for &'x mut TrackerDeserializer<'t, 'a, 'b> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        if !self.tracker.has_remaining() {
+            return Err(DeserError::EndOfTokenList.into());
+        }
+
+        match self.tracker.top_kind().clone() {
+            TokenKind::NonNegativeDecimal(v) => {
+                self.tracker.step();
+                visitor.visit_u64(v)
+            }
+            TokenKind::Decimal(v) => {
+                self.tracker.step();
+                visitor.visit_i64(v)
+            }
+            TokenKind::String(v) => {
+                self.tracker.step();
+                visitor.visit_string(v)
+            }
+            TokenKind::Char(v) => {
+                self.tracker.step();
+                visitor.visit_char(v)
+            }
+            TokenKind::Bytes(v) => {
+                self.tracker.step();
+                visitor.visit_byte_buf(v)
+            }
+            TokenKind::SubscriptOpen => self.items(OPEN_SUBSCRIPT, CLOSE_SUBSCRIPT, visitor),
+            TokenKind::TupleOpen => self.items(OPEN_TUPLE, CLOSE_TUPLE, visitor),
+            TokenKind::CurlyOpen => self.entries(None, visitor),
+            TokenKind::Ident => {
+                let text = String::from(self.tracker.top().text.as_ref());
+                match text.as_str() {
+                    "true" | "false" => self.deserialize_bool(visitor),
+                    "None" | "Some" => self.deserialize_option(visitor),
+                    _ => {
+                        self.tracker.step();
+                        if self.top_is(&TokenKind::CurlyOpen) {
+                            self.entries(None, visitor)
+                        } else if self.top_is(&TokenKind::TupleOpen) {
+                            self.items(OPEN_TUPLE, CLOSE_TUPLE, visitor)
+                        } else {
+                            visitor.visit_string(text)
+                        }
+                    }
+                }
+            }
+            _ => Err(DeserError::UnexpectedToken.into()),
+        }
+    }
+
+    deserialize_deser!(
+        deserialize_bool => visit_bool(bool),
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_char => visit_char(char),
+        deserialize_str => visit_string(String),
+        deserialize_string => visit_string(String),
+        deserialize_bytes => visit_byte_buf(Vec<u8>),
+        deserialize_byte_buf => visit_byte_buf(Vec<u8>)
+    );
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        match self.expect_ident(&["None", "Some"])? {
+            "None" => visitor.visit_none(),
+            _ => {
+                self.try_token(&OPEN_TUPLE)?;
+                let value = visitor.visit_some(&mut *self)?;
+                self.try_token(&CLOSE_TUPLE)?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.try_token(&OPEN_TUPLE)?;
+        self.try_token(&CLOSE_TUPLE)?;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&TrackerDeserializer::ident(name))?;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&TrackerDeserializer::ident(name))?;
+        self.try_token(&OPEN_TUPLE)?;
+        let value = visitor.visit_newtype_struct(&mut *self)?;
+        self.try_token(&CLOSE_TUPLE)?;
+        Ok(value)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.items(OPEN_SUBSCRIPT, CLOSE_SUBSCRIPT, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> DeResult<V::Value> {
+        self.items(OPEN_TUPLE, CLOSE_TUPLE, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&TrackerDeserializer::ident(name))?;
+        self.items(OPEN_TUPLE, CLOSE_TUPLE, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.entries(None, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.try_token(&TrackerDeserializer::ident(name))?;
+        self.entries(Some(fields), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        let variant = self.expect_ident(variants)?;
+        visitor.visit_enum(Variant { de: self, variant })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+/// Items of sequences and tuples, separated by commas.
+struct Items<'x, 't, 'a, 'b> {
+    de: &'x mut TrackerDeserializer<'t, 'a, 'b>,
+    close: Token<'static>,
+    first: bool,
+    done: bool,
+}
+
+impl<'de, 'x, 't, 'a, 'b> de::SeqAccess<'de> for Items<'x, 't, 'a, 'b> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> DeResult<Option<T::Value>> {
+        if !self.de.tracker.has_remaining() {
+            if !self.first {
+                self.de.tracker.possible_token(COMMA);
+            }
+            self.de.tracker.possible_token(self.close.clone());
+            return Err(DeserError::EndOfTokenList.into());
+        }
+
+        if self.de.top_is(&self.close.kind) {
+            self.de.tracker.step();
+            self.done = true;
+            return Ok(None);
+        }
+
+        if !self.first {
+            self.de.try_token(&COMMA)?;
+        }
+        self.first = false;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+/// Entries of maps and structs, separated by commas. For structs, the keys are the field names.
+struct Entries<'x, 't, 'a, 'b> {
+    de: &'x mut TrackerDeserializer<'t, 'a, 'b>,
+    fields: Option<&'static [&'static str]>,
+    seen: Vec<&'static str>,
+    first: bool,
+    done: bool,
+}
+
+impl<'de, 'x, 't, 'a, 'b> de::MapAccess<'de> for Entries<'x, 't, 'a, 'b> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> DeResult<Option<K::Value>> {
+        if !self.de.tracker.has_remaining() {
+            if !self.first {
+                self.de.tracker.possible_token(COMMA);
+            }
+            self.de.tracker.possible_token(CLOSE_CURLY);
+            return Err(DeserError::EndOfTokenList.into());
+        }
+
+        if self.de.top_is(&TokenKind::CurlyClose) {
+            self.de.tracker.step();
+            self.done = true;
+            return Ok(None);
+        }
+
+        if !self.first {
+            self.de.try_token(&COMMA)?;
+        }
+        self.first = false;
+
+        let fields = match self.fields {
+            Some(fields) => fields,
+            None => return seed.deserialize(&mut *self.de).map(Some),
+        };
+
+        if !self.de.tracker.has_remaining() {
+            let seen = &self.seen;
+            for field in fields.iter().filter(|f| !seen.contains(f)) {
+                self.de
+                    .tracker
+                    .possible_token(TrackerDeserializer::ident(field));
+            }
+            return Err(DeserError::EndOfTokenList.into());
+        }
+
+        let field = self.de.expect_ident(fields)?;
+        self.seen.push(field);
+
+        let key: de::value::StrDeserializer<Error> = field.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> DeResult<V::Value> {
+        self.de.try_token(&COLON)?;
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+/// The variant of an enum, following its name.
+struct Variant<'x, 't, 'a, 'b> {
+    de: &'x mut TrackerDeserializer<'t, 'a, 'b>,
+    variant: &'static str,
+}
+
+impl<'de, 'x, 't, 'a, 'b> de::EnumAccess<'de> for Variant<'x, 't, 'a, 'b> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> DeResult<(T::Value, Self)> {
+        let key: de::value::StrDeserializer<Error> = self.variant.into_deserializer();
+        Ok((seed.deserialize(key)?, self))
+    }
+}
+
+impl<'de, 'x, 't, 'a, 'b> de::VariantAccess<'de> for Variant<'x, 't, 'a, 'b> {
+    type Error = Error;
+
+    fn unit_variant(self) -> DeResult<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> DeResult<T::Value> {
+        self.de.try_token(&OPEN_TUPLE)?;
+        let value = seed.deserialize(&mut *self.de)?;
+        self.de.try_token(&CLOSE_TUPLE)?;
+        Ok(value)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> DeResult<V::Value> {
+        self.de.items(OPEN_TUPLE, CLOSE_TUPLE, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.de.entries(Some(fields), visitor)
+    }
+}
@@ -1,16 +1,109 @@
-use crate::deser::Deser;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use crate::deser::{Deser, DeserError, Result, Tracker};
+use crate::tokens::{Token, TokenKind, TokenVec};
+use crate::ExpectTree;
+
+/// Parse a list of items, given either as `[a, b, c]`, or as a byte string literal such as
+/// `b"abc"` or `x"616263"`, in which case each byte is parsed as a number.
+fn deser_items<'a, 'b, T>(tracker: &mut Tracker<'a, 'b>) -> Result<Vec<T>>
+where
+    T: Deser,
+{
+    let open = Token::new_borrowed(TokenKind::SubscriptOpen, "[");
+    let close = Token::new_borrowed(TokenKind::SubscriptClose, "]");
+    let comma = Token::new_borrowed(TokenKind::Comma, ", ");
+
+    if !tracker.has_remaining() {
+        tracker.possible_token(open);
+        return Err(DeserError::EndOfTokenList);
+    }
+
+    if let TokenKind::Bytes(bytes) = tracker.top_kind() {
+        let items = deser_bytes(bytes)?;
+        tracker.step();
+        return Ok(items);
+    }
+
+    tracker.try_token(&open)?;
+
+    let mut items = vec![];
+    if tracker.has_remaining() {
+        if let TokenKind::SubscriptClose = tracker.top_kind() {
+            tracker.step();
+            return Ok(items);
+        }
+    }
+
+    loop {
+        items.push(T::deser(tracker)?);
+
+        if !tracker.has_remaining() {
+            tracker.possible_token(comma);
+            tracker.possible_token(close);
+            return Err(DeserError::EndOfTokenList);
+        }
+
+        match tracker.top_kind() {
+            TokenKind::Comma => tracker.step(),
+            TokenKind::SubscriptClose => {
+                tracker.step();
+                return Ok(items);
+            }
+            _ => return Err(DeserError::UnexpectedToken),
+        }
+    }
+}
+
+fn deser_bytes<T>(bytes: &[u8]) -> Result<Vec<T>>
+where
+    T: Deser,
+{
+    let tokens: Vec<_> = bytes
+        .iter()
+        .map(|b| Token {
+            kind: TokenKind::NonNegativeDecimal(u64::from(*b)),
+            text: Cow::Owned(format!("{}", b)),
+            space_diff: 0,
+        })
+        .collect();
+
+    let mut expect = ExpectTree::new();
+    let mut tokenvec = TokenVec::new(&tokens);
+    let mut tracker = Tracker::new(&mut expect, &mut tokenvec);
+    let mut items = vec![];
+
+    while tracker.has_remaining() {
+        items.push(T::deser(&mut tracker)?);
+    }
+
+    Ok(items)
+}
 
 impl<T> Deser for &[T] where T: Deser {}
 
 impl<T> Deser for &mut [T] where T: Deser {}
 
-// TODO: implement
-impl<T> Deser for Vec<T> where T: Deser {}
+impl<T> Deser for Vec<T>
+where
+    T: Deser,
+{
+    fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> Result<Self> {
+        deser_items(tracker)
+    }
+}
 
 macro_rules! sized_iter {
     ($t:ty) => {
-        // TODO: implement
-        impl<T> Deser for $t where T: Deser {}
+        impl<T> Deser for $t
+        where
+            T: Deser,
+        {
+            fn deser<'a, 'b>(tracker: &mut Tracker<'a, 'b>) -> Result<Self> {
+                <$t>::try_from(deser_items(tracker)?).map_err(|_| DeserError::WrongLength)
+            }
+        }
     };
 }
 
@@ -0,0 +1,80 @@
+//! Running the futures of `async` methods that are called from Interact expressions.
+//!
+//! Interact evaluates expressions synchronously, so the future returned by an `async` method is
+//! driven to completion on the evaluating thread before its result is shown. By default this is
+//! done using `simple_block_on`, which is enough for futures that don't depend on a particular
+//! runtime. Programs using a runtime such as `tokio` can provide their own executor hook using
+//! `set_executor`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// An executor hook, which needs to run the given future to completion before returning.
+pub type Executor = dyn Fn(Pin<&mut dyn Future<Output = ()>>) + Send + Sync;
+
+static EXECUTOR: RwLock<Option<Arc<Executor>>> = RwLock::new(None);
+
+/// Set the executor hook used for running the futures of `async` methods, replacing the built-in
+/// `simple_block_on`.
+///
+/// For example, with `tokio`, providing that evaluation does not happen from within the runtime:
+///
+/// ```ignore
+/// let handle = runtime.handle().clone();
+/// interact::executor::set_executor(move |future| handle.block_on(future));
+/// ```
+pub fn set_executor<F>(executor: F)
+where
+    F: Fn(Pin<&mut dyn Future<Output = ()>>) + Send + Sync + 'static,
+{
+    *EXECUTOR.write().unwrap() = Some(Arc::new(executor));
+}
+
+/// Remove the executor hook, returning to the built-in `simple_block_on`.
+pub fn clear_executor() {
+    *EXECUTOR.write().unwrap() = None;
+}
+
+/// Run a future to completion using the executor hook, if one is set, or otherwise using
+/// `simple_block_on`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let executor = EXECUTOR.read().unwrap().clone();
+    let executor = match executor {
+        None => return simple_block_on(future),
+        Some(executor) => executor,
+    };
+
+    let mut output = None;
+    let mut wrapper: Pin<Box<dyn Future<Output = ()>>> = Box::pin(async {
+        output = Some(future.await);
+    });
+    executor(wrapper.as_mut());
+    drop(wrapper);
+
+    output.expect("the executor hook returned before the future completed")
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future to completion on the current thread, parking it while the future is pending.
+pub fn simple_block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
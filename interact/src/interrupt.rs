@@ -0,0 +1,108 @@
+//! Interrupting evaluations that are in progress, such as on Ctrl-C.
+//!
+//! An evaluation can take long, or even block indefinitely, for example when it waits on a
+//! `ReflectIndirect` value whose owning thread is busy, or when it reflects a large value. An
+//! `Interrupt` is a handle that stops the evaluations started within its `scope` on the same
+//! thread, which then fail with `ClimbError::Interrupted`. The handle can be triggered from any
+//! thread.
+//!
+//! An interruption is only noticed between steps of the evaluation, and while waiting on a
+//! `ReflectIndirect` value. It cannot stop the future of an `async` method while the executor
+//! (see `executor`) is blocking on it, nor a wait for a `Mutex` lock, so such an evaluation fails
+//! only after these return.
+//!
+//! ```ignore
+//! let interrupt = Interrupt::new();
+//! let handle = interrupt.clone();
+//! std::thread::spawn(move || {
+//!     std::thread::sleep(std::time::Duration::from_secs(5));
+//!     handle.interrupt();
+//! });
+//! let (res, _) = interrupt.scope(|| root.access("slow"));
+//! ```
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often a blocked evaluation checks whether it was interrupted.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+thread_local! {
+    static CURRENT: RefCell<Option<Interrupt>> = const { RefCell::new(None) };
+}
+
+/// A handle for interrupting evaluations. Clones of it share the same state.
+///
+/// It does not stop an `async` method blocked on by the executor, or a `Mutex` lock wait.
+#[derive(Clone, Debug, Default)]
+pub struct Interrupt {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl Interrupt {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Interrupt the evaluations in progress in the scopes of this handle.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
+    /// Run `f`, with the evaluations it starts on the current thread being subject to this
+    /// handle. A previous interruption of the handle is cleared on entry.
+    pub fn scope<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        struct Restore(Option<Interrupt>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let prev = self.0.take();
+                CURRENT.with(|current| *current.borrow_mut() = prev);
+            }
+        }
+
+        self.interrupted.store(false, Ordering::SeqCst);
+        let prev = CURRENT.with(|current| current.borrow_mut().replace(self.clone()));
+        let _restore = Restore(prev);
+        f()
+    }
+}
+
+/// The handle of the innermost scope on the current thread.
+pub(crate) fn current() -> Option<Interrupt> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+pub(crate) fn is_interrupted(interrupt: Option<&Interrupt>) -> bool {
+    match interrupt {
+        Some(interrupt) => interrupt.is_interrupted(),
+        None => false,
+    }
+}
+
+/// Wait for a value from the receiver, returning `None` if interrupted before it arrives.
+pub(crate) fn recv<T>(receiver: &Receiver<T>, interrupt: Option<&Interrupt>) -> Option<T> {
+    let interrupt = match interrupt {
+        None => return Some(receiver.recv().unwrap()),
+        Some(interrupt) => interrupt,
+    };
+
+    loop {
+        if interrupt.is_interrupted() {
+            return None;
+        }
+
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(value) => return Some(value),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => panic!("indirect access dropped its reply"),
+        }
+    }
+}
@@ -72,22 +72,40 @@ pub use crate::reflector::Reflector;
 pub mod access;
 #[doc(hidden)]
 pub use crate::access::{
-    derive::{Enum, ReflectEnum, ReflectStruct, Struct, StructKind},
+    check::{in_range, Check, Checked},
+    derive::{
+        has_unique_fields, inline_field_names, inline_field_owner, inline_field_owner_mut,
+        is_default, Enum, FieldSet, ReflectEnum, ReflectStruct, Struct, StructFields, StructKind,
+    },
     iter::ReflectIter,
-    Function,
+    leaf::Leaf,
+    object::{
+        Arg, CloneArg, DeserArg, NoCloneArg, NoDeserArg, ObjectArgs, ObjectRefs, ResolveObject,
+    },
+    remote::Mirror,
+    view::{Render, View},
+    Argument, Function, Methods,
 };
 
 #[doc(inline)]
 pub use crate::access::{
-    deser_assign, Access, AssignError, CallError, ImmutAccess, MutAccess, Reflect, ReflectDirect,
-    ReflectIndirect, ReflectMut, RetValCallback,
+    bytes::BytesMode, deser_assign, Access, AssignError, CallError, ImmutAccess, MutAccess,
+    Reflect, ReflectDirect, ReflectIndirect, ReflectMut, RetValCallback,
 };
 
+#[doc(inline)]
+pub use crate::access::remote::Remote;
+
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use crate::access::serde::Serde;
+
 // #derive
 #[doc(hidden)]
-pub use interact_derive::derive_interact_extern_opqaue;
+#[allow(deprecated)]
+pub use interact_derive::{derive_interact_extern_opaque, derive_interact_extern_opqaue};
 
-pub use interact_derive::Interact;
+pub use interact_derive::{methods, Interact};
 
 // util
 mod util;
@@ -97,15 +115,31 @@ pub use crate::node_tree::{NodeInfo, NodeTree};
 // climber
 pub mod climber;
 #[doc(inline)]
-pub use crate::climber::{ClimbError, Climber};
+pub use crate::climber::{ClimbError, Climber, Docs};
 
 #[doc(hidden)]
 pub use crate::climber::{EnumOrStruct, EnumOrStructMut};
 
+// executor
+pub mod executor;
+
+// interrupt
+pub mod interrupt;
+#[doc(inline)]
+pub use crate::interrupt::Interrupt;
+
+// redact
+pub mod redact;
+
+// schema
+pub mod schema;
+#[doc(inline)]
+pub use crate::schema::{Schema, Schemas, TypeInfo};
+
 // root
 pub mod root;
 #[doc(inline)]
-pub use crate::root::{Root, RootLocal, RootSend};
+pub use crate::root::{CallDeser, Root, RootFunction, RootLocal, RootSend};
 
 //
 // Internally re-exported
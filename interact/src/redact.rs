@@ -0,0 +1,69 @@
+//! Redaction of secret fields, such as passwords and tokens.
+//!
+//! Fields having the `#[interact(redact)]` attribute are always shown as `<redacted>`. In
+//! addition, fields can be redacted by name, using patterns where `*` matches any sequence of
+//! characters, e.g. `*password*`. Names are matched regardless of case. A field redacted by name
+//! is shown as `<redacted>`, and can neither be climbed into nor assigned. The patterns also
+//! apply to getters, and to the fields of values reflected via `Serde`.
+//!
+//! ```ignore
+//! interact::redact::set_patterns(&["*password*", "*token*"]);
+//! ```
+
+use std::sync::RwLock;
+
+static PATTERNS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// How redacted values are shown.
+pub const REDACTED: &str = "<redacted>";
+
+/// Set the patterns of the names of fields to redact, replacing the previous ones.
+pub fn set_patterns<S: AsRef<str>>(patterns: &[S]) {
+    *PATTERNS.write().unwrap() = patterns
+        .iter()
+        .map(|pattern| pattern.as_ref().to_lowercase())
+        .collect();
+}
+
+/// Remove the patterns, leaving only the fields having the `redact` attribute redacted.
+pub fn clear_patterns() {
+    PATTERNS.write().unwrap().clear();
+}
+
+/// Whether a field of the given name is redacted by one of the patterns.
+pub fn is_redacted(name: &str) -> bool {
+    let patterns = PATTERNS.read().unwrap();
+    if patterns.is_empty() {
+        return false;
+    }
+
+    let name = name.to_lowercase();
+    patterns
+        .iter()
+        .any(|pattern| matches_pattern(pattern, &name))
+}
+
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    if !name.starts_with(first) {
+        return false;
+    }
+
+    let mut rest = &name[first.len()..];
+    let parts: Vec<_> = parts.collect();
+    let (last, middle) = match parts.split_last() {
+        // No `*` in the pattern.
+        None => return rest.is_empty(),
+        Some(split) => split,
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
@@ -7,11 +7,14 @@ use std::thread::ThreadId;
 
 use crate::access::vec::ReflectVec;
 use crate::access::{
-    derive::{ReflectStruct, Struct, StructKind},
+    bytes::BytesMode,
+    derive::{inline_field_names, inline_field_owner, ReflectStruct, Struct, StructKind},
     iter::ReflectIter,
     Access,
 };
+use crate::interrupt::{self, Interrupt};
 use crate::node_tree::{NodeInfo, NodeTree, PtrMeta, Wrap};
+use crate::redact;
 
 type ObjPtr = (usize, usize);
 
@@ -22,21 +25,59 @@ type ObjPtr = (usize, usize);
 pub struct Reflector {
     limit: usize,
     used: AtomicUsize,
+    bytes_mode: BytesMode,
 
     seen: Mutex<HashMap<ObjPtr, PtrMeta>>,
     synced_thread: ThreadId,
+    interrupt: Option<Interrupt>,
 }
 
 impl Reflector {
     pub fn new(limit: usize) -> Arc<Self> {
+        Self::with_bytes_mode(limit, BytesMode::List)
+    }
+
+    /// Create a `Reflector` that renders buffers of `u8` values using the given mode.
+    ///
+    /// The reflection stops short if the `Interrupt` in whose scope the `Reflector` is created
+    /// gets interrupted.
+    pub fn with_bytes_mode(limit: usize, bytes_mode: BytesMode) -> Arc<Self> {
+        Self::with_interrupt(limit, bytes_mode, interrupt::current())
+    }
+
+    pub(crate) fn with_interrupt(
+        limit: usize,
+        bytes_mode: BytesMode,
+        interrupt: Option<Interrupt>,
+    ) -> Arc<Self> {
         Arc::new(Self {
             limit,
             used: AtomicUsize::new(0),
+            bytes_mode,
             seen: Mutex::new(HashMap::new()),
             synced_thread: std::thread::current().id(),
+            interrupt,
         })
     }
 
+    pub(crate) fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub(crate) fn interrupt(&self) -> Option<&Interrupt> {
+        self.interrupt.as_ref()
+    }
+
+    pub(crate) fn interrupted(&self) -> bool {
+        interrupt::is_interrupted(self.interrupt.as_ref())
+    }
+
+    /// Whether no more nodes should be reflected, either because the limit was reached or the
+    /// reflection was interrupted.
+    fn exhausted(&self) -> bool {
+        self.limit <= self.used.load(Ordering::Relaxed) || self.interrupted()
+    }
+
     pub fn reflect_struct(
         a_self: &Arc<Self>,
         desc: &Struct,
@@ -53,7 +94,7 @@ impl Reflector {
                 let mut v = vec![];
 
                 for i in 0..*n {
-                    if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+                    if a_self.exhausted() {
                         v.push(NodeInfo::Limited.into_node());
                         break;
                     }
@@ -73,25 +114,32 @@ impl Reflector {
 
                 elem.with_meta(meta)
             }
-            StructKind::Fields(fields) => {
+            StructKind::Fields(_) => {
                 let mut result = vec![];
                 let mut items = vec![];
                 let mut missing_keys = false;
 
-                for field in *fields {
-                    if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+                for field in inline_field_names(p_struct) {
+                    let owner = inline_field_owner(p_struct, field).unwrap();
+                    if owner.is_field_hidden(field) {
+                        continue;
+                    }
+
+                    if a_self.exhausted() {
                         missing_keys = true;
                         break;
                     }
 
                     a_self.used.fetch_add(1, Ordering::SeqCst);
-                    items.push((field, p_struct.get_field_by_name(field).unwrap()));
+                    items.push((field, owner.get_field_by_name(field).unwrap()));
                 }
 
                 for (key, value) in items.into_iter() {
                     let node = {
-                        if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+                        if a_self.exhausted() {
                             NodeInfo::Limited.into_node()
+                        } else if redact::is_redacted(key) {
+                            NodeInfo::Leaf(std::borrow::Cow::Borrowed(redact::REDACTED)).into_node()
                         } else {
                             Self::reflect(a_self, value)
                         }
@@ -107,6 +155,41 @@ impl Reflector {
                     )
                 }
 
+                for getter in p_struct.get_getters() {
+                    if missing_keys {
+                        break;
+                    }
+
+                    if a_self.exhausted() {
+                        missing_keys = true;
+                        break;
+                    }
+
+                    a_self.used.fetch_add(1, Ordering::SeqCst);
+                    let mut node = None;
+                    if redact::is_redacted(getter) {
+                        let redacted = std::borrow::Cow::Borrowed(redact::REDACTED);
+                        node = Some(NodeInfo::Leaf(redacted).into_node());
+                    } else {
+                        p_struct.with_getter(getter, &mut |value| {
+                            node = Some(Self::reflect_detached(a_self, value));
+                        });
+                    }
+
+                    if let Some(node) = node {
+                        result.push(
+                            NodeInfo::Tuple(
+                                Box::new(
+                                    NodeInfo::Leaf(std::borrow::Cow::Borrowed(getter)).into_node(),
+                                ),
+                                ":",
+                                Box::new(node),
+                            )
+                            .into_node(),
+                        )
+                    }
+                }
+
                 if missing_keys {
                     result.push(NodeInfo::Limited.into_node());
                 }
@@ -140,7 +223,7 @@ impl Reflector {
         let mut missing_keys = false;
 
         while let Some((key, value)) = iter.reflect_next() {
-            if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+            if a_self.exhausted() {
                 missing_keys = true;
                 break;
             }
@@ -151,7 +234,7 @@ impl Reflector {
 
         for (key, value) in items.into_iter() {
             let node = {
-                if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+                if a_self.exhausted() {
                     NodeInfo::Limited.into_node()
                 } else {
                     Self::reflect(a_self, value)
@@ -187,7 +270,7 @@ impl Reflector {
         let meta = try_seen_dyn!(iter, a_self);
 
         while let Some(member) = iter.reflect_next() {
-            if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+            if a_self.exhausted() {
                 v.push(NodeInfo::Limited.into_node());
                 break;
             }
@@ -209,8 +292,14 @@ impl Reflector {
 
         let meta = try_seen_dyn!(vec, a_self);
 
+        if a_self.bytes_mode != BytesMode::List {
+            if let Some(bytes) = vec.get_bytes() {
+                return a_self.bytes_mode.render(&bytes).with_meta(meta);
+            }
+        }
+
         for i in 0..vec.get_len() {
-            if a_self.limit <= a_self.used.load(Ordering::Relaxed) {
+            if a_self.exhausted() {
                 v.push(NodeInfo::Limited.into_node());
                 break;
             }
@@ -230,6 +319,22 @@ impl Reflector {
         item.with_meta(meta)
     }
 
+    /// Reflect a temporary value, such as the one returned from a getter. Its address may be
+    /// reused by other temporaries, so it is not tracked along with the other seen objects.
+    pub fn reflect_detached(a_self: &Arc<Self>, access: &dyn Access) -> NodeTree {
+        let used = a_self.used.load(Ordering::Relaxed);
+        let detached = Self::with_interrupt(
+            a_self.limit.saturating_sub(used),
+            a_self.bytes_mode,
+            a_self.interrupt.clone(),
+        );
+        let node = Self::reflect(&detached, access);
+        a_self
+            .used
+            .fetch_add(detached.used.load(Ordering::Relaxed), Ordering::SeqCst);
+        node
+    }
+
     pub fn seen_ptr(a_self: &Arc<Self>, obj_ptr: ObjPtr) -> Result<NodeTree, PtrMeta> {
         let mut seen = a_self.seen.lock().unwrap();
         match seen.entry(obj_ptr) {
@@ -264,7 +369,8 @@ impl Reflector {
                 }));
 
                 if a_self.synced_thread == std::thread::current().id() {
-                    receiver.recv().unwrap()
+                    interrupt::recv(&receiver, a_self.interrupt())
+                        .unwrap_or_else(|| NodeInfo::Limited.into_node())
                 } else {
                     NodeInfo::Hole(Box::new(receiver)).into_node()
                 }
@@ -1,14 +1,92 @@
+use std::cell::{Cell, RefCell};
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
+use crate::access::object::ResolveObject;
+use crate::schema::{self, Schemas, TypeInfo};
 use crate::{
-    tokens::parse_to_tokens, Access, Assist, ClimbError, Climber, NextOptions, NodeTree, Token,
+    tokens::parse_to_tokens, Access, Assist, BytesMode, CallError, ClimbError, Climber, Docs,
+    Function, NextOptions, NodeTree, Token,
 };
 
+mod function;
+pub use self::function::{CallDeser, DeserFn, RootFunction};
+
+/// Resolves function arguments given as paths to the objects of the root, other than the one
+/// whose function is called.
+struct RootObjects<'r> {
+    current: &'r str,
+    objects: BTreeMap<&'static str, &'r dyn Access>,
+}
+
+impl<'r> ResolveObject for RootObjects<'r> {
+    fn is_object(&self, name: &str) -> bool {
+        name == self.current || self.objects.contains_key(name)
+    }
+
+    fn resolve(
+        &self,
+        arg: &'static str,
+        path: &[Token<'static>],
+        f: &mut dyn FnMut(&dyn Access) -> Result<(), CallError>,
+    ) -> Result<(), CallError> {
+        let access = match self.objects.get(path[0].text.as_ref()) {
+            Some(access) => *access,
+            None => return Err(CallError::ObjectBorrowed(arg)),
+        };
+
+        let found = Cell::new(false);
+        let capture = |access: &dyn Access| {
+            found.set(true);
+            f(access)
+        };
+        let mut climber = Climber::new(200, false, &path[1..]);
+        climber.set_capture(Rc::new(RefCell::new(capture)));
+
+        match climber.general_access_immut(access) {
+            Ok(_) if found.get() => Ok(()),
+            Err(ClimbError::CallError(err)) if found.get() => Err(err),
+            _ => Err(CallError::ObjectNotFound(arg)),
+        }
+    }
+}
+
+/// A hint about the rest of an expression that is being typed.
+enum Hint<'f> {
+    /// The function whose arguments are being given.
+    Call(&'f Function),
+
+    /// The values accepted by the field being assigned.
+    Assign(&'f str),
+}
+
+/// The outcome of evaluating a path, with what is known about its end.
+struct AccessResult<'f> {
+    res: Result<NodeTree, ClimbError>,
+    assist: Assist<String>,
+    hint: Option<Hint<'f>>,
+    docs: Docs,
+    type_info: Option<TypeInfo>,
+}
+
+impl<'f> AccessResult<'f> {
+    fn failed(err: ClimbError, assist: Assist<String>) -> Self {
+        Self {
+            res: Err(err),
+            assist,
+            hint: None,
+            docs: Docs::default(),
+            type_info: None,
+        }
+    }
+}
+
 /// Holds a root dictionary of `Send`-able trait objects that implement `Access` and are therefore
 /// Interact-able. These are most likely objects that are held globally behind an `Arc`.
 #[derive(Default)]
 pub struct RootSend {
     pub owned: BTreeMap<&'static str, Box<dyn Access + Send>>,
+    pub functions: BTreeMap<&'static str, Box<dyn RootFunction + Send>>,
 }
 
 impl RootSend {
@@ -16,11 +94,28 @@ impl RootSend {
         Default::default()
     }
 
+    /// Register an object, panicking if a function of the same name is registered.
+    pub fn insert(&mut self, name: &'static str, item: Box<dyn Access + Send>) {
+        assert_not_function(name, &self.functions);
+        self.owned.insert(name, item);
+    }
+
+    /// Register a function that is independent of any object, called like `name(arg, ...)`. The
+    /// arguments are parsed using `Deser`, and are described by the given names.
+    ///
+    /// Panics if an object of the same name is registered, as the object would shadow it.
+    pub fn insert_fn<F, Args>(&mut self, name: &'static str, arg_names: &[&'static str], f: F)
+    where
+        F: CallDeser<Args> + Send + 'static,
+        Args: 'static,
+    {
+        assert_not_object(name, &self.owned);
+        let function = DeserFn::<F, Args>::new(name, arg_names, f);
+        self.functions.insert(name, Box::new(function));
+    }
+
     pub fn as_root(&mut self) -> Root {
-        Root {
-            send: Some(self),
-            local: None,
-        }
+        Root::new(Some(self), None)
     }
 }
 
@@ -29,12 +124,46 @@ impl RootSend {
 #[derive(Default)]
 pub struct RootLocal {
     pub owned: BTreeMap<&'static str, Box<dyn Access>>,
+    pub functions: BTreeMap<&'static str, Box<dyn RootFunction>>,
 }
 
 impl RootLocal {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Register an object, like `RootSend::insert` does.
+    pub fn insert(&mut self, name: &'static str, item: Box<dyn Access>) {
+        assert_not_function(name, &self.functions);
+        self.owned.insert(name, item);
+    }
+
+    /// Register a function that is independent of any object, like `RootSend::insert_fn` does.
+    pub fn insert_fn<F, Args>(&mut self, name: &'static str, arg_names: &[&'static str], f: F)
+    where
+        F: CallDeser<Args> + 'static,
+        Args: 'static,
+    {
+        assert_not_object(name, &self.owned);
+        let function = DeserFn::<F, Args>::new(name, arg_names, f);
+        self.functions.insert(name, Box::new(function));
+    }
+}
+
+fn assert_not_object<V: ?Sized>(name: &str, owned: &BTreeMap<&'static str, Box<V>>) {
+    assert!(
+        !owned.contains_key(name),
+        "Function {} would be shadowed by the object of the same name",
+        name
+    );
+}
+
+fn assert_not_function<V: ?Sized>(name: &str, functions: &BTreeMap<&'static str, Box<V>>) {
+    assert!(
+        !functions.contains_key(name),
+        "Object {} would shadow the function of the same name",
+        name
+    );
 }
 
 /// A temporary binder of `RootSend` and `RootLocal` dictionaries, used for providing a unified
@@ -45,6 +174,27 @@ pub struct Root<'a, 'b> {
 }
 
 impl<'a, 'b> Root<'a, 'b> {
+    /// Bind the given dictionaries into a root.
+    ///
+    /// Panics if a name is used more than once across the objects and functions of both
+    /// dictionaries, such as by a function of one and an object of the other, or by an object
+    /// inserted into `owned` directly, as only one of them could be reached.
+    pub fn new(send: Option<&'a mut RootSend>, local: Option<&'b mut RootLocal>) -> Self {
+        let root = Root { send, local };
+
+        let mut keys = root.keys();
+        keys.sort_unstable();
+        for pair in keys.windows(2) {
+            assert!(
+                pair[0] != pair[1],
+                "The name {} is registered more than once in the root",
+                pair[0]
+            );
+        }
+
+        root
+    }
+
     /// Probe a path, checking if it is valid. If it contains a function name, it will not be
     /// called. If it contains an assignment, the assignment will not take place but the parameters
     /// value will check for `Deser` deserialization.
@@ -53,7 +203,8 @@ impl<'a, 'b> Root<'a, 'b> {
     /// it may block because some fields, depending on the types and usage, could be behind a
     /// `Mutex` lock.
     pub fn probe(&mut self, path_str: &str) -> (Result<NodeTree, ClimbError>, Assist<String>) {
-        self._access(path_str, true)
+        let result = self._access(path_str, true, BytesMode::List);
+        (result.res, result.assist)
     }
 
     /// Perform evaluation of the provided path. This may perform assignments, or call user-defined
@@ -63,15 +214,105 @@ impl<'a, 'b> Root<'a, 'b> {
     /// it may block because some fields, depending on the types and usage, could be behind a
     /// `Mutex` lock.
     pub fn access(&mut self, path_str: &str) -> (Result<NodeTree, ClimbError>, Assist<String>) {
-        self._access(path_str, false)
+        let result = self._access(path_str, false, BytesMode::List);
+        (result.res, result.assist)
+    }
+
+    /// Perform evaluation of the provided path like `access`, rendering buffers of `u8` values
+    /// in the result using the given mode.
+    pub fn access_as(
+        &mut self,
+        path_str: &str,
+        bytes_mode: BytesMode,
+    ) -> (Result<NodeTree, ClimbError>, Assist<String>) {
+        let result = self._access(path_str, false, bytes_mode);
+        (result.res, result.assist)
+    }
+
+    /// Return the signature of the function whose arguments are being given at the end of the
+    /// provided path, e.g. `add(param_a: u32) -> ()` for `a.add(`. This only probes the path.
+    pub fn call_signature(&mut self, path_str: &str) -> Option<String> {
+        match self._access(path_str, true, BytesMode::List).hint {
+            Some(Hint::Call(function)) => Some(function.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Return a hint about the rest of the provided path: either the signature of the function
+    /// being called, like `call_signature`, or the values accepted by the field being assigned,
+    /// e.g. `1..=1024` for a field having `#[interact(range(1..=1024))]`. Otherwise, for a valid
+    /// path, this is the first line of its doc comment as given by `doc`. This only probes the
+    /// path.
+    pub fn hint(&mut self, path_str: &str) -> Option<String> {
+        let result = self._access(path_str, true, BytesMode::List);
+        match result.hint {
+            Some(Hint::Call(function)) => Some(function.to_string()),
+            Some(Hint::Assign(hint)) => Some(hint.to_owned()),
+            None if result.res.is_ok() => {
+                let doc = render_docs(&result.docs)?;
+                doc.lines().next().map(String::from)
+            }
+            None => None,
+        }
+    }
+
+    /// Return the doc comments describing the end of the provided path. For a function being
+    /// called, this is its signature followed by its doc comment. For a value, these are the
+    /// doc comments of the field holding it, of its type, and of its current variant if it is an
+    /// enum, as far as they are given. This only probes the path.
+    pub fn doc(&mut self, path_str: &str) -> Result<Option<String>, ClimbError> {
+        let result = self._access(path_str, true, BytesMode::List);
+        match result.hint {
+            Some(Hint::Call(function)) if function.doc.is_empty() => Ok(Some(function.to_string())),
+            Some(Hint::Call(function)) => Ok(Some(format!("{}\n\n{}", function, function.doc))),
+            _ => {
+                let docs = result.docs;
+                result.res.map(|_| render_docs(&docs))
+            }
+        }
     }
 
+    /// Return the type of the value at the end of the provided path, which can be described
+    /// using `TypeInfo::describe`. This is `None` for the result of a function call, as calls
+    /// are not made. This only probes the path.
+    pub fn type_info(&mut self, path_str: &str) -> Result<Option<TypeInfo>, ClimbError> {
+        let result = self._access(path_str, true, BytesMode::List);
+        let type_info = result.type_info;
+        result.res.map(|_| type_info)
+    }
+
+    /// Render a JSON Schema-like description of the objects and functions of the root, from
+    /// the types of the objects alone. No value is read, so that no lock is taken.
+    pub fn json_schema(&self) -> String {
+        let mut schemas = Schemas::new();
+        let mut properties = vec![];
+        let mut functions = vec![];
+
+        if let Some(x) = &self.send {
+            for (k, v) in x.owned.iter() {
+                properties.push((*k, (**v).type_info().describe(&mut schemas)));
+            }
+            functions.extend(x.functions.values().map(|f| f.function()));
+        }
+        if let Some(x) = &self.local {
+            for (k, v) in x.owned.iter() {
+                properties.push((*k, (**v).type_info().describe(&mut schemas)));
+            }
+            functions.extend(x.functions.values().map(|f| f.function()));
+        }
+        properties.sort_by_key(|(k, _)| *k);
+        functions.sort_by_key(|f| f.name);
+
+        schema::json_schema(&properties, &functions, &schemas)
+    }
+
+    /// The names of the objects and functions of the root.
     pub fn keys(&self) -> Vec<&'static str> {
         let mut v = vec![];
         match &self.send {
             None => {}
             Some(x) => {
-                for k in x.owned.keys() {
+                for k in x.owned.keys().chain(x.functions.keys()) {
                     v.push(*k);
                 }
             }
@@ -79,7 +320,7 @@ impl<'a, 'b> Root<'a, 'b> {
         match &self.local {
             None => {}
             Some(x) => {
-                for k in x.owned.keys() {
+                for k in x.owned.keys().chain(x.functions.keys()) {
                     v.push(*k);
                 }
             }
@@ -88,22 +329,38 @@ impl<'a, 'b> Root<'a, 'b> {
         v
     }
 
+    /// Describe the function of the root having the given name, if any.
+    pub fn function(&self, name: &str) -> Option<&'static Function> {
+        let send = self.send.as_ref().and_then(|x| x.functions.get(name));
+        let local = self.local.as_ref().and_then(|x| x.functions.get(name));
+        match (send, local) {
+            (Some(function), _) => Some(function.function()),
+            (None, Some(function)) => Some(function.function()),
+            (None, None) => None,
+        }
+    }
+
     fn _access(
         &mut self,
         path_str: &str,
         probe_only: bool,
-    ) -> (Result<NodeTree, ClimbError>, Assist<String>) {
+        bytes_mode: BytesMode,
+    ) -> AccessResult<'static> {
         enum Item<'a, 'b> {
             Send(&'a mut Box<dyn Access + Send>),
             Local(&'b mut Box<dyn Access>),
         };
         let mut h = std::collections::BTreeMap::new();
+        let mut functions: BTreeMap<&'static str, &dyn RootFunction> = BTreeMap::new();
         match &mut self.send {
             None => {}
             Some(x) => {
                 for (k, v) in x.owned.iter_mut() {
                     h.insert(*k, Item::Send(v));
                 }
+                for (k, v) in x.functions.iter() {
+                    functions.insert(*k, &**v);
+                }
             }
         }
         match &mut self.local {
@@ -112,10 +369,14 @@ impl<'a, 'b> Root<'a, 'b> {
                 for (k, v) in x.owned.iter_mut() {
                     h.insert(*k, Item::Local(v));
                 }
+                for (k, v) in x.functions.iter() {
+                    functions.insert(*k, &**v);
+                }
             }
         }
         let matching_prefix_keys = h
             .keys()
+            .chain(functions.keys())
             .filter(|x| x.starts_with(path_str))
             .map(|x| String::from(&x[..]))
             .collect();
@@ -130,61 +391,69 @@ impl<'a, 'b> Root<'a, 'b> {
         };
         let tokens = match parse_to_tokens(path_str).map_err(ClimbError::TokenError) {
             Err(err) => {
-                return (Err(err), ret_assist(0));
+                return AccessResult::failed(err, ret_assist(0));
             }
             Ok(tokens) => tokens,
         };
         if tokens.is_empty() {
-            return (Err(ClimbError::NullPath), ret_assist(0));
+            return AccessResult::failed(ClimbError::NullPath, ret_assist(0));
         }
 
         let first_token = tokens[0].text.as_ref();
-        let item = match h.get(first_token) {
-            Some(v) => v,
+        let start_pos = tokens[0].space_diff + tokens[0].text.len();
+        let function = functions.get(first_token).cloned();
+        let item = match h.remove(first_token) {
+            Some(v) => Some(v),
+            None if function.is_some() => None,
             None => {
-                return (
-                    Err(ClimbError::MissingStartComponent),
+                return AccessResult::failed(
+                    ClimbError::MissingStartComponent,
                     ret_assist(first_token.len()),
                 );
             }
         };
 
-        let start_pos = tokens[0].space_diff + tokens[0].text.len();
+        // The other objects can be passed by path as function arguments.
+        let objects = h
+            .iter()
+            .map(|(k, v)| {
+                let access: &dyn Access = match v {
+                    Item::Send(x) => &***x,
+                    Item::Local(x) => &***x,
+                };
+                (*k, access)
+            })
+            .collect();
+        let resolver = RootObjects {
+            current: first_token,
+            objects,
+        };
+
         let tokens = &tokens[1..];
         let mut climber = Climber::new(200, probe_only, tokens);
+        climber.set_bytes_mode(bytes_mode);
+        climber.set_resolver(Rc::new(resolver));
         let climber_clone = climber.clone();
 
         let mut res = match item {
-            Item::Local(x) => match climber.general_access_immut(&***x) {
-                Err(ClimbError::NeedMutPath) => match h.get_mut(first_token) {
-                    Some(Item::Local(item)) => {
-                        climber = climber_clone;
-                        climber.general_access_mut(&mut ***item)
-                    }
-                    _ => {
-                        return (
-                            Err(ClimbError::MissingStartComponent),
-                            ret_assist(first_token.len()),
-                        );
-                    }
-                },
+            Some(Item::Local(x)) => match climber.general_access_immut(&**x) {
+                Err(ClimbError::NeedMutPath) => {
+                    climber = climber_clone;
+                    climber.general_access_mut(&mut **x)
+                }
                 e => e,
             },
-            Item::Send(x) => match climber.general_access_immut(&***x) {
-                Err(ClimbError::NeedMutPath) => match h.get_mut(first_token) {
-                    Some(Item::Send(item)) => {
-                        climber = climber_clone;
-                        climber.general_access_mut(&mut ***item)
-                    }
-                    _ => {
-                        return (
-                            Err(ClimbError::MissingStartComponent),
-                            ret_assist(first_token.len()),
-                        );
-                    }
-                },
+            Some(Item::Send(x)) => match climber.general_access_immut(&**x) {
+                Err(ClimbError::NeedMutPath) => {
+                    climber = climber_clone;
+                    climber.general_access_mut(&mut **x)
+                }
                 e => e,
             },
+            None => match function {
+                Some(function) => climber.call_root_function(function),
+                None => Err(ClimbError::MissingStartComponent),
+            },
         };
 
         match &mut res {
@@ -193,7 +462,18 @@ impl<'a, 'b> Root<'a, 'b> {
             }
             Err(_) => {}
         }
+        if res.is_ok() && climber.interrupted() {
+            // The value was reflected only partially.
+            res = Err(ClimbError::Interrupted);
+        }
 
+        let hint = match (climber.call_hint(), climber.assign_hint()) {
+            (Some(function), _) => Some(Hint::Call(function)),
+            (None, Some(hint)) => Some(Hint::Assign(hint)),
+            (None, None) => None,
+        };
+        let docs = climber.docs();
+        let type_info = climber.type_info();
         let (old_assist, pending_partial) = climber.convert_to_assist();
 
         // Convert the tokens-based Assist back to String-based assist
@@ -251,6 +531,28 @@ impl<'a, 'b> Root<'a, 'b> {
             }
         };
 
-        (res, new_assist.next_options(next_options))
+        AccessResult {
+            res,
+            assist: new_assist.next_options(next_options),
+            hint,
+            docs,
+            type_info,
+        }
     }
 }
+
+/// Join the given doc comments into paragraphs, or `None` if there are none.
+fn render_docs(docs: &Docs) -> Option<String> {
+    let mut paragraphs: Vec<&str> = vec![];
+    for doc in &[docs.field, docs.ty, docs.variant] {
+        if !doc.is_empty() && !paragraphs.contains(doc) {
+            paragraphs.push(doc);
+        }
+    }
+
+    if paragraphs.is_empty() {
+        return None;
+    }
+
+    Some(paragraphs.join("\n\n"))
+}
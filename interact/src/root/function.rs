@@ -0,0 +1,151 @@
+//! Free functions registered in a `Root`, independent of any object, such as `flush_all_caches()`.
+
+use std::marker::PhantomData;
+
+use crate::access::{Access, Argument, CallError, Function, RetValCallback};
+use crate::deser::{self, Deser};
+use crate::schema::short_type_name;
+use crate::Climber;
+
+/// A function that can be called from the start of an expression, next to the objects of a `Root`.
+pub trait RootFunction {
+    /// Describes the name, arguments and return type of the function.
+    fn function(&self) -> &'static Function;
+
+    /// Parse the arguments of the function, and unless probing, call it with the return value
+    /// provided to the callback.
+    fn call<'a>(
+        &self,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError>;
+}
+
+/// A function or closure whose arguments are all parsed using `Deser`, where `Args` is the tuple
+/// of argument types. Implemented for up to six arguments.
+pub trait CallDeser<Args> {
+    /// The names of the argument types.
+    fn types() -> Vec<String>;
+
+    /// The name of the return type.
+    fn ret() -> String;
+
+    /// Parse the arguments and call the function.
+    fn call<'a>(
+        &self,
+        args: &'static [Argument],
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError>;
+}
+
+/// A `RootFunction` calling a function or closure whose arguments implement `Deser`, and whose
+/// return value implements `Access`.
+pub struct DeserFn<F, Args> {
+    function: &'static Function,
+    f: F,
+    _args: PhantomData<fn(Args)>,
+}
+
+impl<F: CallDeser<Args>, Args> DeserFn<F, Args> {
+    /// Describe a function by its name and the names of its arguments.
+    ///
+    /// The description is kept for the lifetime of the process, as functions are expected to be
+    /// registered once.
+    pub fn new(name: &'static str, arg_names: &[&'static str], f: F) -> Self {
+        let types = F::types();
+        assert_eq!(
+            arg_names.len(),
+            types.len(),
+            "Wrong number of argument names for function {}",
+            name
+        );
+
+        let args: Vec<_> = arg_names
+            .iter()
+            .zip(types)
+            .map(|(name, ty)| Argument {
+                name,
+                ty: Box::leak(ty.into_boxed_str()),
+                default: None,
+            })
+            .collect();
+        let function = Box::leak(Box::new(Function {
+            name,
+            args: Box::leak(args.into_boxed_slice()),
+            ret: Box::leak(F::ret().into_boxed_str()),
+            doc: "",
+        }));
+
+        DeserFn {
+            function,
+            f,
+            _args: PhantomData,
+        }
+    }
+}
+
+impl<F: CallDeser<Args>, Args> RootFunction for DeserFn<F, Args> {
+    fn function(&self) -> &'static Function {
+        self.function
+    }
+
+    fn call<'a>(
+        &self,
+        climber: &mut Climber<'a>,
+        retcall: RetValCallback<'a>,
+    ) -> Result<(), CallError> {
+        self.f.call(self.function.args, climber, retcall)
+    }
+}
+
+macro_rules! call_deser {
+    ($($idx:tt $arg:ident),*) => {
+        impl<Func, Ret, $($arg),*> CallDeser<($($arg,)*)> for Func
+        where
+            Func: Fn($($arg),*) -> Ret,
+            Ret: Access,
+            $($arg: Deser),*
+        {
+            fn types() -> Vec<String> {
+                vec![$(short_type_name(std::any::type_name::<$arg>())),*]
+            }
+
+            fn ret() -> String {
+                short_type_name(std::any::type_name::<Ret>())
+            }
+
+            #[allow(unused_mut, unused_variables)]
+            fn call<'a>(
+                &self,
+                args: &'static [Argument],
+                climber: &mut Climber<'a>,
+                mut retcall: RetValCallback<'a>,
+            ) -> Result<(), CallError> {
+                let mut values: ($(Option<$arg>,)*) = Default::default();
+                deser::deser_args(&mut climber.borrow_tracker(), args, &mut |idx, tracker| {
+                    match idx {
+                        $($idx => values.$idx = Some($arg::deser(tracker)?),)*
+                        _ => {}
+                    }
+                    Ok(())
+                })?;
+
+                if !climber.is_probe_only() {
+                    let retval = self($(values.$idx.take().unwrap()),*);
+                    retcall(&retval, climber);
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+call_deser!();
+call_deser!(0 A);
+call_deser!(0 A, 1 B);
+call_deser!(0 A, 1 B, 2 C);
+call_deser!(0 A, 1 B, 2 C, 3 D);
+call_deser!(0 A, 1 B, 2 C, 3 D, 4 E);
+call_deser!(0 A, 1 B, 2 C, 3 D, 4 E, 5 G);
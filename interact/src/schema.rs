@@ -0,0 +1,545 @@
+//! Descriptions of the types of the values that are accessible from Interact.
+//!
+//! A type is described by `Access::describe`, without reading any value of it, giving the
+//! fields of structs, the variants of enums, the element types of collections, and the
+//! functions that can be called. Types deriving `Interact` are described once in `Schemas`, and
+//! are otherwise referred to by name, so that recursive types can be described.
+
+use std::any;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Access, Function};
+
+/// Describes a type, as far as it is known to Interact.
+pub struct Schema {
+    /// The name of the type, as given by `std::any::type_name`.
+    pub ty: &'static str,
+
+    pub kind: SchemaKind,
+
+    /// The doc comment of the type.
+    pub doc: &'static str,
+
+    /// The functions that can be called on values of the type.
+    pub functions: &'static [Function],
+}
+
+pub enum SchemaKind {
+    /// A value shown as a single token, such as a number or a string.
+    Leaf,
+
+    /// A value whose structure is not known to Interact.
+    Opaque,
+
+    /// A struct or a tuple.
+    Struct(Fields),
+
+    Enum(Vec<Variant>),
+
+    /// A collection of values, such as a `Vec` or a `HashSet`.
+    Seq(Box<Schema>),
+
+    /// A map of keys to values.
+    Map(Box<Schema>, Box<Schema>),
+
+    /// A type that is described in `Schemas` under its name.
+    Ref,
+}
+
+/// The fields of a struct or of an enum variant.
+pub enum Fields {
+    Unit,
+    Tuple(Vec<Schema>),
+    Named(Vec<Field>),
+}
+
+pub struct Field {
+    /// The name of the field, as shown by Interact.
+    pub name: &'static str,
+
+    /// The doc comment of the field.
+    pub doc: &'static str,
+
+    /// Whether the fields of the struct held by the field are shown in its place, as given by
+    /// `#[interact(flatten)]`.
+    pub flatten: bool,
+
+    pub schema: Schema,
+}
+
+pub struct Variant {
+    /// The name of the variant, as shown by Interact.
+    pub name: &'static str,
+
+    /// The doc comment of the variant.
+    pub doc: &'static str,
+
+    pub fields: Fields,
+}
+
+impl Schema {
+    pub fn new(ty: &'static str, kind: SchemaKind) -> Self {
+        Self {
+            ty,
+            kind,
+            doc: "",
+            functions: &[],
+        }
+    }
+
+    /// Describe the type `T` as being of the given kind.
+    pub fn of<T: ?Sized>(kind: SchemaKind) -> Self {
+        Self::new(any::type_name::<T>(), kind)
+    }
+
+    pub fn with_doc(self, doc: &'static str) -> Self {
+        Self { doc, ..self }
+    }
+
+    pub fn with_functions(self, functions: &'static [Function]) -> Self {
+        Self { functions, ..self }
+    }
+}
+
+/// Holds the descriptions of the types that are referred to by name, such as those deriving
+/// `Interact`.
+#[derive(Default)]
+pub struct Schemas {
+    defs: BTreeMap<&'static str, Option<Schema>>,
+}
+
+impl Schemas {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Describe the type `T` using `describe`, unless it is already described or being
+    /// described, and return a reference to it.
+    pub fn define<T: ?Sized, F>(&mut self, describe: F) -> Schema
+    where
+        F: FnOnce(&mut Self) -> Schema,
+    {
+        let ty = any::type_name::<T>();
+
+        if !self.defs.contains_key(ty) {
+            self.defs.insert(ty, None);
+            let schema = describe(self);
+            self.defs.insert(ty, Some(schema));
+        }
+
+        Schema::new(ty, SchemaKind::Ref)
+    }
+
+    /// The description of the type having the given name, if it is defined.
+    pub fn get(&self, ty: &str) -> Option<&Schema> {
+        self.defs.get(ty)?.as_ref()
+    }
+
+    /// The description that a schema refers to, or the schema itself if it is not a reference.
+    pub fn resolve<'s>(&'s self, schema: &'s Schema) -> &'s Schema {
+        match schema.kind {
+            SchemaKind::Ref => self.get(schema.ty).unwrap_or(schema),
+            _ => schema,
+        }
+    }
+
+    /// The defined descriptions, ordered by the names of the types.
+    pub fn iter(&self) -> impl Iterator<Item = &Schema> {
+        self.defs.values().filter_map(Option::as_ref)
+    }
+}
+
+/// The type of a value implementing `Access`, which can be described without reading the value.
+#[derive(Clone, Copy)]
+pub struct TypeInfo {
+    name: &'static str,
+    describe: fn(&mut Schemas) -> Schema,
+}
+
+impl TypeInfo {
+    pub fn of<T: Access>() -> Self {
+        Self {
+            name: any::type_name::<T>(),
+            describe: T::describe,
+        }
+    }
+
+    /// The name of the type, as given by `std::any::type_name`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn describe(&self, schemas: &mut Schemas) -> Schema {
+        (self.describe)(schemas)
+    }
+}
+
+impl fmt::Debug for TypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TypeInfo").field(&self.name).finish()
+    }
+}
+
+/// Gives the `TypeInfo` of a value, including via `dyn Access`. This is implemented for every
+/// type implementing `Access`.
+pub trait AccessType {
+    fn type_info(&self) -> TypeInfo;
+}
+
+impl<T: Access> AccessType for T {
+    fn type_info(&self) -> TypeInfo {
+        TypeInfo::of::<T>()
+    }
+}
+
+/// Describe the type of a field as it is accessed via the given view of it, such as the `Leaf`
+/// given by the `debug` attribute. Used by `#[derive(Interact)]`.
+pub fn describe_field<T, V: Access>(_view: fn(&T) -> &V, schemas: &mut Schemas) -> Schema {
+    V::describe(schemas)
+}
+
+/// Shorten a type name given by `std::any::type_name` by leaving out the module paths, e.g.
+/// `Vec<u32>` for `alloc::vec::Vec<u32>`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut chars = name.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            while short.ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                short.pop();
+            }
+        } else {
+            short.push(c);
+        }
+    }
+
+    short
+}
+
+fn write_doc(f: &mut fmt::Formatter, doc: &str, indent: &str) -> fmt::Result {
+    if doc.is_empty() {
+        return Ok(());
+    }
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(f, "{}///", indent)?;
+        } else {
+            writeln!(f, "{}/// {}", indent, line)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_fields(f: &mut fmt::Formatter, fields: &Fields, indent: &str) -> fmt::Result {
+    match fields {
+        Fields::Unit => Ok(()),
+        Fields::Tuple(schemas) => {
+            let names: Vec<_> = schemas.iter().map(|s| short_type_name(s.ty)).collect();
+            write!(f, "({})", names.join(", "))
+        }
+        Fields::Named(fields) => {
+            writeln!(f, " {{")?;
+            for field in fields {
+                let inner = format!("{}    ", indent);
+                write_doc(f, field.doc, &inner)?;
+                if field.flatten {
+                    writeln!(f, "{}#[interact(flatten)]", inner)?;
+                }
+                writeln!(
+                    f,
+                    "{}{}: {},",
+                    inner,
+                    field.name,
+                    short_type_name(field.schema.ty)
+                )?;
+            }
+            write!(f, "{}}}", indent)
+        }
+    }
+}
+
+impl fmt::Display for Schema {
+    /// Format the schema like the declaration of the type, e.g. `struct Point { x: u32, ... }`,
+    /// followed by the signatures of its functions. The types of fields are given by name.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = short_type_name(self.ty);
+
+        write_doc(f, self.doc, "")?;
+        match &self.kind {
+            SchemaKind::Struct(fields) if !name.starts_with('(') => {
+                write!(f, "struct {}", name)?;
+                write_fields(f, fields, "")?;
+                if let Fields::Unit | Fields::Tuple(_) = fields {
+                    write!(f, ";")?;
+                }
+            }
+            SchemaKind::Enum(variants) => {
+                writeln!(f, "enum {} {{", name)?;
+                for variant in variants {
+                    write_doc(f, variant.doc, "    ")?;
+                    write!(f, "    {}", variant.name)?;
+                    write_fields(f, &variant.fields, "    ")?;
+                    writeln!(f, ",")?;
+                }
+                write!(f, "}}")?;
+            }
+            _ => write!(f, "{}", name)?,
+        }
+
+        if !self.functions.is_empty() {
+            writeln!(f)?;
+            for function in self.functions {
+                write!(f, "\nfn {}", function)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A JSON value, as needed for rendering schemas.
+enum Json {
+    Str(String),
+    Bool(bool),
+    Num(usize),
+    Arr(Vec<Json>),
+    Obj(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn str(s: &str) -> Self {
+        Json::Str(String::from(s))
+    }
+
+    fn obj(entries: Vec<(&str, Json)>) -> Self {
+        Json::Obj(
+            entries
+                .into_iter()
+                .map(|(k, v)| (String::from(k), v))
+                .collect(),
+        )
+    }
+
+    fn write_str(out: &mut String, s: &str) {
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    /// Write the value, with nested values indented by two spaces per level.
+    fn write(&self, out: &mut String, indent: usize) {
+        let pad = |out: &mut String, indent: usize| {
+            out.push('\n');
+            out.push_str(&" ".repeat(indent * 2));
+        };
+
+        match self {
+            Json::Str(s) => Self::write_str(out, s),
+            Json::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Json::Num(n) => out.push_str(&n.to_string()),
+            Json::Arr(items) if items.is_empty() => out.push_str("[]"),
+            Json::Obj(entries) if entries.is_empty() => out.push_str("{}"),
+            Json::Arr(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    pad(out, indent + 1);
+                    item.write(out, indent + 1);
+                }
+                pad(out, indent);
+                out.push(']');
+            }
+            Json::Obj(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    pad(out, indent + 1);
+                    Self::write_str(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                pad(out, indent);
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// The JSON Schema type of a leaf value, by the name of its type.
+fn json_type(ty: &str) -> Option<&'static str> {
+    match ty {
+        "bool" => Some("boolean"),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" => Some("integer"),
+        "f32" | "f64" => Some("number"),
+        "char" | "alloc::string::String" | "&str" => Some("string"),
+        _ => None,
+    }
+}
+
+fn signatures(functions: &[Function]) -> Json {
+    Json::Arr(functions.iter().map(|f| Json::Str(f.to_string())).collect())
+}
+
+fn fields_to_json(fields: &Fields, name: &str, entries: &mut Vec<(&str, Json)>) {
+    match fields {
+        Fields::Unit => entries.push(("const", Json::str(name))),
+        Fields::Tuple(schemas) => {
+            entries.push(("type", Json::str("array")));
+            entries.push((
+                "prefixItems",
+                Json::Arr(schemas.iter().map(to_json).collect()),
+            ));
+            entries.push(("items", Json::Bool(false)));
+            entries.push(("minItems", Json::Num(schemas.len())));
+        }
+        Fields::Named(fields) => {
+            let mut properties = vec![];
+            let mut flattened = vec![];
+            for field in fields {
+                let mut schema = to_json(&field.schema);
+                if !field.doc.is_empty() {
+                    // A `$ref` along with other keywords is taken as is by JSON Schema.
+                    if let Json::Obj(entries) = &mut schema {
+                        entries.push((String::from("description"), Json::str(field.doc)));
+                    }
+                }
+                if field.flatten {
+                    flattened.push(schema);
+                } else {
+                    properties.push((String::from(field.name), schema));
+                }
+            }
+
+            let required = properties.iter().map(|(k, _)| Json::str(k)).collect();
+            entries.push(("type", Json::str("object")));
+            entries.push(("properties", Json::Obj(properties)));
+            entries.push(("required", Json::Arr(required)));
+            if flattened.is_empty() {
+                entries.push(("additionalProperties", Json::Bool(false)));
+            } else {
+                entries.push(("allOf", Json::Arr(flattened)));
+            }
+        }
+    }
+}
+
+fn variant_to_json(variant: &Variant) -> Json {
+    let mut entries = vec![];
+
+    match &variant.fields {
+        Fields::Unit => entries.push(("const", Json::str(variant.name))),
+        fields => {
+            let mut inner = vec![];
+            fields_to_json(fields, variant.name, &mut inner);
+            entries.push(("type", Json::str("object")));
+            entries.push((
+                "properties",
+                Json::Obj(vec![(String::from(variant.name), Json::obj(inner))]),
+            ));
+            entries.push(("required", Json::Arr(vec![Json::str(variant.name)])));
+            entries.push(("additionalProperties", Json::Bool(false)));
+        }
+    }
+    if !variant.doc.is_empty() {
+        entries.push(("description", Json::str(variant.doc)));
+    }
+
+    Json::obj(entries)
+}
+
+fn to_json(schema: &Schema) -> Json {
+    let mut entries = vec![];
+    let name = short_type_name(schema.ty);
+
+    match &schema.kind {
+        SchemaKind::Ref => {
+            let reference = format!("#/$defs/{}", schema.ty);
+            return Json::obj(vec![("$ref", Json::Str(reference))]);
+        }
+        SchemaKind::Leaf => {
+            if let Some(ty) = json_type(schema.ty) {
+                entries.push(("type", Json::str(ty)));
+            }
+        }
+        SchemaKind::Opaque => {}
+        SchemaKind::Struct(fields) => fields_to_json(fields, &name, &mut entries),
+        SchemaKind::Enum(variants) => {
+            entries.push((
+                "oneOf",
+                Json::Arr(variants.iter().map(variant_to_json).collect()),
+            ));
+        }
+        SchemaKind::Seq(items) => {
+            entries.push(("type", Json::str("array")));
+            entries.push(("items", to_json(items)));
+        }
+        SchemaKind::Map(keys, values) => {
+            entries.push(("type", Json::str("object")));
+            entries.push(("propertyNames", to_json(keys)));
+            entries.push(("additionalProperties", to_json(values)));
+        }
+    }
+
+    if !schema.doc.is_empty() {
+        entries.push(("description", Json::str(schema.doc)));
+    }
+    entries.push(("x-rust-type", Json::str(schema.ty)));
+    if !schema.functions.is_empty() {
+        entries.push(("x-functions", signatures(schema.functions)));
+    }
+
+    Json::obj(entries)
+}
+
+/// Render a JSON Schema-like description of an object having the given properties, such as the
+/// objects of a `Root`, and having the given functions, with the types defined in `schemas`
+/// under `$defs`.
+pub fn json_schema(
+    properties: &[(&str, Schema)],
+    functions: &[&Function],
+    schemas: &Schemas,
+) -> String {
+    let properties = properties
+        .iter()
+        .map(|(name, schema)| (String::from(*name), to_json(schema)))
+        .collect();
+    let defs = schemas
+        .iter()
+        .map(|schema| (String::from(schema.ty), to_json(schema)))
+        .collect();
+
+    let mut entries = vec![
+        (
+            "$schema",
+            Json::str("https://json-schema.org/draft/2020-12/schema"),
+        ),
+        ("type", Json::str("object")),
+        ("properties", Json::Obj(properties)),
+    ];
+    if !functions.is_empty() {
+        let signatures = functions.iter().map(|f| Json::Str(f.to_string())).collect();
+        entries.push(("x-functions", Json::Arr(signatures)));
+    }
+    entries.push(("$defs", Json::Obj(defs)));
+
+    let mut out = String::new();
+    Json::obj(entries).write(&mut out, 0);
+    out
+}
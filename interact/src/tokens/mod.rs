@@ -31,8 +31,10 @@ pub enum TokenKind {
     Assign,
     Colon,
     Asterix,
+    Question,
     Char(char),
     String(String),
+    Bytes(Vec<u8>),
     Range(bool),
     Comma,
     InvalidToken,
@@ -166,10 +168,36 @@ impl<'a> TokenVec<'a> {
         &self.tokens[self.pos].kind
     }
 
+    /// Look at a token following the top one, if there is one.
+    pub fn peek(&self, n: usize) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos + n)
+    }
+
     pub fn len(&self) -> usize {
         self.tokens.len()
     }
 
+    /// Whether the remaining tokens assign a value, i.e. contain `=` outside of any brackets,
+    /// unlike the named arguments of a call such as `f(x = 1)`.
+    pub fn has_assign(&self) -> bool {
+        let mut depth = 0usize;
+
+        for token in &self.tokens[self.pos..] {
+            match token.kind {
+                TokenKind::SubscriptOpen | TokenKind::TupleOpen | TokenKind::CurlyOpen => {
+                    depth += 1
+                }
+                TokenKind::SubscriptClose | TokenKind::TupleClose | TokenKind::CurlyClose => {
+                    depth = depth.saturating_sub(1)
+                }
+                TokenKind::Assign if depth == 0 => return true,
+                _ => {}
+            }
+        }
+
+        false
+    }
+
     pub fn advance(&mut self, count: usize) {
         self.pos += count;
         if self.pos > self.tokens.len() {
@@ -187,6 +215,50 @@ pub enum Error {
     Pest(pest::error::Error<Rule>),
     IntError(std::num::ParseIntError),
     RonError(ron::de::Error),
+    OddHexDigits,
+}
+
+fn unescape_bytes(s: &str) -> Vec<u8> {
+    let mut bytes = vec![];
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+
+        // The grammar only allows for valid escape sequences.
+        bytes.push(match chars.next().unwrap() {
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                u8::from_str_radix(&hex, 16).unwrap()
+            }
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            '0' => b'\0',
+            c => c as u8,
+        });
+    }
+
+    bytes
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, Error> {
+    let digits: Vec<u8> = s
+        .chars()
+        .filter_map(|c| c.to_digit(16))
+        .map(|d| d as u8)
+        .collect();
+
+    let pairs = digits.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::OddHexDigits);
+    }
+
+    Ok(pairs.map(|pair| (pair[0] << 4) | pair[1]).collect())
 }
 
 /// Parse a string into a vector of tokens.
@@ -222,6 +294,7 @@ pub fn parse_to_tokens<'a>(s: &'a str) -> Result<Vec<Token<'a>>, Error> {
             Rule::comma => TokenKind::Comma,
             Rule::colon => TokenKind::Colon,
             Rule::asterix => TokenKind::Asterix,
+            Rule::question => TokenKind::Question,
             Rule::char_literal => {
                 TokenKind::Char(ron::de::from_str(span.as_str()).map_err(Error::RonError)?)
             }
@@ -231,6 +304,14 @@ pub fn parse_to_tokens<'a>(s: &'a str) -> Result<Vec<Token<'a>>, Error> {
             Rule::string_literal => {
                 TokenKind::String(ron::de::from_str(span.as_str()).map_err(Error::RonError)?)
             }
+            Rule::byte_string_literal => {
+                let s = span.as_str();
+                TokenKind::Bytes(unescape_bytes(&s[2..s.len() - 1]))
+            }
+            Rule::hex_literal => {
+                let s = span.as_str();
+                TokenKind::Bytes(parse_hex(&s[2..s.len() - 1])?)
+            }
             Rule::underscore
             | Rule::alpha
             | Rule::alphanumeric
@@ -239,6 +320,9 @@ pub fn parse_to_tokens<'a>(s: &'a str) -> Result<Vec<Token<'a>>, Error> {
             | Rule::token
             | Rule::negative_decimal
             | Rule::escape_sequence
+            | Rule::byte_escape_sequence
+            | Rule::byte_literal_char
+            | Rule::hex_digit
             | Rule::whitespace_char
             | Rule::literal_char
             | Rule::single_literal_char
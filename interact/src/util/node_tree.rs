@@ -6,6 +6,8 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::mpsc::Receiver;
 use std::sync::Arc;
 
+use crate::interrupt;
+
 type Delimiter = char;
 
 #[derive(Debug)]
@@ -15,6 +17,7 @@ pub enum NodeInfo {
     Named(Box<NodeTree>, Box<NodeTree>),
     Tuple(Box<NodeTree>, &'static str, Box<NodeTree>),
     Leaf(Cow<'static, str>),
+    Lines(Vec<Cow<'static, str>>),
     Hole(Box<Receiver<NodeTree>>),
     BorrowedMut,
     Locked,
@@ -140,6 +143,15 @@ impl NodeInfo {
             Leaf(s) => {
                 state.write(s)?;
             }
+            Lines(lines) => {
+                for (idx, line) in lines.iter().enumerate() {
+                    if idx > 0 {
+                        state.write("\n")?;
+                    }
+
+                    state.write(line)?;
+                }
+            }
             Hole(_) => {
                 state.write(&format!("<hole>"))?;
             }
@@ -194,7 +206,16 @@ impl NodeTree {
                     count += v.len();
                     None
                 }
-                Hole(receiver) => Some((*receiver).recv().unwrap()),
+                Lines(lines) => {
+                    for line in lines.iter() {
+                        count += line.len() + 1;
+                    }
+                    None
+                }
+                Hole(receiver) => Some(
+                    interrupt::recv(receiver, interrupt::current().as_ref())
+                        .unwrap_or_else(|| NodeInfo::Limited.into_node()),
+                ),
                 Limited => None,
                 Repeated => None,
                 BorrowedMut => None,
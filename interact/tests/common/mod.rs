@@ -7,10 +7,14 @@ use pseudo_mutex::PseudoMutex;
 pub use random::Rand;
 
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
 use std::iter::FromIterator;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
 use std::time::Instant;
 
 use interact::Interact;
@@ -139,7 +143,7 @@ struct UnitStruct;
 /// A doc comment for testing.
 #[derive(Interact)]
 #[interact(immut_fn(check()))]
-#[interact(mut_fn(add(a)))]
+#[interact(mut_fn(add(a: u32) -> ()))]
 pub struct Complex {
     simple: HashMap<u64, u32>,
     complex_key: HashMap<Key, u32>,
@@ -264,8 +268,566 @@ impl Rand for LocalRcLoop {
     }
 }
 
+#[derive(Interact)]
+pub struct Buffers {
+    #[interact(bytes = "hexdump")]
+    packet: Vec<u8>,
+    #[interact(bytes = "hex")]
+    key: [u8; 8],
+    #[interact(bytes = "base64")]
+    token: Vec<u8>,
+    #[interact(bytes = "utf8")]
+    name: Vec<u8>,
+    raw: Vec<u8>,
+}
+
+impl Buffers {
+    pub fn new() -> Self {
+        Self {
+            packet: (0..40).collect(),
+            key: [0xde, 0xad, 0xbe, 0xef, 0, 1, 2, 3],
+            token: b"interact".to_vec(),
+            name: b"caf\xc3\xa9 \xff".to_vec(),
+            raw: vec![104, 105],
+        }
+    }
+}
+
+#[derive(Interact)]
+pub struct Formatted {
+    #[interact(format = "hex")]
+    flags: u32,
+    #[interact(format = "binary")]
+    mask: u8,
+    #[interact(format = "octal")]
+    mode: u32,
+    #[interact(format = "debug")]
+    label: Option<String>,
+    #[interact(format = "display")]
+    title: String,
+    #[interact(with = "render_celsius")]
+    temp: i32,
+    #[interact(skip_if_default)]
+    retries: u32,
+    state: FormattedState,
+}
+
+#[derive(Interact)]
+pub enum FormattedState {
+    Idle,
+    Busy {
+        #[interact(skip_if_default)]
+        pending: u32,
+        #[interact(with = "self::render_celsius")]
+        temp: i32,
+    },
+}
+
+fn render_celsius(temp: &i32) -> String {
+    format!("{}°C", temp)
+}
+
+impl Formatted {
+    pub fn new() -> Self {
+        Self {
+            flags: 0x1f,
+            mask: 0b1010,
+            mode: 0o755,
+            label: Some("main".to_owned()),
+            title: "Main unit".to_owned(),
+            temp: 21,
+            retries: 0,
+            state: FormattedState::Busy {
+                pending: 0,
+                temp: -4,
+            },
+        }
+    }
+}
+
+#[derive(Interact)]
+pub struct Fallback {
+    #[interact(display, from_str)]
+    addr: std::net::Ipv4Addr,
+    #[interact(debug)]
+    order: std::cmp::Ordering,
+    #[interact(from_str)]
+    port: std::num::NonZeroU16,
+    endpoint: Endpoint,
+}
+
+#[derive(Interact)]
+pub struct Endpoint(#[interact(display, from_str)] std::net::Ipv4Addr, u16);
+
+impl Fallback {
+    pub fn new() -> Self {
+        Self {
+            addr: std::net::Ipv4Addr::new(192, 168, 1, 1),
+            order: std::cmp::Ordering::Less,
+            port: std::num::NonZeroU16::new(8080).unwrap(),
+            endpoint: Endpoint(std::net::Ipv4Addr::LOCALHOST, 22),
+        }
+    }
+}
+
+#[derive(Interact)]
+#[interact(getter(depth, stats))]
+pub struct Queue {
+    items: Vec<u32>,
+    capacity: usize,
+}
+
+#[derive(Interact, Clone)]
+pub struct QueueStats {
+    min: Option<u32>,
+    max: Option<u32>,
+}
+
+impl Queue {
+    pub fn new() -> Self {
+        Self {
+            items: vec![4, 9, 2],
+            capacity: 8,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.items.len()
+    }
+
+    fn stats(&self) -> QueueStats {
+        QueueStats {
+            min: self.items.iter().cloned().min(),
+            max: self.items.iter().cloned().max(),
+        }
+    }
+}
+
+#[derive(Interact)]
+#[interact(methods)]
+pub struct Worker {
+    name: String,
+    capacity: usize,
+    jobs: Vec<u32>,
+}
+
+#[interact::methods]
+impl Worker {
+    pub fn new() -> Self {
+        Self {
+            name: String::from("w1"),
+            capacity: 4,
+            jobs: vec![],
+        }
+    }
+
+    /// Queue a job, returning whether there was room for it.
+    pub fn submit(&mut self, job: u32) -> bool {
+        if self.jobs.len() >= self.capacity {
+            return false;
+        }
+        self.jobs.push(job);
+        true
+    }
+
+    /// Change the capacity of the worker.
+    ///
+    /// Jobs beyond the new capacity are dropped.
+    #[interact(default(keep_first = true))]
+    pub fn resize(&mut self, capacity: usize, keep_first: bool) {
+        self.capacity = capacity;
+        if keep_first {
+            self.jobs.truncate(capacity);
+        } else {
+            let start = self.jobs.len().saturating_sub(capacity);
+            self.jobs.drain(..start);
+        }
+    }
+
+    pub fn load(&self) -> (usize, usize) {
+        (self.jobs.len(), self.capacity)
+    }
+
+    /// Remove all the jobs, returning how many there were.
+    pub async fn flush(&mut self) -> usize {
+        YieldOnce(false).await;
+        self.jobs.drain(..).count()
+    }
+
+    #[interact(skip)]
+    pub fn secret(&self) -> u32 {
+        42
+    }
+}
+
+#[derive(Interact)]
+#[interact(methods)]
+pub struct Scheduler {
+    assigned: Vec<String>,
+    max_seen: Option<u32>,
+}
+
+#[interact::methods]
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            assigned: vec![],
+            max_seen: None,
+        }
+    }
+
+    /// Assign a worker, given by a path to it.
+    pub fn assign(&mut self, worker: &Worker) -> usize {
+        self.assigned.push(worker.name.clone());
+        self.assigned.len()
+    }
+
+    pub fn room(&self, worker: &Worker, extra: usize) -> usize {
+        worker.capacity + extra - worker.jobs.len()
+    }
+
+    pub fn observe(&mut self, stats: QueueStats) -> Option<u32> {
+        self.max_seen = stats.max;
+        self.max_seen
+    }
+
+    pub fn double(&self, n: usize) -> usize {
+        n * 2
+    }
+
+    pub fn take(&mut self, worker: Worker) {}
+}
+
+/// A future that is pending on its first poll, making the executor wait for a wake-up.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[derive(Interact)]
+#[interact(async_fn(fetch(key: u32) -> u32, doc = "The value stored for the key, or 0."))]
+#[interact(async_mut_fn(store(key: u32, value: u32 = 1) -> ()))]
+pub struct Cache {
+    entries: BTreeMap<u32, u32>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    async fn fetch(&self, key: u32) -> u32 {
+        YieldOnce(false).await;
+        self.entries.get(&key).cloned().unwrap_or(0)
+    }
+
+    async fn store(&mut self, key: u32, value: u32) {
+        YieldOnce(false).await;
+        self.entries.insert(key, value);
+    }
+}
+
+/// Types standing for ones that are defined in another crate, and don't implement `Access`.
+pub mod foreign {
+    pub struct Config {
+        pub name: String,
+        pub retries: u32,
+        pub verbose: bool,
+    }
+
+    pub struct Version(pub u16, pub u16);
+}
+
+#[derive(Interact)]
+#[interact(remote = "foreign::Config")]
+pub struct ConfigDef {
+    name: String,
+    retries: u32,
+    verbose: bool,
+}
+
+#[derive(Interact)]
+#[interact(remote = "foreign::Version")]
+pub struct VersionDef(u16, u16);
+
+#[derive(Interact)]
+pub struct Deployment {
+    #[interact(remote = "ConfigDef")]
+    config: foreign::Config,
+    #[interact(remote = "VersionDef")]
+    version: foreign::Version,
+}
+
+impl Deployment {
+    pub fn new() -> Self {
+        Self {
+            config: foreign::Config {
+                name: String::from("staging"),
+                retries: 3,
+                verbose: false,
+            },
+            version: foreign::Version(1, 4),
+        }
+    }
+}
+
 /// A doc comment for testing.
 #[derive(Interact)]
 pub struct LocalComplex {
     rc_loop: LocalRcLoop,
 }
+
+#[derive(Interact)]
+pub struct Inventory {
+    items: Vec<String>,
+    counts: HashMap<String, u32>,
+    labels: BTreeMap<u32, String>,
+    tags: HashSet<u32>,
+    slots: [u32; 3],
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self {
+            items: vec!["bolt".to_owned(), "nut".to_owned()],
+            counts: HashMap::from_iter(vec![("bolt".to_owned(), 3)]),
+            labels: BTreeMap::from_iter(vec![(1, "one".to_owned()), (2, "two".to_owned())]),
+            tags: HashSet::from_iter(vec![7]),
+            slots: [5, 6, 7],
+        }
+    }
+}
+
+/// Counts the renderings of `Code` values.
+pub static CODE_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Interact, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Code(#[interact(with = "render_code")] u32);
+
+fn render_code(code: &u32) -> String {
+    CODE_RENDERS.fetch_add(1, Ordering::SeqCst);
+    format!("{}", code)
+}
+
+#[derive(Interact)]
+pub struct Catalog {
+    codes: BTreeMap<Code, u32>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self {
+            codes: (1..=30).map(|code| (Code(code), code * 10)).collect(),
+        }
+    }
+}
+
+#[derive(Interact, PartialEq, Eq, PartialOrd, Ord)]
+#[interact(transparent)]
+pub struct UserId(u64);
+
+#[derive(Interact)]
+#[interact(transparent = "named")]
+pub struct Millimeters {
+    value: u32,
+}
+
+#[derive(Interact)]
+pub struct Location {
+    lat: i32,
+    lon: i32,
+}
+
+#[derive(Interact)]
+#[interact(transparent = "named")]
+pub struct Home(Location);
+
+#[derive(Interact)]
+pub struct Account {
+    owner: UserId,
+    friends: Vec<UserId>,
+    height: Millimeters,
+    home: Home,
+    scores: BTreeMap<UserId, u32>,
+}
+
+impl Account {
+    pub fn new() -> Self {
+        Self {
+            owner: UserId(42),
+            friends: vec![UserId(7), UserId(9)],
+            height: Millimeters { value: 1500 },
+            home: Home(Location { lat: 32, lon: 34 }),
+            scores: BTreeMap::from_iter(vec![(UserId(7), 10), (UserId(9), 20)]),
+        }
+    }
+}
+
+#[derive(Interact)]
+pub struct Limits {
+    max_conns: u32,
+    timeout: u32,
+}
+
+#[derive(Interact)]
+pub struct Tls {
+    enabled: bool,
+    #[interact(flatten)]
+    limits: Limits,
+}
+
+#[derive(Interact)]
+pub struct ServerConfig {
+    name: String,
+    #[interact(flatten)]
+    tls: Tls,
+    port: u16,
+}
+
+impl ServerConfig {
+    pub fn new() -> Self {
+        Self {
+            name: "edge".to_owned(),
+            tls: Tls {
+                enabled: true,
+                limits: Limits {
+                    max_conns: 100,
+                    timeout: 30,
+                },
+            },
+            port: 443,
+        }
+    }
+}
+
+/// The phase of a pipeline.
+#[derive(Interact)]
+#[interact(rename_all = "snake_case")]
+pub enum Phase {
+    Idle,
+    /// Filling up the stages.
+    WarmingUp {
+        /// Percents of the stages that are filled.
+        progress: u32,
+    },
+    #[interact(rename = "done")]
+    Finished(u32),
+    #[interact(skip)]
+    Draining(std::time::Instant),
+}
+
+/// A pipeline of stages.
+///
+/// Stages are filled one after the other.
+#[derive(Interact)]
+#[interact(rename_all = "camelCase")]
+pub struct Pipeline {
+    /// The number of stages.
+    stage_count: u32,
+    #[interact(rename = "state")]
+    phase: Phase,
+    previous: Phase,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self {
+            stage_count: 3,
+            phase: Phase::WarmingUp { progress: 40 },
+            previous: Phase::Draining(std::time::Instant::now()),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("The name cannot be empty".to_owned());
+    }
+    Ok(())
+}
+
+#[derive(Interact)]
+pub struct Pool {
+    #[interact(validate = "validate_name")]
+    name: String,
+    /// The maximum number of jobs in flight.
+    #[interact(range(1..=8), on_change = "Self::resize")]
+    size: u32,
+    slots: Vec<u32>,
+    resizes: u32,
+}
+
+impl Pool {
+    pub fn new() -> Self {
+        Self {
+            name: "workers".to_owned(),
+            size: 2,
+            slots: vec![0, 0],
+            resizes: 0,
+        }
+    }
+
+    fn resize(&mut self) {
+        self.slots.resize(self.size as usize, 0);
+        self.resizes += 1;
+    }
+}
+
+#[derive(Interact)]
+pub struct Listeners {
+    #[interact(validate = "validate_ports")]
+    ports: Vec<u16>,
+}
+
+fn validate_ports(ports: &[u16]) -> Result<(), String> {
+    if ports.contains(&0) {
+        return Err("Port 0 is reserved".to_owned());
+    }
+    Ok(())
+}
+
+impl Listeners {
+    pub fn new() -> Self {
+        Self {
+            ports: vec![80, 443],
+        }
+    }
+}
+
+#[derive(Interact)]
+#[interact(getter(session_token))]
+pub struct Credentials {
+    user: String,
+    #[interact(redact = "write_only")]
+    password: String,
+    #[interact(redact)]
+    private_key: Vec<u8>,
+    api_token: String,
+}
+
+impl Credentials {
+    pub fn new() -> Self {
+        Self {
+            user: "admin".to_owned(),
+            password: "secret".to_owned(),
+            private_key: vec![0x2a; 4],
+            api_token: "abc123".to_owned(),
+        }
+    }
+
+    fn session_token(&self) -> String {
+        format!("{}-session", self.api_token)
+    }
+}
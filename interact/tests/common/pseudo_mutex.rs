@@ -87,7 +87,7 @@ where
     }
 }
 
-use interact::derive_interact_extern_opqaue;
-derive_interact_extern_opqaue! {
+use interact::derive_interact_extern_opaque;
+derive_interact_extern_opaque! {
     struct PseudoMutex<T>;
 }
@@ -1,8 +1,86 @@
 extern crate interact;
 
 use pretty_assertions::assert_eq;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 mod common;
-use common::{Basic, Complex, LocalRcLoop, Rand};
+use common::{
+    Account, Basic, Buffers, Cache, Catalog, Complex, Credentials, Deployment, Fallback, Formatted,
+    Inventory, Listeners, LocalRcLoop, Pipeline, Pool, Queue, Rand, Scheduler, ServerConfig,
+    Worker,
+};
+
+#[cfg(feature = "serde")]
+mod serde_types {
+    use interact::{Interact, Serde};
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize, Deserialize)]
+    pub enum Mode {
+        Off,
+        Fixed(u32),
+        Range { low: u32, high: u32 },
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Config {
+        name: String,
+        mode: Mode,
+        limits: Vec<u16>,
+        labels: BTreeMap<String, bool>,
+        marker: Option<char>,
+    }
+
+    #[derive(Interact)]
+    pub struct Service {
+        #[interact(serde)]
+        config: Config,
+        mode: Serde<Mode>,
+        port: u16,
+    }
+
+    impl Service {
+        pub fn new() -> Self {
+            let mut labels = BTreeMap::new();
+            labels.insert("public".to_owned(), true);
+
+            Self {
+                config: Config {
+                    name: "api".to_owned(),
+                    mode: Mode::Range { low: 1, high: 4 },
+                    limits: vec![10, 20],
+                    labels,
+                    marker: None,
+                },
+                mode: Serde(Mode::Fixed(3)),
+                port: 80,
+            }
+        }
+    }
+}
+
+/// Describe the type of the value at the end of the path, as shown by `:schema`.
+fn schema(root: &mut interact::Root, path: &str) -> Option<String> {
+    let type_info = root.type_info(path).ok()??;
+    let mut schemas = interact::Schemas::new();
+    let schema = type_info.describe(&mut schemas);
+    Some(schemas.resolve(&schema).to_string())
+}
+
+fn pipeline_json_schema() -> String {
+    let mut root = interact::RootSend::new();
+    root.owned.insert("pipeline", Box::new(Pipeline::new()));
+    root.insert_fn("scale", &["value", "factor"], |value: u32, factor: u32| {
+        value * factor
+    });
+    root.as_root().json_schema()
+}
+
+/// The number of `Code` values rendered since the last call.
+fn take_code_renders() -> usize {
+    common::CODE_RENDERS.swap(0, Ordering::SeqCst)
+}
 
 struct Context {
     count: usize,
@@ -64,6 +142,45 @@ fn main() {
     }
 }
 
+#[test]
+#[should_panic(expected = "Function pipeline would be shadowed by the object of the same name")]
+fn root_function_shadowed_by_object() {
+    let mut root = interact::RootSend::new();
+    root.insert("pipeline", Box::new(Pipeline::new()));
+    root.insert_fn("pipeline", &["value"], |value: u32| value);
+}
+
+#[test]
+#[should_panic(expected = "Object scale would shadow the function of the same name")]
+fn root_object_shadowing_function() {
+    let mut root = interact::RootLocal::new();
+    root.insert_fn("scale", &["value", "factor"], |value: u32, factor: u32| {
+        value * factor
+    });
+    root.insert("scale", Box::new(Pipeline::new()));
+}
+
+#[test]
+#[should_panic(expected = "The name scale is registered more than once in the root")]
+fn root_function_shadowed_across_registries() {
+    let mut root = interact::RootSend::new();
+    root.insert_fn("scale", &["value", "factor"], |value: u32, factor: u32| {
+        value * factor
+    });
+    let mut root_local = interact::RootLocal::new();
+    root_local.insert("scale", Box::new(Pipeline::new()));
+    interact::Root::new(Some(&mut root), Some(&mut root_local));
+}
+
+#[test]
+#[should_panic(expected = "The name pipeline is registered more than once in the root")]
+fn root_object_inserted_directly() {
+    let mut root = interact::RootSend::new();
+    root.insert_fn("pipeline", &["value"], |value: u32| value);
+    root.owned.insert("pipeline", Box::new(Pipeline::new()));
+    root.as_root();
+}
+
 #[rustfmt::skip]
 impl Context {
     fn main(&mut self) {
@@ -74,12 +191,33 @@ impl Context {
 
         root.owned.insert("complex", Box::new(Complex::new_random(&mut rng)));
         root.owned.insert("basic", Box::new(Basic::new_random(&mut rng)));
+        root.owned.insert("buffers", Box::new(Buffers::new()));
+        root.owned.insert("formatted", Box::new(Formatted::new()));
+        root.owned.insert("fallback", Box::new(Fallback::new()));
+        root.owned.insert("deployment", Box::new(Deployment::new()));
+        root.owned.insert("queue", Box::new(Queue::new()));
+        root.owned.insert("worker", Box::new(Worker::new()));
+        root.owned.insert("cache", Box::new(Cache::new()));
+        root.owned.insert("scheduler", Box::new(Scheduler::new()));
+        root.owned.insert("inventory", Box::new(Inventory::new()));
+        root.owned.insert("catalog", Box::new(Catalog::new()));
+        root.owned.insert("account", Box::new(Account::new()));
+        root.owned.insert("server_config", Box::new(ServerConfig::new()));
+        root.owned.insert("pipeline", Box::new(Pipeline::new()));
+        root.owned.insert("pool", Box::new(Pool::new()));
+        root.owned.insert("listeners", Box::new(Listeners::new()));
+        root.owned.insert("credentials", Box::new(Credentials::new()));
+        #[cfg(feature = "serde")]
+        root.owned.insert("service", Box::new(serde_types::Service::new()));
         root_local.owned.insert("rc_loops", Box::new(LocalRcLoop::new_random(&mut rng)));
 
-        let mut root = interact::Root {
-            send: Some(&mut root),
-            local: Some(&mut root_local),
-        };
+        let flushes = Arc::new(AtomicUsize::new(0));
+        let flushes_fn = flushes.clone();
+        root.insert_fn("flush_all_caches", &[], move || flushes_fn.fetch_add(1, Ordering::SeqCst) + 1);
+        root.insert_fn("scale", &["value", "factor"], |value: u32, factor: u32| value * factor);
+        root_local.insert_fn("greet", &["name"], |name: String| format!("hello {}", name));
+
+        let mut root = interact::Root::new(Some(&mut root), Some(&mut root_local));
 
         // Check for a non-existing root key
 
@@ -109,6 +247,404 @@ impl Context {
         verify!(self, root.access("complex.add(3)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
         verify!(self, root.access("complex.tuple_1.0 = 7") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
 
+        // Byte buffer rendering
+
+        verify!(self, root.access("buffers.key") => "(Ok(NodeTree { info: Leaf(\"x\\\"deadbeef00010203\\\"\"), meta: Some(Wrap(1)), size: 20 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access("buffers.token") => "(Ok(NodeTree { info: Leaf(\"\\\"aW50ZXJhY3Q=\\\"\"), meta: Some(Wrap(1)), size: 15 }), Assist { valid: 13, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access("buffers.name") => "(Ok(NodeTree { info: Leaf(\"\\\"café �\\\"\"), meta: Some(Wrap(1)), size: 12 }), Assist { valid: 12, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access("buffers.packet").0.unwrap().info.to_string() => "\"00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|\\n00000010  10 11 12 13 14 15 16 17  18 19 1a 1b 1c 1d 1e 1f  |................|\\n00000020  20 21 22 23 24 25 26 27                           | !\\\"#$%&'|\"");
+        verify!(self, root.access("buffers.key[1]") => "(Ok(NodeTree { info: Leaf(\"173\"), meta: Some(Wrap(1)), size: 4 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access_as("buffers.raw", interact::BytesMode::Hex) => "(Ok(NodeTree { info: Leaf(\"x\\\"6869\\\"\"), meta: Some(Wrap(1)), size: 8 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access_as("basic.arr", interact::BytesMode::Hex) => "(Ok(NodeTree { info: Leaf(\"x\\\"29645a8d\\\"\"), meta: Some(Wrap(1)), size: 12 }), Assist { valid: 9, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access_as("complex.vec", interact::BytesMode::Hex) => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"2538836651\"), meta: Some(Wrap(1)), size: 11 }, NodeTree { info: Leaf(\"VarUnit\"), meta: Some(Wrap(1)), size: 8 }]), meta: None, size: 24 }, ')'), meta: Some(Wrap(1)), size: 27 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"1523598186\"), meta: Some(Wrap(1)), size: 11 }, NodeTree { info: Named(NodeTree { info: Leaf(\"VarNamed\"), meta: None, size: 9 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"a\"), meta: None, size: 2 }, \":\", NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), meta: None, size: 8 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"b\"), meta: None, size: 2 }, \":\", NodeTree { info: Leaf(\"4\"), meta: Some(Wrap(1)), size: 2 }), meta: None, size: 8 }]), meta: None, size: 21 }, '}'), meta: None, size: 24 }), meta: Some(Wrap(1)), size: 34 }]), meta: None, size: 50 }, ')'), meta: Some(Wrap(1)), size: 53 }]), meta: None, size: 85 }, ']'), meta: None, size: 88 }), meta: Some(Wrap(1)), size: 93 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+
+        // Byte string assignment
+
+        verify!(self, root.access("buffers.raw = b\"a\\x00\\n\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("buffers.raw") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"97\"), meta: Some(Wrap(1)), size: 3 }, NodeTree { info: Leaf(\"0\"), meta: Some(Wrap(1)), size: 2 }, NodeTree { info: Leaf(\"10\"), meta: Some(Wrap(1)), size: 3 }]), meta: None, size: 15 }, ']'), meta: None, size: 18 }), meta: Some(Wrap(1)), size: 23 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access("buffers.key = x\"0102 0304 0506 0708\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 36, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("buffers.key") => "(Ok(NodeTree { info: Leaf(\"x\\\"0102030405060708\\\"\"), meta: Some(Wrap(1)), size: 20 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access("buffers.key = x\"0102\"") => "(Err(AssignError(Deser(WrongLength))), Assist { valid: 11, pending: 10, pending_special: 0, next_options: Avail(10, []) })");
+        verify!(self, root.access("buffers.key = [1, 2, 3, 4, 5, 6, 7, 8]") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 38, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("buffers.key") => "(Ok(NodeTree { info: Leaf(\"x\\\"0102030405060708\\\"\"), meta: Some(Wrap(1)), size: 20 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access("buffers.raw = x\"012\"") => "(Err(TokenError(OddHexDigits)), Assist { valid: 0, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("buffers.raw = x\"\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access_as("buffers.raw", interact::BytesMode::Hex) => "(Ok(NodeTree { info: Leaf(\"x\\\"\\\"\"), meta: Some(Wrap(1)), size: 4 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access_as("buffers.raw", interact::BytesMode::HexDump) => "(Ok(NodeTree { info: Leaf(\"[]\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access("buffers.raw") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', []), meta: None, size: 1 }, ']'), meta: None, size: 4 }), meta: Some(Wrap(1)), size: 9 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+
+        // Per-field formatting
+
+        verify!(self, root.access("formatted").0.unwrap().info.to_string() => "\"Formatted { flags : 0x1f, mask : 0b1010, mode : 0o755, label : Some(\\\"main\\\"), title : Main unit, temp : 21°C, state : Busy { temp : -4°C } }\"");
+        verify!(self, root.access("formatted.flags") => "(Ok(NodeTree { info: Leaf(\"0x1f\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 15, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("formatted.flags = 255") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("formatted.flags").0.unwrap().info.to_string() => "\"0xff\"");
+        verify!(self, root.access("formatted.retries = 3") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("formatted.state.Busy.pending = 2") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 32, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("formatted").0.unwrap().info.to_string() => "\"Formatted { flags : 0xff, mask : 0b1010, mode : 0o755, label : Some(\\\"main\\\"), title : Main unit, temp : 21°C, retries : 3, state : Busy { pending : 2, temp : -4°C } }\"");
+        verify!(self, root.access("formatted.state.Busy.temp").0.unwrap().info.to_string() => "\"-4°C\"");
+
+        // Fallback for non-Interact field types
+
+        verify!(self, root.access("fallback").0.unwrap().info.to_string() => "\"Fallback { addr : 192.168.1.1, order : Less, port : 8080, endpoint : Endpoint ( 127.0.0.1, 22 ) }\"");
+        verify!(self, root.access("fallback.order") => "(Ok(NodeTree { info: Leaf(\"Less\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("fallback.order = Greater") => "(Err(AssignError(Unbuildable)), Assist { valid: 14, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("fallback.addr = \"10.0.0.1\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("fallback.addr = \"10.0.0\"") => "(Err(AssignError(Deser(FromStr(\"invalid IPv4 address syntax\")))), Assist { valid: 13, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("fallback.port = \"0\"") => "(Err(AssignError(Deser(FromStr(\"number would be zero for non-zero type\")))), Assist { valid: 13, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("fallback.port = \"443\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("fallback.endpoint = Endpoint(\"10.0.0.2\", 80)") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 44, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("fallback").0.unwrap().info.to_string() => "\"Fallback { addr : 10.0.0.1, order : Less, port : 443, endpoint : Endpoint ( 10.0.0.2, 80 ) }\"");
+
+        // Remote types
+
+        verify!(self, root.access("deployment").0.unwrap().info.to_string() => "\"Deployment { config : Config { name : \\\"staging\\\", retries : 3, verbose : false }, version : Version ( 1, 4 ) }\"");
+        verify!(self, root.access("deployment.config.retries") => "(Ok(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("deployment.config.retries = 5") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 29, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("deployment.config = Config { name: \"prod\", retries: 1, verbose: true }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 70, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("deployment.config = Config { name: \"prod\", ver") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 17, pending: 29, pending_special: 0, next_options: Avail(26, [\"verbose\"]) })");
+        verify!(self, root.access("deployment.version = Version(2, 0)") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 34, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("deployment.version.1") => "(Ok(NodeTree { info: Leaf(\"0\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 20, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("deployment").0.unwrap().info.to_string() => "\"Deployment { config : Config { name : \\\"prod\\\", retries : 1, verbose : true }, version : Version ( 2, 0 ) }\"");
+
+        // Getters
+
+        verify!(self, root.access("queue").0.unwrap().info.to_string() => "\"Queue { items : Vec [ 4, 9, 2 ], capacity : 8, depth : 3, stats : QueueStats { min : Some ( 2 ), max : Some ( 9 ) } }\"");
+        verify!(self, root.access("queue.depth") => "(Ok(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("queue.stats.max") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"9\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 5 }, ')'), meta: None, size: 8 }), meta: Some(Wrap(1)), size: 14 }), Assist { valid: 15, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("queue.st") => "(Err(UnexpectedToken), Assist { valid: 5, pending: 3, pending_special: 0, next_options: Avail(1, [\"stats\"]) })");
+        verify!(self, root.access("queue.depth = 2") => "(Err(NeedMutPath), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("queue.items[1] = 12") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 19, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("queue").0.unwrap().info.to_string() => "\"Queue { items : Vec [ 4, 12, 2 ], capacity : 8, depth : 3, stats : QueueStats { min : Some ( 2 ), max : Some ( 12 ) } }\"");
+
+        // Methods from impl blocks
+
+        verify!(self, <Worker as interact::Methods>::functions().iter().map(|f| (f.to_string(), f.doc)).collect::<Vec<_>>() => "[(\"submit(job: u32) -> bool\", \"Queue a job, returning whether there was room for it.\"), (\"resize(capacity: usize, keep_first: bool = true) -> ()\", \"Change the capacity of the worker.\\n\\nJobs beyond the new capacity are dropped.\"), (\"load() -> (usize, usize)\", \"\"), (\"flush() -> usize\", \"Remove all the jobs, returning how many there were.\")]");
+        verify!(self, root.access("worker.submit(5)") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker.submit(7)") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker.load()") => "(Ok(NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }, NodeTree { info: Leaf(\"4\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 9 }, ')'), meta: Some(Wrap(1)), size: 12 }), Assist { valid: 13, pending: 0, pending_special: 0, next_options: Avail(0, [\".0\", \".1\"]) })");
+        verify!(self, root.access("worker.resize(1, false)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker.res") => "(Err(UnexpectedToken), Assist { valid: 6, pending: 4, pending_special: 0, next_options: Avail(1, [\"resize(\"]) })");
+        verify!(self, root.access("worker.secret()") => "(Err(UnexpectedToken), Assist { valid: 6, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("worker.resize(1, tru") => "(Err(CallError(Deser(UnexpectedToken))), Assist { valid: 6, pending: 14, pending_special: 0, next_options: Avail(11, [\"true\"]) })");
+        verify!(self, root.access("worker.resize(4)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker.resize(keep_first: false, capacity: 8)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 45, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker.resize(capacity: 2, 1)") => "(Err(CallError(Deser(UnexpectedToken))), Assist { valid: 6, pending: 20, pending_special: 0, next_options: Avail(20, []) })");
+        verify!(self, root.access("worker.resize(keep_first: true)") => "(Err(CallError(Deser(MissingArgument(\"capacity\")))), Assist { valid: 6, pending: 25, pending_special: 0, next_options: Avail(25, []) })");
+        verify!(self, root.access("worker.resize(1, ke") => "(Err(CallError(Deser(UnexpectedToken))), Assist { valid: 6, pending: 13, pending_special: 0, next_options: Avail(11, [\"keep_first\"]) })");
+        verify!(self, root.access("worker.resize(1") => "(Err(CallError(Deser(EndOfTokenList))), Assist { valid: 6, pending: 9, pending_special: 0, next_options: Avail(9, [\", \", \")\"]) })");
+        verify!(self, root.call_signature("worker.resize(1, ") => "Some(\"resize(capacity: usize, keep_first: bool = true) -> ()\")");
+        verify!(self, root.call_signature("complex.add(") => "Some(\"add(a: u32) -> ()\")");
+        verify!(self, root.call_signature("worker.load()") => "None");
+        verify!(self, root.access("worker").0.unwrap().info.to_string() => "\"Worker { name : \\\"w1\\\", capacity : 8, jobs : Vec [ 7 ] }\"");
+
+        // Async methods
+
+        verify!(self, root.access("cache.store(3, 30)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("cache.store(4)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("cache.fetch(3)") => "(Ok(NodeTree { info: Leaf(\"30\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("cache.fetch(4)") => "(Ok(NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("cache.store(5, 50)") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Cache\"), meta: None, size: 6 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"entries\"), meta: None, size: 8 }, \":\", NodeTree { info: Named(NodeTree { info: Leaf(\"BTreeMap\"), meta: None, size: 9 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }, \":\", NodeTree { info: Leaf(\"30\"), meta: Some(Wrap(1)), size: 3 }), meta: None, size: 9 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"4\"), meta: Some(Wrap(1)), size: 2 }, \":\", NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }), meta: None, size: 8 }]), meta: None, size: 22 }, '}'), meta: None, size: 25 }), meta: Some(Wrap(1)), size: 35 }), meta: None, size: 47 }]), meta: None, size: 50 }, '}'), meta: None, size: 53 }), meta: Some(Wrap(1)), size: 60 }), Assist { valid: 5, pending: 13, pending_special: 0, next_options: Avail(13, []) })");
+        verify!(self, root.access("cache.fetch(5)") => "(Ok(NodeTree { info: Leaf(\"0\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.call_signature("cache.fetch(") => "Some(\"fetch(key: u32) -> u32\")");
+        verify!(self, root.access("worker.flush()") => "(Ok(NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("worker").0.unwrap().info.to_string() => "\"Worker { name : \\\"w1\\\", capacity : 8, jobs : Vec [] }\"");
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let hook_calls = calls.clone();
+        interact::executor::set_executor(move |future| {
+            hook_calls.fetch_add(1, Ordering::SeqCst);
+            interact::executor::simple_block_on(future)
+        });
+        verify!(self, root.access("cache.fetch(3)") => "(Ok(NodeTree { info: Leaf(\"30\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, calls.load(Ordering::SeqCst) => "1");
+        interact::executor::clear_executor();
+
+        // Objects as function arguments
+
+        verify!(self, root.access("scheduler.assign(worker)") => "(Ok(NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scheduler.room(worker, 2)") => "(Ok(NodeTree { info: Leaf(\"10\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scheduler.room(extra: 1, worker: worker)") => "(Ok(NodeTree { info: Leaf(\"9\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 40, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scheduler.double(queue.capacity)") => "(Ok(NodeTree { info: Leaf(\"16\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 32, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scheduler.double(3)") => "(Ok(NodeTree { info: Leaf(\"6\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 19, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scheduler.observe(queue.stats)") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"12\"), meta: Some(Wrap(1)), size: 3 }]), meta: None, size: 6 }, ')'), meta: None, size: 9 }), meta: Some(Wrap(1)), size: 15 }), Assist { valid: 30, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("complex.add(queue.items[0])") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 27, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("complex.add(basic.u_16)") => "(Err(CallError(ObjectType(\"a\"))), Assist { valid: 7, pending: 16, pending_special: 0, next_options: Avail(16, []) })");
+        verify!(self, root.probe("scheduler.room(worker, 1)") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Scheduler\"), meta: None, size: 10 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"assigned\"), meta: None, size: 9 }, \":\", NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"w1\\\"\"), meta: Some(Wrap(1)), size: 5 }]), meta: None, size: 8 }, ']'), meta: None, size: 11 }), meta: Some(Wrap(1)), size: 16 }), meta: None, size: 29 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"max_seen\"), meta: None, size: 9 }, \":\", NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"12\"), meta: Some(Wrap(1)), size: 3 }]), meta: None, size: 6 }, ')'), meta: None, size: 9 }), meta: Some(Wrap(1)), size: 15 }), meta: None, size: 28 }]), meta: None, size: 62 }, '}'), meta: None, size: 65 }), meta: Some(Wrap(1)), size: 76 }), Assist { valid: 9, pending: 16, pending_special: 0, next_options: Avail(16, []) })");
+        verify!(self, root.access("scheduler.assign(5)") => "(Err(CallError(ObjectRequired(\"worker\"))), Assist { valid: 9, pending: 8, pending_special: 0, next_options: Avail(8, []) })");
+        verify!(self, root.access("scheduler.assign(queue)") => "(Err(CallError(ObjectType(\"worker\"))), Assist { valid: 9, pending: 14, pending_special: 0, next_options: Avail(14, []) })");
+        verify!(self, root.access("scheduler.assign(worker.jobs)") => "(Err(CallError(ObjectType(\"worker\"))), Assist { valid: 9, pending: 20, pending_special: 0, next_options: Avail(20, []) })");
+        verify!(self, root.access("scheduler.assign(worker.missing)") => "(Err(CallError(ObjectNotFound(\"worker\"))), Assist { valid: 9, pending: 23, pending_special: 0, next_options: Avail(23, []) })");
+        verify!(self, root.access("scheduler.assign(scheduler)") => "(Err(CallError(ObjectBorrowed(\"worker\"))), Assist { valid: 9, pending: 18, pending_special: 0, next_options: Avail(18, []) })");
+        verify!(self, root.access("scheduler.take(worker)") => "(Err(CallError(ObjectNotCloneable(\"worker\"))), Assist { valid: 9, pending: 13, pending_special: 0, next_options: Avail(13, []) })");
+        verify!(self, root.access("scheduler").0.unwrap().info.to_string() => "\"Scheduler { assigned : Vec [ \\\"w1\\\" ], max_seen : Some ( 12 ) }\"");
+
+        // Functions registered in the root
+
+        verify!(self, root.keys().into_iter().filter(|k| root.function(k).is_some()).collect::<Vec<_>>() => "[\"flush_all_caches\", \"scale\", \"greet\"]");
+        verify!(self, root.access("flush_all_caches()") => "(Ok(NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("flush_all_caches()") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 16, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, flushes.load(Ordering::SeqCst) => "1");
+        verify!(self, root.probe("flush_all") => "(Err(MissingStartComponent), Assist { valid: 0, pending: 9, pending_special: 0, next_options: Avail(0, [\"flush_all_caches\"]) })");
+        verify!(self, root.probe("flush_all_caches") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, [\"(\"]) })");
+        verify!(self, root.access("scale(3, 4)") => "(Ok(NodeTree { info: Leaf(\"12\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 11, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scale(factor: 5, value: 2)") => "(Ok(NodeTree { info: Leaf(\"10\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("scale(3)") => "(Err(CallError(Deser(MissingArgument(\"factor\")))), Assist { valid: 5, pending: 3, pending_special: 0, next_options: Avail(3, []) })");
+        verify!(self, root.access("scale(3, true)") => "(Err(CallError(Deser(UnexpectedToken))), Assist { valid: 5, pending: 3, pending_special: 0, next_options: Avail(3, []) })");
+        verify!(self, root.call_signature("scale(3, ") => "Some(\"scale(value: u32, factor: u32) -> u32\")");
+        verify!(self, root.access("greet(\"there\")") => "(Ok(NodeTree { info: Leaf(\"\\\"hello there\\\"\"), meta: Some(Wrap(1)), size: 14 }), Assist { valid: 14, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.function("greet").map(|f| f.to_string()) => "Some(\"greet(name: String) -> String\")");
+
+        // Built-in collection functions
+
+        verify!(self, root.access("inventory.items.len()") => "(Ok(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.is_empty()") => "(Ok(NodeTree { info: Leaf(\"false\"), meta: Some(Wrap(1)), size: 6 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.first()") => "(Ok(NodeTree { info: Leaf(\"\\\"bolt\\\"\"), meta: Some(Wrap(1)), size: 7 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.last()") => "(Ok(NodeTree { info: Leaf(\"\\\"nut\\\"\"), meta: Some(Wrap(1)), size: 6 }), Assist { valid: 22, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.push(\"washer\")") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 30, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.insert(0, \"screw\")") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 34, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.insert(9, \"screw\")") => "(Err(CallError(OutOfRange)), Assist { valid: 15, pending: 19, pending_special: 0, next_options: Avail(19, []) })");
+        verify!(self, root.access("inventory.items.remove(1)") => "(Ok(NodeTree { info: Leaf(\"\\\"bolt\\\"\"), meta: Some(Wrap(1)), size: 7 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.remove(9)") => "(Err(CallError(OutOfRange)), Assist { valid: 15, pending: 10, pending_special: 0, next_options: Avail(10, []) })");
+        verify!(self, root.access("inventory.items.pop()") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"washer\\\"\"), meta: Some(Wrap(1)), size: 9 }]), meta: None, size: 12 }, ')'), meta: None, size: 15 }), meta: Some(Wrap(1)), size: 21 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("inventory.items") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"screw\\\"\"), meta: Some(Wrap(1)), size: 8 }, NodeTree { info: Leaf(\"\\\"nut\\\"\"), meta: Some(Wrap(1)), size: 6 }]), meta: None, size: 19 }, ']'), meta: None, size: 22 }), meta: Some(Wrap(1)), size: 27 }), Assist { valid: 15, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \".push(\", \".pop(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.probe("inventory.items.clear()") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Vec\"), meta: None, size: 4 }, NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"screw\\\"\"), meta: Some(Wrap(1)), size: 8 }, NodeTree { info: Leaf(\"\\\"nut\\\"\"), meta: Some(Wrap(1)), size: 6 }]), meta: None, size: 19 }, ']'), meta: None, size: 22 }), meta: Some(Wrap(1)), size: 27 }), Assist { valid: 15, pending: 8, pending_special: 0, next_options: Avail(8, []) })");
+        verify!(self, root.access("inventory.items.len()") => "(Ok(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.clear()") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.items.first()") => "(Err(CallError(OutOfRange)), Assist { valid: 15, pending: 8, pending_special: 0, next_options: Avail(8, []) })");
+        verify!(self, root.access("inventory.slots.last()") => "(Ok(NodeTree { info: Leaf(\"7\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 22, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.slots.push(1)") => "(Err(UnexpectedToken), Assist { valid: 15, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("inventory.counts.contains(\"bolt\")") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 33, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.counts.insert(\"bolt\", 4)") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 5 }, ')'), meta: None, size: 8 }), meta: Some(Wrap(1)), size: 14 }), Assist { valid: 34, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("inventory.counts[\"nut\"]") => "(Err(NotFound), Assist { valid: 16, pending: 6, pending_special: 0, next_options: Avail(6, []) })");
+        verify!(self, root.access("inventory.counts[\"nut\"] = 10") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 28, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("inventory.counts[\"pin\"] = 1") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 27, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.counts.contains(\"pin\")") => "(Ok(NodeTree { info: Leaf(\"false\"), meta: Some(Wrap(1)), size: 6 }), Assist { valid: 32, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.counts[\"nut\"]") => "(Ok(NodeTree { info: Leaf(\"10\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.counts.remove(\"bolt\")") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"4\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 5 }, ')'), meta: None, size: 8 }), meta: Some(Wrap(1)), size: 14 }), Assist { valid: 31, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("inventory.counts.keys()") => "(Ok(NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"nut\\\"\"), meta: Some(Wrap(1)), size: 6 }]), meta: None, size: 9 }, ']'), meta: Some(Wrap(1)), size: 12 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access("inventory.labels.keys()") => "(Ok(NodeTree { info: Grouped('[', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"1\"), meta: Some(Wrap(1)), size: 2 }, NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 9 }, ']'), meta: Some(Wrap(1)), size: 12 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".first(\", \".last(\", \"[\"]) })");
+        verify!(self, root.access("inventory.labels.keys().len()") => "(Ok(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 29, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.labels[3] = \"three\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 29, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.labels[3] = 3") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 19, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("inventory.labels.remove(1)") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"\\\"one\\\"\"), meta: Some(Wrap(1)), size: 6 }]), meta: None, size: 9 }, ')'), meta: None, size: 12 }), meta: Some(Wrap(1)), size: 18 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("inventory.labels") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"BTreeMap\"), meta: None, size: 9 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }, \":\", NodeTree { info: Leaf(\"\\\"two\\\"\"), meta: Some(Wrap(1)), size: 6 }), meta: None, size: 12 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }, \":\", NodeTree { info: Leaf(\"\\\"three\\\"\"), meta: Some(Wrap(1)), size: 8 }), meta: None, size: 14 }]), meta: None, size: 31 }, '}'), meta: None, size: 34 }), meta: Some(Wrap(1)), size: 44 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, [\".len(\", \".is_empty(\", \".contains(\", \".keys(\", \".insert(\", \".remove(\", \".clear(\", \"[\"]) })");
+        verify!(self, root.access("inventory.tags.contains(7)") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.tags.insert(7)") => "(Ok(NodeTree { info: Leaf(\"false\"), meta: Some(Wrap(1)), size: 6 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.tags.remove(7)") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.tags.is_empty()") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.call_signature("inventory.counts.insert(") => "Some(\"insert(key: K, value: V) -> Option<V>\")");
+
+        // Completion of subscripts
+
+        verify!(self, root.access("inventory.counts[\"bolt\"] = 2") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 28, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("inventory.counts[") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 1, pending_special: 0, next_options: Avail(1, [\"\\\"bolt\\\"]\", \"\\\"nut\\\"]\"]) })");
+        verify!(self, root.probe("inventory.counts[\"") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 2, pending_special: 0, next_options: Avail(1, [\"\\\"bolt\\\"]\", \"\\\"nut\\\"]\"]) })");
+        verify!(self, root.probe("inventory.counts[\"bolt\"") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 7, pending_special: 0, next_options: Avail(1, [\"\\\"bolt\\\"]\"]) })");
+        verify!(self, root.probe("inventory.counts[\"bolt\"]") => "(Ok(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("inventory.labels[") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 1, pending_special: 0, next_options: Avail(1, [\"2]\", \"3]\"]) })");
+        verify!(self, root.probe("inventory.labels[3") => "(Err(UnexpectedExpressionEnd), Assist { valid: 16, pending: 2, pending_special: 0, next_options: Avail(1, [\"3]\"]) })");
+        verify!(self, root.probe("inventory.labels[4") => "(Err(NotFound), Assist { valid: 16, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("inventory.tags.insert(12)") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("inventory.tags[1") => "(Err(UnexpectedExpressionEnd), Assist { valid: 14, pending: 2, pending_special: 0, next_options: Avail(1, [\"12]\"]) })");
+        verify!(self, root.access("inventory.tags[12]") => "(Ok(NodeTree { info: Leaf(\"12\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("inventory.tags[13]") => "(Err(NotFound), Assist { valid: 14, pending: 3, pending_special: 0, next_options: Avail(3, []) })");
+        verify!(self, root.probe("inventory.slots[") => "(Err(UnexpectedExpressionEnd), Assist { valid: 15, pending: 1, pending_special: 0, next_options: Avail(1, [\"0]\", \"1]\", \"2]\"]) })");
+        verify!(self, root.probe("inventory.items[") => "(Err(DeserError(EndOfTokenList)), Assist { valid: 15, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        take_code_renders();
+        verify!(self, root.access("catalog.codes[Code(3)]") => "(Ok(NodeTree { info: Leaf(\"30\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 22, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, take_code_renders() => "0");
+        verify!(self, root.probe("catalog.codes[") => "(Err(UnexpectedExpressionEnd), Assist { valid: 13, pending: 1, pending_special: 0, next_options: Avail(1, [\"Code ( 1 )]\", \"Code ( 10 )]\", \"Code ( 11 )]\", \"Code ( 12 )]\", \"Code ( 13 )]\", \"Code ( 14 )]\", \"Code ( 15 )]\", \"Code ( 16 )]\", \"Code ( 17 )]\", \"Code ( 18 )]\", \"Code ( 19 )]\", \"Code ( 2 )]\", \"Code ( 20 )]\", \"Code ( 3 )]\", \"Code ( 4 )]\", \"Code ( 5 )]\", \"Code ( 6 )]\", \"Code ( 7 )]\", \"Code ( 8 )]\", \"Code ( 9 )]\"]) })");
+        verify!(self, take_code_renders() => "20");
+        verify!(self, root.probe("catalog.codes[Code") => "(Err(UnexpectedExpressionEnd), Assist { valid: 13, pending: 5, pending_special: 0, next_options: Avail(1, [\"Code ( 1 )]\", \"Code ( 10 )]\", \"Code ( 11 )]\", \"Code ( 12 )]\", \"Code ( 13 )]\", \"Code ( 14 )]\", \"Code ( 15 )]\", \"Code ( 16 )]\", \"Code ( 17 )]\", \"Code ( 18 )]\", \"Code ( 19 )]\", \"Code ( 2 )]\", \"Code ( 20 )]\", \"Code ( 3 )]\", \"Code ( 4 )]\", \"Code ( 5 )]\", \"Code ( 6 )]\", \"Code ( 7 )]\", \"Code ( 8 )]\", \"Code ( 9 )]\"]) })");
+
+        // Optional chaining
+
+        verify!(self, root.access("basic.option_some?") => "(Ok(NodeTree { info: Leaf(\"193\"), meta: Some(Wrap(1)), size: 4 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.option_none?") => "(Ok(NodeTree { info: Leaf(\"<None>\"), meta: None, size: 7 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.result_ok?") => "(Ok(NodeTree { info: Leaf(\"185\"), meta: Some(Wrap(1)), size: 4 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.result_err?") => "(Ok(NodeTree { info: Leaf(\"<Err ( 4102917090 )>\"), meta: None, size: 21 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.option_none?.value") => "(Ok(NodeTree { info: Leaf(\"<None>\"), meta: None, size: 7 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.result_err?.value") => "(Ok(NodeTree { info: Leaf(\"<Err ( 4102917090 )>\"), meta: None, size: 21 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.option_none?.value = 3") => "(Err(NotAssigned), Assist { valid: 17, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.probe("basic.result_err? = 3") => "(Err(NotAssigned), Assist { valid: 16, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("basic.option_none") => "(Ok(NodeTree { info: Leaf(\"None\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, [\".None\"]) })");
+        verify!(self, root.access("basic.option_some? = 9") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 22, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("basic.option_some") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Leaf(\"9\"), meta: Some(Wrap(1)), size: 2 }]), meta: None, size: 5 }, ')'), meta: None, size: 8 }), meta: Some(Wrap(1)), size: 14 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, [\".Some\"]) })");
+        verify!(self, root.access("basic.u_8?") => "(Err(UnexpectedToken), Assist { valid: 9, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain?") => "(Err(UnexpectedToken), Assist { valid: 14, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("rc_loops.chain.nest?.value") => "(Ok(NodeTree { info: Leaf(\"4280494281\"), meta: Some(Wrap(1)), size: 11 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest?.nest?.nest?.value") => "(Ok(NodeTree { info: Leaf(\"<None>\"), meta: None, size: 7 }), Assist { valid: 38, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest?.value = 3") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 30, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("rc_loops.chain.nest?.") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Chain\"), meta: None, size: 6 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"value\"), meta: None, size: 6 }, \":\", NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), meta: None, size: 12 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"nest\"), meta: None, size: 5 }, \":\", NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Named(NodeTree { info: Leaf(\"Chain\"), meta: None, size: 6 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"value\"), meta: None, size: 6 }, \":\", NodeTree { info: Leaf(\"2902727629\"), meta: Some(Wrap(1)), size: 11 }), meta: None, size: 21 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"nest\"), meta: None, size: 5 }, \":\", NodeTree { info: Leaf(\"None\"), meta: Some(Wrap(1)), size: 5 }), meta: None, size: 14 }]), meta: None, size: 40 }, '}'), meta: None, size: 43 }), meta: Some(Wrap(1)), size: 50 }]), meta: None, size: 53 }, ')'), meta: None, size: 56 }), meta: Some(Wrap(1)), size: 62 }), meta: None, size: 71 }]), meta: None, size: 88 }, '}'), meta: None, size: 91 }), meta: Some(Wrap(1)), size: 98 }), Assist { valid: 20, pending: 1, pending_special: 0, next_options: Avail(1, [\"value\", \"nest\"]) })");
+        verify!(self, root.access("rc_loops.chain.nest.value") => "(Ok(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest.nest.value") => "(Ok(NodeTree { info: Leaf(\"2902727629\"), meta: Some(Wrap(1)), size: 11 }), Assist { valid: 30, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest.nest.nest.value") => "(Ok(NodeTree { info: Leaf(\"<None>\"), meta: None, size: 7 }), Assist { valid: 35, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest.nest.nest.value = 3") => "(Err(NotAssigned), Assist { valid: 29, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest.nest.nest.Some") => "(Err(UnexpectedToken), Assist { valid: 29, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("basic.result_err.value") => "(Ok(NodeTree { info: Leaf(\"<Err ( 4102917090 )>\"), meta: None, size: 21 }), Assist { valid: 22, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("rc_loops.chain.nest.Some.0.value") => "(Ok(NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 32, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("rc_loops.chain.nest.") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Named(NodeTree { info: Leaf(\"Chain\"), meta: None, size: 6 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"value\"), meta: None, size: 6 }, \":\", NodeTree { info: Leaf(\"3\"), meta: Some(Wrap(1)), size: 2 }), meta: None, size: 12 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"nest\"), meta: None, size: 5 }, \":\", NodeTree { info: Named(NodeTree { info: Leaf(\"Some\"), meta: None, size: 5 }, NodeTree { info: Grouped('(', NodeTree { info: Delimited(',', [NodeTree { info: Named(NodeTree { info: Leaf(\"Chain\"), meta: None, size: 6 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"value\"), meta: None, size: 6 }, \":\", NodeTree { info: Leaf(\"2902727629\"), meta: Some(Wrap(1)), size: 11 }), meta: None, size: 21 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"nest\"), meta: None, size: 5 }, \":\", NodeTree { info: Leaf(\"None\"), meta: Some(Wrap(1)), size: 5 }), meta: None, size: 14 }]), meta: None, size: 40 }, '}'), meta: None, size: 43 }), meta: Some(Wrap(1)), size: 50 }]), meta: None, size: 53 }, ')'), meta: None, size: 56 }), meta: Some(Wrap(1)), size: 62 }), meta: None, size: 71 }]), meta: None, size: 88 }, '}'), meta: None, size: 91 }), meta: Some(Wrap(1)), size: 98 }]), meta: None, size: 101 }, ')'), meta: None, size: 104 }), meta: Some(Wrap(1)), size: 110 }), Assist { valid: 19, pending: 1, pending_special: 0, next_options: Avail(1, [\"Some\"]) })");
+
+        // Transparent newtypes
+
+        verify!(self, root.access("account").0.unwrap().info.to_string() => "\"Account { owner : 42, friends : Vec [ 7, 9 ], height : Millimeters 1500, home : Home Location { lat : 32, lon : 34 }, scores : BTreeMap { 7 : 10, 9 : 20 } }\"");
+        verify!(self, root.access("account.owner") => "(Ok(NodeTree { info: Leaf(\"42\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 13, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.owner = 43") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.owner").0.unwrap().info.to_string() => "\"43\"");
+        verify!(self, root.access("account.owner = UserId(43)") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 13, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("account.friends = [1, 2, 3]") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 27, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.friends.push(4)") => "(Ok(NodeTree { info: Leaf(\"()\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.friends").0.unwrap().info.to_string() => "\"Vec [ 1, 2, 3, 4 ]\"");
+        verify!(self, root.access("account.height").0.unwrap().info.to_string() => "\"Millimeters 1500\"");
+        verify!(self, root.access("account.height = 1750") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.height").0.unwrap().info.to_string() => "\"Millimeters 1750\"");
+        verify!(self, root.access("account.home").0.unwrap().info.to_string() => "\"Home Location { lat : 32, lon : 34 }\"");
+        verify!(self, root.access("account.home.lat") => "(Ok(NodeTree { info: Leaf(\"32\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.home.lat = 31") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.home = Location { lat: 1, lon: 2 }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 42, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("account.home").0.unwrap().info.to_string() => "\"Home Location { lat : 1, lon : 2 }\"");
+        verify!(self, root.probe("account.home.l") => "(Err(UnexpectedToken), Assist { valid: 12, pending: 2, pending_special: 0, next_options: Avail(1, [\"lat\", \"lon\"]) })");
+        verify!(self, root.probe("account.scores[") => "(Err(UnexpectedExpressionEnd), Assist { valid: 14, pending: 1, pending_special: 0, next_options: Avail(1, [\"7]\", \"9]\"]) })");
+        verify!(self, root.access("account.scores[9]") => "(Ok(NodeTree { info: Leaf(\"20\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+
+        // Flattened fields
+
+        verify!(self, root.access("server_config").0.unwrap().info.to_string() => "\"ServerConfig { name : \\\"edge\\\", enabled : true, max_conns : 100, timeout : 30, port : 443 }\"");
+        verify!(self, root.access("server_config.enabled") => "(Ok(NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("server_config.timeout = 60") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 26, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("server_config.timeout") => "(Ok(NodeTree { info: Leaf(\"60\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("server_config.tls") => "(Err(UnexpectedToken), Assist { valid: 13, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.access("server_config.limits") => "(Err(UnexpectedToken), Assist { valid: 13, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.probe("server_config.") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"ServerConfig\"), meta: None, size: 13 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"name\"), meta: None, size: 5 }, \":\", NodeTree { info: Leaf(\"\\\"edge\\\"\"), meta: Some(Wrap(1)), size: 7 }), meta: None, size: 16 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"enabled\"), meta: None, size: 8 }, \":\", NodeTree { info: Leaf(\"true\"), meta: Some(Wrap(1)), size: 5 }), meta: None, size: 17 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"max_conns\"), meta: None, size: 10 }, \":\", NodeTree { info: Leaf(\"100\"), meta: Some(Wrap(1)), size: 4 }), meta: None, size: 18 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"timeout\"), meta: None, size: 8 }, \":\", NodeTree { info: Leaf(\"60\"), meta: Some(Wrap(1)), size: 3 }), meta: None, size: 15 }, NodeTree { info: Tuple(NodeTree { info: Leaf(\"port\"), meta: None, size: 5 }, \":\", NodeTree { info: Leaf(\"443\"), meta: Some(Wrap(1)), size: 4 }), meta: None, size: 13 }]), meta: None, size: 90 }, '}'), meta: None, size: 93 }), meta: Some(Wrap(1)), size: 107 }), Assist { valid: 13, pending: 1, pending_special: 0, next_options: Avail(1, [\"name\", \"enabled\", \"max_conns\", \"timeout\", \"port\"]) })");
+        verify!(self, root.probe("server_config.ma") => "(Err(UnexpectedToken), Assist { valid: 13, pending: 3, pending_special: 0, next_options: Avail(1, [\"max_conns\"]) })");
+        verify!(self, root.access("server_config = ServerConfig { name: \"core\", tls: Tls { enabled: false, limits: Limits { max_conns: 1, timeout: 2 } }, port: 80 }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 129, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("server_config").0.unwrap().info.to_string() => "\"ServerConfig { name : \\\"core\\\", enabled : false, max_conns : 1, timeout : 2, port : 80 }\"");
+        verify!(self, interact::has_unique_fields(&<ServerConfig as interact::StructFields>::FIELDS) => "true");
+
+        // Renaming
+
+        verify!(self, root.access("pipeline").0.unwrap().info.to_string() => "\"Pipeline { stageCount : 3, state : warming_up { progress : 40 }, previous : draining }\"");
+        verify!(self, root.access("pipeline.state.warming_up.progress") => "(Ok(NodeTree { info: Leaf(\"40\"), meta: Some(Wrap(1)), size: 3 }), Assist { valid: 34, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pipeline.phase") => "(Err(UnexpectedToken), Assist { valid: 8, pending: 1, pending_special: 0, next_options: Avail(1, []) })");
+        verify!(self, root.probe("pipeline.s") => "(Err(UnexpectedToken), Assist { valid: 8, pending: 2, pending_special: 0, next_options: Avail(1, [\"stageCount\", \"state\"]) })");
+        verify!(self, root.probe("pipeline.state.") => "(Ok(NodeTree { info: Named(NodeTree { info: Leaf(\"warming_up\"), meta: None, size: 11 }, NodeTree { info: Grouped('{', NodeTree { info: Delimited(',', [NodeTree { info: Tuple(NodeTree { info: Leaf(\"progress\"), meta: None, size: 9 }, \":\", NodeTree { info: Leaf(\"40\"), meta: Some(Wrap(1)), size: 3 }), meta: None, size: 16 }]), meta: None, size: 19 }, '}'), meta: None, size: 22 }), meta: Some(Wrap(1)), size: 34 }), Assist { valid: 14, pending: 1, pending_special: 0, next_options: Avail(1, [\"warming_up\"]) })");
+        verify!(self, root.access("pipeline.state = do") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 14, pending: 5, pending_special: 0, next_options: Avail(3, [\"done\"]) })");
+        verify!(self, root.access("pipeline.state = done(7)") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pipeline.state").0.unwrap().info.to_string() => "\"done ( 7 )\"");
+        verify!(self, root.access("pipeline.state = Finished(7)") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 14, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("pipeline.previous.draining") => "(Err(UnexpectedToken), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.probe("pipeline.previous") => "(Ok(NodeTree { info: Leaf(\"draining\"), meta: Some(Wrap(1)), size: 9 }), Assist { valid: 17, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pipeline.previous = dr") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 17, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("pipeline.previous = idle") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pipeline = Pipeline { stageCount: 4, state: warming_up { progress: 1 }, previous: idle }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 88, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pipeline").0.unwrap().info.to_string() => "\"Pipeline { stageCount : 4, state : warming_up { progress : 1 }, previous : idle }\"");
+
+        // Validation and change hooks
+
+        verify!(self, root.access("pool.size = 4") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 13, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pool").0.unwrap().info.to_string() => "\"Pool { name : \\\"workers\\\", size : 4, slots : Vec [ 0, 0, 0, 0 ], resizes : 1 }\"");
+        verify!(self, root.access("pool.size = 9") => "(Err(AssignError(Invalid(\"Expected a value within 1..=8\"))), Assist { valid: 9, pending: 4, pending_special: 0, next_options: Avail(4, []) })");
+        verify!(self, root.probe("pool.size = 0") => "(Err(AssignError(Invalid(\"Expected a value within 1..=8\"))), Assist { valid: 9, pending: 4, pending_special: 0, next_options: Avail(4, []) })");
+        verify!(self, root.probe("pool.size = 3") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 13, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pool.size").0.unwrap().info.to_string() => "\"4\"");
+        verify!(self, root.hint("pool.size = ") => "Some(\"1..=8\")");
+        verify!(self, root.hint("pool.size = 3") => "Some(\"1..=8\")");
+        verify!(self, root.hint("worker.resize(1, ") => "Some(\"resize(capacity: usize, keep_first: bool = true) -> ()\")");
+        verify!(self, root.call_signature("pool.size = ") => "None");
+        verify!(self, root.access("pool.name = \"\"") => "(Err(AssignError(Invalid(\"The name cannot be empty\"))), Assist { valid: 9, pending: 5, pending_special: 0, next_options: Avail(5, []) })");
+        verify!(self, root.access("pool.name = \"io\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 16, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pool = Pool { name: \"cpu\", size: 12, slots: [], resizes: 0 }") => "(Err(AssignError(Deser(Custom(\"Expected a value within 1..=8\")))), Assist { valid: 4, pending: 31, pending_special: 0, next_options: Avail(31, []) })");
+        verify!(self, root.access("pool = Pool { name: \"cpu\", size: 1, slots: [], resizes: 0 }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 59, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("pool").0.unwrap().info.to_string() => "\"Pool { name : \\\"cpu\\\", size : 1, slots : Vec [ 0 ], resizes : 1 }\"");
+        verify!(self, root.access("listeners.ports[0] = 0") => "(Err(AssignError(Checked)), Assist { valid: 15, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("listeners.ports.push(0)") => "(Err(CallError(Checked)), Assist { valid: 15, pending: 5, pending_special: 0, next_options: Avail(5, []) })");
+        verify!(self, root.access("listeners.ports.clear()") => "(Err(CallError(Checked)), Assist { valid: 15, pending: 6, pending_special: 0, next_options: Avail(6, []) })");
+        verify!(self, root.access("listeners.ports.len()") => "(Ok(NodeTree { info: Leaf(\"2\"), meta: Some(Wrap(1)), size: 2 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("listeners.ports[1]") => "(Ok(NodeTree { info: Leaf(\"443\"), meta: Some(Wrap(1)), size: 4 }), Assist { valid: 18, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("listeners.ports = [80, 0]") => "(Err(AssignError(Invalid(\"Port 0 is reserved\"))), Assist { valid: 15, pending: 10, pending_special: 0, next_options: Avail(10, []) })");
+        verify!(self, root.access("listeners.ports = [8080]") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 24, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("listeners").0.unwrap().info.to_string() => "\"Listeners { ports : Vec [ 8080 ] }\"");
+
+        // Redaction
+
+        verify!(self, root.access("credentials").0.unwrap().info.to_string() => "\"Credentials { user : \\\"admin\\\", password : <redacted>, private_key : <redacted>, api_token : \\\"abc123\\\", session_token : \\\"abc123-session\\\" }\"");
+        verify!(self, root.access("credentials.password") => "(Ok(NodeTree { info: Leaf(\"<redacted>\"), meta: Some(Wrap(1)), size: 11 }), Assist { valid: 20, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.password.len()") => "(Err(UnexpectedToken), Assist { valid: 20, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.password = \"hunter2\"") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 32, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.private_key = [1, 2]") => "(Err(AssignError(Unbuildable)), Assist { valid: 23, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.access("credentials.private_key[0]") => "(Err(UnexpectedToken), Assist { valid: 23, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials = Credentials { user: \"root\", password: \"x\", private_key: [], api_token: \"y\" }") => "(Err(AssignError(Unbuildable)), Assist { valid: 11, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        interact::redact::set_patterns(&["*TOKEN*", "user"]);
+        verify!(self, root.access("credentials").0.unwrap().info.to_string() => "\"Credentials { user : <redacted>, password : <redacted>, private_key : <redacted>, api_token : <redacted>, session_token : <redacted> }\"");
+        verify!(self, root.access("credentials.api_token") => "(Ok(NodeTree { info: Leaf(\"<redacted>\"), meta: Some(Wrap(1)), size: 11 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.api_token = \"z\"") => "(Err(AssignError(Unbuildable)), Assist { valid: 21, pending: 2, pending_special: 0, next_options: Avail(2, []) })");
+        verify!(self, root.probe("credentials.api_token.") => "(Err(UnexpectedToken), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.session_token") => "(Ok(NodeTree { info: Leaf(\"<redacted>\"), meta: Some(Wrap(1)), size: 11 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.session_token.len()") => "(Err(UnexpectedToken), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        interact::redact::clear_patterns();
+        verify!(self, root.access("credentials.api_token") => "(Ok(NodeTree { info: Leaf(\"\\\"abc123\\\"\"), meta: Some(Wrap(1)), size: 9 }), Assist { valid: 21, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+        verify!(self, root.access("credentials.session_token") => "(Ok(NodeTree { info: Leaf(\"\\\"abc123-session\\\"\"), meta: Some(Wrap(1)), size: 17 }), Assist { valid: 25, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+
+        // Doc comments
+
+        verify!(self, root.doc("pipeline") => "Ok(Some(\"A pipeline of stages.\\n\\nStages are filled one after the other.\"))");
+        verify!(self, root.doc("pipeline.stageCount") => "Ok(Some(\"The number of stages.\"))");
+        verify!(self, root.doc("pipeline.state") => "Ok(Some(\"The phase of a pipeline.\\n\\nFilling up the stages.\"))");
+        verify!(self, root.doc("pipeline.state.warming_up.progress") => "Ok(Some(\"Percents of the stages that are filled.\"))");
+        verify!(self, root.doc("pipeline.previous") => "Ok(Some(\"The phase of a pipeline.\"))");
+        verify!(self, root.doc("pool.size") => "Ok(Some(\"The maximum number of jobs in flight.\"))");
+        verify!(self, root.doc("pool.name") => "Ok(None)");
+        verify!(self, root.doc("pool.siz") => "Err(UnexpectedToken)");
+        verify!(self, root.doc("worker.resize") => "Ok(Some(\"resize(capacity: usize, keep_first: bool = true) -> ()\\n\\nChange the capacity of the worker.\\n\\nJobs beyond the new capacity are dropped.\"))");
+        verify!(self, root.doc("worker.flush(") => "Ok(Some(\"flush() -> usize\\n\\nRemove all the jobs, returning how many there were.\"))");
+        verify!(self, root.doc("cache.fetch") => "Ok(Some(\"fetch(key: u32) -> u32\\n\\nThe value stored for the key, or 0.\"))");
+        verify!(self, root.doc("cache.store") => "Ok(Some(\"store(key: u32, value: u32 = 1) -> ()\"))");
+        verify!(self, root.hint("pipeline") => "Some(\"A pipeline of stages.\")");
+        verify!(self, root.hint("pool.size") => "Some(\"The maximum number of jobs in flight.\")");
+        verify!(self, root.hint("pool.size = ") => "Some(\"1..=8\")");
+        verify!(self, root.hint("pool.name") => "None");
+
+        // Types and schemas
+
+        verify!(self, root.type_info("pool.size").map(|t| t.map(|t| t.name())) => "Ok(Some(\"u32\"))");
+        verify!(self, root.type_info("pipeline.state").map(|t| t.map(|t| t.name())) => "Ok(Some(\"integ::common::Phase\"))");
+        verify!(self, root.type_info("pool.slots").map(|t| t.map(|t| t.name())) => "Ok(Some(\"alloc::vec::Vec<u32>\"))");
+        verify!(self, root.type_info("worker.flush()").map(|t| t.map(|t| t.name())) => "Ok(None)");
+        verify!(self, root.type_info("scale(2, 3)").map(|t| t.map(|t| t.name())) => "Ok(None)");
+        verify!(self, root.type_info("pool.siz").map(|t| t.map(|t| t.name())) => "Err(UnexpectedToken)");
+        verify!(self, schema(&mut root, "pipeline") => "Some(\"/// A pipeline of stages.\\n///\\n/// Stages are filled one after the other.\\nstruct Pipeline {\\n    /// The number of stages.\\n    stageCount: u32,\\n    state: Phase,\\n    previous: Phase,\\n}\")");
+        verify!(self, schema(&mut root, "pipeline.state") => "Some(\"/// The phase of a pipeline.\\nenum Phase {\\n    idle,\\n    /// Filling up the stages.\\n    warming_up {\\n        /// Percents of the stages that are filled.\\n        progress: u32,\\n    },\\n    done(u32),\\n}\")");
+        verify!(self, schema(&mut root, "pool") => "Some(\"struct Pool {\\n    name: String,\\n    /// The maximum number of jobs in flight.\\n    size: u32,\\n    slots: Vec<u32>,\\n    resizes: u32,\\n}\")");
+        verify!(self, schema(&mut root, "pool.slots") => "Some(\"Vec<u32>\\n\\nfn len() -> usize\\nfn is_empty() -> bool\\nfn first() -> T\\nfn last() -> T\\nfn push(value: T) -> ()\\nfn pop() -> Option<T>\\nfn insert(index: usize, value: T) -> ()\\nfn remove(index: usize) -> T\\nfn clear() -> ()\")");
+        verify!(self, schema(&mut root, "worker") => "Some(\"struct Worker {\\n    name: String,\\n    capacity: usize,\\n    jobs: Vec<u32>,\\n}\\n\\nfn submit(job: u32) -> bool\\nfn resize(capacity: usize, keep_first: bool = true) -> ()\\nfn load() -> (usize, usize)\\nfn flush() -> usize\")");
+        verify!(self, schema(&mut root, "queue") => "Some(\"struct Queue {\\n    items: Vec<u32>,\\n    capacity: usize,\\n}\")");
+        verify!(self, schema(&mut root, "account") => "Some(\"struct Account {\\n    owner: u64,\\n    friends: Vec<UserId>,\\n    height: u32,\\n    home: Location,\\n    scores: BTreeMap<UserId, u32>,\\n}\")");
+        verify!(self, schema(&mut root, "server_config") => "Some(\"struct ServerConfig {\\n    name: String,\\n    #[interact(flatten)]\\n    tls: Tls,\\n    port: u16,\\n}\")");
+        verify!(self, schema(&mut root, "deployment.config") => "Some(\"struct Config {\\n    name: String,\\n    retries: u32,\\n    verbose: bool,\\n}\")");
+        verify!(self, pipeline_json_schema() => "\"{\\n  \\\"$schema\\\": \\\"https://json-schema.org/draft/2020-12/schema\\\",\\n  \\\"type\\\": \\\"object\\\",\\n  \\\"properties\\\": {\\n    \\\"pipeline\\\": {\\n      \\\"$ref\\\": \\\"#/$defs/integ::common::Pipeline\\\"\\n    }\\n  },\\n  \\\"x-functions\\\": [\\n    \\\"scale(value: u32, factor: u32) -> u32\\\"\\n  ],\\n  \\\"$defs\\\": {\\n    \\\"integ::common::Phase\\\": {\\n      \\\"oneOf\\\": [\\n        {\\n          \\\"const\\\": \\\"idle\\\"\\n        },\\n        {\\n          \\\"type\\\": \\\"object\\\",\\n          \\\"properties\\\": {\\n            \\\"warming_up\\\": {\\n              \\\"type\\\": \\\"object\\\",\\n              \\\"properties\\\": {\\n                \\\"progress\\\": {\\n                  \\\"type\\\": \\\"integer\\\",\\n                  \\\"x-rust-type\\\": \\\"u32\\\",\\n                  \\\"description\\\": \\\"Percents of the stages that are filled.\\\"\\n                }\\n              },\\n              \\\"required\\\": [\\n                \\\"progress\\\"\\n              ],\\n              \\\"additionalProperties\\\": false\\n            }\\n          },\\n          \\\"required\\\": [\\n            \\\"warming_up\\\"\\n          ],\\n          \\\"additionalProperties\\\": false,\\n          \\\"description\\\": \\\"Filling up the stages.\\\"\\n        },\\n        {\\n          \\\"type\\\": \\\"object\\\",\\n          \\\"properties\\\": {\\n            \\\"done\\\": {\\n              \\\"type\\\": \\\"array\\\",\\n              \\\"prefixItems\\\": [\\n                {\\n                  \\\"type\\\": \\\"integer\\\",\\n                  \\\"x-rust-type\\\": \\\"u32\\\"\\n                }\\n              ],\\n              \\\"items\\\": false,\\n              \\\"minItems\\\": 1\\n            }\\n          },\\n          \\\"required\\\": [\\n            \\\"done\\\"\\n          ],\\n          \\\"additionalProperties\\\": false\\n        }\\n      ],\\n      \\\"description\\\": \\\"The phase of a pipeline.\\\",\\n      \\\"x-rust-type\\\": \\\"integ::common::Phase\\\"\\n    },\\n    \\\"integ::common::Pipeline\\\": {\\n      \\\"type\\\": \\\"object\\\",\\n      \\\"properties\\\": {\\n        \\\"stageCount\\\": {\\n          \\\"type\\\": \\\"integer\\\",\\n          \\\"x-rust-type\\\": \\\"u32\\\",\\n          \\\"description\\\": \\\"The number of stages.\\\"\\n        },\\n        \\\"state\\\": {\\n          \\\"$ref\\\": \\\"#/$defs/integ::common::Phase\\\"\\n        },\\n        \\\"previous\\\": {\\n          \\\"$ref\\\": \\\"#/$defs/integ::common::Phase\\\"\\n        }\\n      },\\n      \\\"required\\\": [\\n        \\\"stageCount\\\",\\n        \\\"state\\\",\\n        \\\"previous\\\"\\n      ],\\n      \\\"additionalProperties\\\": false,\\n      \\\"description\\\": \\\"A pipeline of stages.\\\\n\\\\nStages are filled one after the other.\\\",\\n      \\\"x-rust-type\\\": \\\"integ::common::Pipeline\\\"\\n    }\\n  }\\n}\"");
+
+        // Serde bridge
+
+        #[cfg(feature = "serde")]
+        {
+            verify!(self, root.access("service").0.unwrap().info.to_string() => "\"Service { config : Config { name : \\\"api\\\", mode : Range { low : 1, high : 4 }, limits : [ 10, 20 ], labels : { \\\"public\\\" : true }, marker : None }, mode : Fixed ( 3 ), port : 80 }\"");
+            verify!(self, root.access("service.mode = Range { low: 2, high: 5 }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 40, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+            verify!(self, root.access("service.mode").0.unwrap().info.to_string() => "\"Range { low : 2, high : 5 }\"");
+            verify!(self, root.access("service.config = Config { name: \"web\", mode: Off, limits: [], labels: { \"a\": false }, marker: Some('x') }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 105, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+            verify!(self, root.access("service.config").0.unwrap().info.to_string() => "\"Config { name : \\\"web\\\", mode : Off, limits : [], labels : { \\\"a\\\" : false }, marker : Some ( 'x' ) }\"");
+            verify!(self, root.access("service.mode = Range { low: 2 }") => "(Err(AssignError(Deser(Custom(\"missing field `high`\")))), Assist { valid: 12, pending: 19, pending_special: 0, next_options: Avail(19, []) })");
+            verify!(self, root.access("service.mode = Ra") => "(Err(AssignError(Deser(UnexpectedToken))), Assist { valid: 12, pending: 5, pending_special: 0, next_options: Avail(3, [\"Range\"]) })");
+            verify!(self, root.access("service.mode = Range { low: 2, ") => "(Err(AssignError(Deser(EndOfTokenList))), Assist { valid: 12, pending: 18, pending_special: 0, next_options: Avail(18, [\"high\"]) })");
+            verify!(self, root.access("service = Service { config: Config { name: \"x\", mode: Fixed(1), limits: [1], labels: {}, marker: None }, mode: Off, port: 8 }") => "(Ok(NodeTree { info: Leaf(\"\"), meta: None, size: 1 }), Assist { valid: 125, pending: 0, pending_special: 0, next_options: Avail(0, []) })");
+            verify!(self, root.access("service").0.unwrap().info.to_string() => "\"Service { config : Config { name : \\\"x\\\", mode : Fixed ( 1 ), limits : [ 1 ], labels : {}, marker : None }, mode : Off, port : 8 }\"");
+            interact::redact::set_patterns(&["name", "*mark*"]);
+            verify!(self, root.access("service").0.unwrap().info.to_string() => "\"Service { config : Config { name : <redacted>, mode : Fixed ( 1 ), limits : [ 1 ], labels : {}, marker : <redacted> }, mode : Off, port : 8 }\"");
+            interact::redact::clear_patterns();
+            verify!(self, root.access("service.config = Config { name: \"caf\u{e9}\\t\", mode: Off, limits: [], labels: {}, marker: Some('\u{e9}') }").0.is_ok() => "true");
+            verify!(self, root.access("service.config").0.unwrap().info.to_string() => "\"Config { name : \\\"café\\\\t\\\", mode : Off, limits : [], labels : {}, marker : Some ( 'é' ) }\"");
+        }
+
         // TODO: add more comparision tests
     }
 }
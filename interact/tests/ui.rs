@@ -0,0 +1,10 @@
+//! Compile-fail tests of `#[derive(Interact)]` and `#[interact::methods]`.
+//!
+//! The errors of each case under `tests/ui` are compared with the `.stderr` file beside it. Run
+//! with `TRYBUILD=overwrite` to write the `.stderr` files from the current errors.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
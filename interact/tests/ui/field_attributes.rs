@@ -0,0 +1,70 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+struct UnknownAttribute {
+    #[interact(hidden)]
+    count: u32,
+}
+
+#[derive(Interact)]
+struct InvalidMode {
+    #[interact(bytes = "octets")]
+    data: Vec<u8>,
+}
+
+#[derive(Interact)]
+struct MissingValue {
+    #[interact(rename)]
+    count: u32,
+}
+
+#[derive(Interact)]
+struct WordWithValue {
+    #[interact(skip = "true")]
+    count: u32,
+}
+
+#[derive(Interact)]
+struct InvalidPath {
+    #[interact(validate = "check name")]
+    name: String,
+}
+
+#[derive(Interact)]
+struct InvalidRange {
+    #[interact(range(1, 8))]
+    size: u32,
+}
+
+#[derive(Interact)]
+struct OpenRange {
+    #[interact(range(1..=))]
+    size: u32,
+}
+
+#[derive(Interact)]
+struct Repeated {
+    #[interact(skip, skip)]
+    count: u32,
+}
+
+#[derive(Interact)]
+struct RenamedTuple(#[interact(rename = "count")] u32);
+
+#[derive(Interact)]
+enum OnChangeInVariant {
+    Sized {
+        #[interact(on_change = "resize")]
+        size: u32,
+    },
+}
+
+#[derive(Interact)]
+struct RedactedDebug {
+    #[interact(redact, debug)]
+    token: String,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+#[interact(mut_fn(reset))]
+struct MissingParameters {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(immut_fn(get() => u32))]
+struct InvalidReturnType {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(mut_fn(resize(capacity: = 3)))]
+struct MissingParameterType {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(immut_fn(get()), immut_fn(get()))]
+struct DuplicateFunction {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(methods, mut_fn(reset()))]
+struct MethodsAndFunctions {
+    count: u32,
+}
+
+fn main() {}
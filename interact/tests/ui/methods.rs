@@ -0,0 +1,46 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+#[interact(methods)]
+struct Worker {
+    count: u32,
+}
+
+#[interact::methods]
+impl Worker {
+    pub fn get<T: Default>(&self) -> T {
+        T::default()
+    }
+}
+
+#[derive(Interact)]
+#[interact(methods)]
+struct Queue {
+    len: usize,
+}
+
+#[interact::methods]
+impl Queue {
+    #[interact(default(capacity = 8))]
+    pub fn resize(&mut self, size: usize) {
+        self.len = size;
+    }
+}
+
+#[derive(Interact)]
+#[interact(methods)]
+struct Pool {
+    size: usize,
+}
+
+#[interact::methods]
+impl Pool {
+    #[interact(hidden)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+#[interact(getter(depht))]
+struct Tree {
+    depth: u32,
+}
+
+impl Tree {
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+}
+
+#[derive(Interact)]
+#[interact(mut_fn(reset()))]
+struct Counter {
+    count: u32,
+}
+
+#[derive(Interact)]
+struct Account {
+    #[interact(validate = "check_name")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+struct NotAnIdentifier {
+    #[interact(rename = "max-size")]
+    max_size: u32,
+}
+
+#[derive(Interact)]
+#[interact(rename_all = "camelCase")]
+struct Collision {
+    max_size: u32,
+    #[interact(rename = "maxSize")]
+    limit: u32,
+}
+
+#[derive(Interact)]
+enum UnknownVariantAttribute {
+    #[interact(flatten)]
+    Empty,
+}
+
+fn main() {}
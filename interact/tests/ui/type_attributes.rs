@@ -0,0 +1,52 @@
+extern crate interact;
+
+use interact::Interact;
+
+#[derive(Interact)]
+#[interact(mut_function(reset()))]
+struct UnknownAttribute {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(rename_all = "kebab-case")]
+struct InvalidCase {
+    count: u32,
+}
+
+#[derive(Interact)]
+#[interact(getter(depth))]
+enum GetterOnEnum {
+    Leaf,
+}
+
+#[derive(Interact)]
+#[interact(transparent)]
+struct TransparentPair(u32, u32);
+
+#[derive(Interact)]
+#[interact(remote = "&str")]
+struct RemoteReference {
+    len: usize,
+}
+
+#[derive(Interact)]
+#[interact(mut_assign)]
+struct Unbuildable {
+    count: u32,
+    #[interact(skip)]
+    cache: Vec<u32>,
+}
+
+#[derive(Interact)]
+union Union {
+    a: u32,
+}
+
+#[derive(Interact)]
+#[interact]
+struct MissingItems {
+    count: u32,
+}
+
+fn main() {}
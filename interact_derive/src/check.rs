@@ -0,0 +1,550 @@
+//! Checks of the `interact` attributes of a type deriving `Interact`, reporting misuses as
+//! compile errors that point at the offending tokens, before any code is generated.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
+
+use proc_macro2::TokenStream as Tokens;
+use proc_macro2::{Delimiter, Ident, TokenTree};
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::{convert_case, get_attr_info, interact_items, is_interact_attr, renamed, CASES};
+
+/// An item of an `interact` attribute, such as `skip` or `rename = "name"`.
+pub struct Item {
+    pub key: Ident,
+    pub tokens: Tokens,
+}
+
+fn error<T: ToTokens, U: Display>(tokens: T, message: U) -> syn::Error {
+    syn::Error::new_spanned(tokens, message)
+}
+
+/// The items of the `interact` attributes in a list of attributes, in order.
+pub fn items(attrs: &[syn::Attribute]) -> syn::Result<Vec<Item>> {
+    let mut items = vec![];
+
+    for attr in attrs.iter().filter(|attr| is_interact_attr(attr)) {
+        let tts: Vec<_> = attr.tts.clone().into_iter().collect();
+        match tts.as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {}
+            _ => return Err(error(attr, "Expected `#[interact(...)]`")),
+        }
+
+        for tokens in interact_items(attr) {
+            let key = match tokens.clone().into_iter().next() {
+                Some(TokenTree::Ident(key)) => key,
+                _ => return Err(error(tokens, "Expected an attribute name")),
+            };
+            items.push(Item { key, tokens });
+        }
+    }
+
+    Ok(items)
+}
+
+pub fn find<'a>(items: &'a [Item], key: &str) -> Option<&'a Item> {
+    items.iter().find(|item| item.key == key)
+}
+
+/// Check that an item is given once at most, among those that cannot be repeated.
+fn check_unique_items(items: &[Item], repeatable: &[&str]) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+
+    for item in items {
+        let key = item.key.to_string();
+        if !repeatable.contains(&key.as_str()) && !seen.insert(key) {
+            return Err(error(
+                &item.key,
+                format!("The `{}` attribute is given twice", item.key),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that an item is a single word, such as `skip`.
+pub fn word(item: &Item) -> syn::Result<()> {
+    if item.tokens.clone().into_iter().count() != 1 {
+        return Err(error(
+            &item.tokens,
+            format!("The `{}` attribute does not take a value", item.key),
+        ));
+    }
+    Ok(())
+}
+
+/// The string literal given by an item such as `rename = "name"`.
+fn str_value(item: &Item) -> syn::Result<syn::LitStr> {
+    match syn::parse2::<syn::MetaNameValue>(item.tokens.clone()) {
+        Ok(syn::MetaNameValue {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(lit),
+        _ => Err(error(
+            &item.tokens,
+            format!("Expected `{} = \"...\"`", item.key),
+        )),
+    }
+}
+
+/// Check that the string literal given by an item is one of the accepted values.
+fn one_of(item: &Item, values: &[&str]) -> syn::Result<()> {
+    let lit = str_value(item)?;
+    if values.contains(&lit.value().as_str()) {
+        return Ok(());
+    }
+
+    let expected: Vec<_> = values.iter().map(|value| format!("`{}`", value)).collect();
+    Err(error(
+        lit,
+        format!(
+            "Invalid value for the `{}` attribute, expected one of {}",
+            item.key,
+            expected.join(", ")
+        ),
+    ))
+}
+
+/// Check that an item such as `redact` is either a single word, or has one of the accepted
+/// values.
+fn word_or_one_of(item: &Item, values: &[&str]) -> syn::Result<()> {
+    if item.tokens.clone().into_iter().count() == 1 {
+        return Ok(());
+    }
+    one_of(item, values)
+}
+
+/// Check that the string literal given by an item is a path, such as `validate = "check"`.
+fn path_value(item: &Item) -> syn::Result<()> {
+    let lit = str_value(item)?;
+    match lit.parse::<syn::Path>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(error(
+            &lit,
+            format!(
+                "Invalid path `{}` for the `{}` attribute",
+                lit.value(),
+                item.key
+            ),
+        )),
+    }
+}
+
+/// Check that an item such as `range(1..=8)` holds an expression.
+fn range_value(item: &Item) -> syn::Result<()> {
+    let tts: Vec<_> = item.tokens.clone().into_iter().collect();
+    match tts.as_slice() {
+        [_, TokenTree::Group(args)] if args.delimiter() == Delimiter::Parenthesis => {
+            match syn::parse2::<syn::Expr>(args.stream()) {
+                Ok(syn::Expr::Range(syn::ExprRange {
+                    limits: syn::RangeLimits::Closed(_),
+                    to: None,
+                    ..
+                }))
+                | Err(_) => Err(error(
+                    args.stream(),
+                    "Expected a range, as in `range(1..=8)`",
+                )),
+                Ok(_) => Ok(()),
+            }
+        }
+        _ => Err(error(
+            &item.tokens,
+            "Expected a range, as in `range(1..=8)`",
+        )),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => {
+            (first.is_ascii_alphabetic() || first == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    }
+}
+
+/// Check the `interact` attributes of a type, of its fields, and of its variants.
+pub fn check_input(input: &DeriveInput) -> syn::Result<()> {
+    let items = items(&input.attrs)?;
+    check_container(input, &items)?;
+
+    let rename_all = find(&items, "rename_all")
+        .and_then(|item| str_value(item).ok())
+        .map(|lit| lit.value());
+    let rename_all = rename_all.as_deref();
+    let remote = find(&items, "remote").is_some();
+
+    match &input.data {
+        Data::Struct(data) => {
+            for field in data.fields.iter() {
+                check_field(field, false, remote)?;
+            }
+            check_names(&input.ident, &data.fields, rename_all)?;
+        }
+        Data::Enum(data) => {
+            for variant in data.variants.iter() {
+                let items = self::items(&variant.attrs)?;
+                check_unique_items(&items, &[])?;
+                for item in &items {
+                    match item.key.to_string().as_str() {
+                        "skip" => word(item)?,
+                        "rename" => {
+                            str_value(item)?;
+                        }
+                        other => {
+                            return Err(error(
+                                &item.key,
+                                format!("Unknown attribute `{}` for a variant", other),
+                            ))
+                        }
+                    }
+                }
+
+                for field in variant.fields.iter() {
+                    check_field(field, true, remote)?;
+                }
+                check_names(&variant.ident, &variant.fields, None)?;
+            }
+
+            let mut seen = HashSet::new();
+            for variant in data.variants.iter() {
+                check_name(
+                    &input.ident,
+                    &variant.ident,
+                    &variant.attrs,
+                    rename_all,
+                    &mut seen,
+                )?;
+            }
+        }
+        Data::Union(data) => {
+            return Err(error(
+                data.union_token,
+                "Unions are not supported by `Interact`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the attributes of a type, along with the ways they are combined.
+fn check_container(input: &DeriveInput, items: &[Item]) -> syn::Result<()> {
+    let mut fnmap = BTreeMap::new();
+    let mut mut_assign = false;
+    for attr in &input.attrs {
+        get_attr_info(attr, &mut fnmap, &mut HashSet::new(), &mut mut_assign)?;
+    }
+    check_unique_items(
+        items,
+        &[
+            "mut_fn",
+            "immut_fn",
+            "async_mut_fn",
+            "async_fn",
+            "skip_bound",
+            "getter",
+        ],
+    )?;
+
+    for item in items {
+        match item.key.to_string().as_str() {
+            "remote" => {
+                let lit = str_value(item)?;
+                match lit.parse::<syn::Type>() {
+                    Ok(syn::Type::Path(_)) => {}
+                    _ => return Err(error(&lit, "Expected a path for the `remote` attribute")),
+                }
+            }
+            "transparent" => word_or_one_of(item, &["named"])?,
+            "rename_all" => one_of(item, CASES)?,
+            "getter" => match syn::parse2::<syn::MetaList>(item.tokens.clone()) {
+                Ok(list) => {
+                    for nested in list.nested.iter() {
+                        match nested {
+                            syn::NestedMeta::Meta(syn::Meta::Word(_)) => {}
+                            _ => {
+                                return Err(error(
+                                    nested,
+                                    "Expected method names in the `getter` attribute",
+                                ))
+                            }
+                        }
+                    }
+                }
+                Err(_) => {
+                    return Err(error(
+                        &item.tokens,
+                        "Expected method names, as in `getter(depth)`",
+                    ))
+                }
+            },
+            "methods" | "mut_assign" => word(item)?,
+            // The others are checked by `get_attr_info`.
+            _ => {}
+        }
+    }
+
+    let getter = find(items, "getter");
+    let methods = find(items, "methods");
+    let remote = find(items, "remote");
+
+    if let Some(methods) = methods {
+        if !fnmap.is_empty() {
+            return Err(error(
+                &methods.key,
+                "The `methods` attribute cannot be used along with `mut_fn` and `immut_fn`",
+            ));
+        }
+    }
+
+    if let (Some(getter), Data::Enum(_)) = (getter, &input.data) {
+        return Err(error(
+            &getter.key,
+            "The `getter` attribute is only supported on structs",
+        ));
+    }
+
+    if let Some(transparent) = find(items, "transparent") {
+        let key = &transparent.key;
+        if remote.is_some() {
+            return Err(error(
+                key,
+                "The `transparent` attribute cannot be used along with `remote`",
+            ));
+        }
+        match &input.data {
+            Data::Struct(data) if data.fields.iter().count() == 1 => {}
+            Data::Struct(_) => {
+                return Err(error(
+                    key,
+                    "The `transparent` attribute is only supported on structs having one field",
+                ))
+            }
+            _ => {
+                return Err(error(
+                    key,
+                    "The `transparent` attribute is only supported on structs",
+                ))
+            }
+        }
+        if !fnmap.is_empty() || getter.is_some() || methods.is_some() {
+            return Err(error(
+                key,
+                "The `transparent` attribute does not support methods and getters",
+            ));
+        }
+    }
+
+    if let Some(remote) = remote {
+        if let Data::Enum(_) | Data::Union(_) = &input.data {
+            return Err(error(
+                &remote.key,
+                "The `remote` attribute is only supported on structs",
+            ));
+        }
+        if !fnmap.is_empty() || getter.is_some() {
+            return Err(error(
+                &remote.key,
+                "The `remote` attribute does not support `mut_fn`, `immut_fn` and `getter`",
+            ));
+        }
+    }
+
+    if let (Some(item), Data::Struct(data)) = (find(items, "mut_assign"), &input.data) {
+        for field in data.fields.iter() {
+            if let Some(key) = unbuildable_by(&self::items(&field.attrs)?) {
+                return Err(error(
+                    key,
+                    format!(
+                        "The `{}` attribute makes `{}` unbuildable, so it cannot have the \
+                         `{}` attribute",
+                        key, input.ident, item.key
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The attribute that makes a field unbuildable, if any. This follows `is_buildable`.
+fn unbuildable_by(items: &[Item]) -> Option<&Ident> {
+    if let Some(skip) = find(items, "skip") {
+        return Some(&skip.key);
+    }
+    if let Some(redact) = find(items, "redact") {
+        if redact.tokens.clone().into_iter().count() == 1 {
+            return Some(&redact.key);
+        }
+    }
+    if find(items, "from_str").is_none() {
+        if let Some(leaf) = find(items, "debug").or_else(|| find(items, "display")) {
+            return Some(&leaf.key);
+        }
+    }
+    None
+}
+
+/// Check the attributes of a field, along with the ways they are combined.
+fn check_field(field: &syn::Field, in_enum: bool, remote: bool) -> syn::Result<()> {
+    let items = items(&field.attrs)?;
+    check_unique_items(&items, &[])?;
+
+    for item in &items {
+        match item.key.to_string().as_str() {
+            "skip" | "skip_if_default" | "flatten" | "serde" | "debug" | "display" | "from_str" => {
+                word(item)?
+            }
+            "rename" => {
+                str_value(item)?;
+            }
+            "validate" | "on_change" | "with" | "remote" => path_value(item)?,
+            "bytes" => one_of(item, &["list", "hexdump", "hex", "base64", "utf8"])?,
+            "format" => one_of(item, &["hex", "binary", "octal", "debug", "display"])?,
+            "redact" => word_or_one_of(item, &["write_only"])?,
+            "range" => range_value(item)?,
+            other => {
+                return Err(error(
+                    &item.key,
+                    format!("Unknown attribute `{}` for a field", other),
+                ))
+            }
+        }
+    }
+
+    let has = |key| find(&items, key);
+
+    if field.ident.is_none() {
+        for key in &["skip_if_default", "rename", "flatten", "on_change"] {
+            if let Some(item) = has(key) {
+                return Err(error(
+                    &item.key,
+                    format!("The `{}` attribute is only supported on named fields", key),
+                ));
+            }
+        }
+    }
+
+    if in_enum {
+        for key in &["flatten", "on_change"] {
+            if let Some(item) = has(key) {
+                return Err(error(
+                    &item.key,
+                    format!(
+                        "The `{}` attribute is only supported on fields of structs",
+                        key
+                    ),
+                ));
+            }
+        }
+    }
+
+    if let Some(on_change) = has("on_change") {
+        if remote {
+            return Err(error(
+                &on_change.key,
+                "The `remote` attribute does not support `on_change` on fields",
+            ));
+        }
+        if has("flatten").is_some() {
+            return Err(error(
+                &on_change.key,
+                "The `on_change` attribute cannot be used along with `flatten`",
+            ));
+        }
+    }
+
+    let conflicts: &[(&str, &[&str])] = &[
+        (
+            "redact",
+            &[
+                "flatten", "validate", "range", "serde", "remote", "debug", "display",
+            ],
+        ),
+        ("validate", &["debug", "display", "serde", "remote"]),
+        ("range", &["debug", "display", "serde", "remote"]),
+    ];
+    for (key, others) in conflicts {
+        if let Some(item) = has(key) {
+            if let Some(other) = others.iter().find(|other| has(other).is_some()) {
+                return Err(error(
+                    &item.key,
+                    format!(
+                        "The `{}` attribute cannot be used along with `{}`",
+                        key, other
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the named fields of a struct or of a variant are shown under distinct
+/// identifiers.
+fn check_names(owner: &Ident, fields: &Fields, rename_all: Option<&str>) -> syn::Result<()> {
+    let fields = match fields {
+        Fields::Named(fields) => fields,
+        Fields::Unnamed(_) | Fields::Unit => return Ok(()),
+    };
+
+    let mut seen = HashSet::new();
+    for field in fields.named.iter() {
+        if find(&items(&field.attrs)?, "skip").is_some() {
+            continue;
+        }
+        if let Some(ident) = &field.ident {
+            check_name(owner, ident, &field.attrs, rename_all, &mut seen)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that a field or variant is shown under an identifier that is not taken by another.
+fn check_name(
+    owner: &Ident,
+    ident: &Ident,
+    attrs: &[syn::Attribute],
+    rename_all: Option<&str>,
+    seen: &mut HashSet<String>,
+) -> syn::Result<()> {
+    let items = items(attrs)?;
+    let rename = find(&items, "rename");
+    let name = match (renamed(attrs), rename_all) {
+        (Some(name), _) => name,
+        (None, Some(case)) => convert_case(&format!("{}", ident), case),
+        (None, None) => format!("{}", ident),
+    };
+
+    let span: Tokens = match rename {
+        Some(item) => item.tokens.clone(),
+        None => ident.into_token_stream(),
+    };
+    if (rename.is_some() || rename_all.is_some()) && !is_identifier(&name) {
+        return Err(error(
+            span,
+            format!(
+                "The name `{}` given to `{}` is not an identifier",
+                name, ident
+            ),
+        ));
+    }
+    if !seen.insert(name.clone()) {
+        return Err(error(
+            span,
+            format!("The name `{}` is given twice in `{}`", name, owner),
+        ));
+    }
+
+    Ok(())
+}
@@ -7,13 +7,16 @@ use proc_macro2::TokenStream as Tokens;
 use proc_macro2::{Delimiter, Ident, Span, TokenTree};
 use std::collections::{BTreeMap, HashSet};
 use std::process::Command;
-use syn::Meta::{List, Word};
+use syn::Meta::{List, NameValue, Word};
 use syn::NestedMeta::Meta;
 use syn::{Data, DeriveInput, Fields, GenericParam, Generics};
 
 #[macro_use]
 extern crate quote;
 
+mod check;
+mod methods;
+
 struct DeriveInfo {
     from_interact: bool,
     opaque: bool,
@@ -29,8 +32,68 @@ struct DeriveInfo {
 /// #[interact(immut_fn(function_name(param_a, param_b)))
 /// ```
 ///
+/// Parameters may have types and default values, and the function a return type:
+///
+/// ```ignore
+/// #[interact(mut_fn(function_name(param_a: u32, param_b: bool = true) -> ()))
+/// ```
+///
+/// A description of the function, shown by `:doc`, may follow:
+///
+/// ```ignore
+/// #[interact(mut_fn(function_name(param_a: u32) -> (), doc = "What the function does."))
+/// ```
+///
+/// For `async fn` methods, taking `&self` or `&mut self` respectively:
+///
+/// ```ignore
+/// #[interact(async_fn(function_name(param_a, param_b)))
+/// #[interact(async_mut_fn(function_name(param_a, param_b)))
+/// ```
+///
+/// The methods of an `impl` block having the `#[interact::methods]` attribute can be exposed
+/// instead:
+///
+/// ```ignore
+/// #[interact(methods)]
+/// ```
+///
+/// Methods of a struct taking `&self` can be shown as computed pseudo-fields:
+///
+/// ```ignore
+/// #[interact(getter(method_name))]
+/// ```
+///
+/// The fields of a struct from another crate can be described by a mirror struct, giving
+/// access to it via `interact::Remote`:
+///
+/// ```ignore
+/// #[interact(remote = "other_crate::Config")]
+/// ```
+///
+/// A single-field struct can be accessed, assigned and deserialized as the value of its field,
+/// optionally keeping its name as a prefix of the reflected value:
+///
+/// ```ignore
+/// #[interact(transparent)]
+/// #[interact(transparent = "named")]
+/// ```
+///
+/// The fields of a struct or the variants of an enum can be shown under names of another case,
+/// being one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, or
+/// `SCREAMING_SNAKE_CASE`:
+///
+/// ```ignore
+/// #[interact(rename_all = "snake_case")]
+/// ```
+///
 /// ### Per field:
 ///
+/// A named field can be shown under another name:
+/// ```ignore
+/// #[interact(rename = "state")]
+/// ```
+///
 /// The ignore attribute allows to make some fields invisible:
 /// ```ignore
 /// #[interact(ignore))
@@ -39,6 +102,75 @@ struct DeriveInfo {
 /// The downside is that having any ignored field on a type means that it is unbuildable, and
 /// therefore cannot be passed as value to functions or to be assigned using `=` in
 /// an expression.
+///
+/// Buffers of bytes, such as `Vec<u8>` or `[u8; 32]`, can be rendered in a mode other than
+/// a list of decimal numbers, being one of `hexdump`, `hex`, `base64`, or `utf8`:
+/// ```ignore
+/// #[interact(bytes = "hexdump")]
+/// ```
+///
+/// Other fields can be rendered using one of the `std::fmt` traits, being one of `hex`, `binary`,
+/// `octal`, `debug`, or `display`, or using a function `fn(&T) -> String`:
+/// ```ignore
+/// #[interact(format = "hex")]
+/// #[interact(with = "path::to::render_fn")]
+/// ```
+///
+/// The fields of a struct held by a named field can be shown and accessed inline, as fields of
+/// the containing struct:
+/// ```ignore
+/// #[interact(flatten)]
+/// ```
+///
+/// Values assigned to a field can be checked using a function `fn(&T) -> Result<(), String>`,
+/// or against a range:
+/// ```ignore
+/// #[interact(validate = "path::to::validate_fn")]
+/// #[interact(range(1..=1024))]
+/// ```
+///
+/// A function taking `&mut` of the containing struct can be called after the field is modified:
+/// ```ignore
+/// #[interact(on_change = "Self::reopen")]
+/// ```
+///
+/// Secret fields can be shown as `<redacted>` and made unreachable, optionally still allowing
+/// new values to be assigned to them:
+/// ```ignore
+/// #[interact(redact)]
+/// #[interact(redact = "write_only")]
+/// ```
+///
+/// Named fields holding their default value can be hidden from the rendering:
+/// ```ignore
+/// #[interact(skip_if_default)]
+/// ```
+///
+/// Fields of types that don't implement `Access` can be shown as opaque values, using either
+/// `fmt::Debug` or `fmt::Display`, and optionally be assigned from a string literal via `FromStr`:
+/// ```ignore
+/// #[interact(debug)]
+/// #[interact(display, from_str)]
+/// ```
+///
+/// With the `serde` feature of `interact`, fields of types implementing `serde::Serialize` and
+/// `serde::Deserialize` can be accessed via `serde`:
+/// ```ignore
+/// #[interact(serde)]
+/// ```
+///
+/// Fields of types from other crates can be accessed using their mirror struct:
+/// ```ignore
+/// #[interact(remote = "ConfigDef")]
+/// ```
+///
+/// ### Per variant:
+///
+/// Variants can be shown under another name, or be made unreachable and unbuildable:
+/// ```ignore
+/// #[interact(rename = "done")]
+/// #[interact(skip)]
+/// ```
 #[proc_macro_derive(Interact, attributes(interact))]
 pub fn derive_interact(input: TokenStream) -> TokenStream {
     derive_interact_inner(
@@ -76,7 +208,7 @@ pub fn derive_interact_opaque(input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro]
-pub fn derive_interact_extern_opqaue(input: TokenStream) -> TokenStream {
+pub fn derive_interact_extern_opaque(input: TokenStream) -> TokenStream {
     derive_interact_inner(
         input,
         DeriveInfo {
@@ -87,6 +219,12 @@ pub fn derive_interact_extern_opqaue(input: TokenStream) -> TokenStream {
     )
 }
 
+#[proc_macro]
+#[deprecated(note = "renamed to `derive_interact_extern_opaque`")]
+pub fn derive_interact_extern_opqaue(input: TokenStream) -> TokenStream {
+    derive_interact_extern_opaque(input)
+}
+
 #[proc_macro]
 pub fn derive_interact_basic(input: TokenStream) -> TokenStream {
     derive_interact_inner(
@@ -99,11 +237,64 @@ pub fn derive_interact_basic(input: TokenStream) -> TokenStream {
     )
 }
 
+/// Expose the methods of an `impl` block to Interact, for types deriving `Interact` having the
+/// `methods` attribute:
+///
+/// ```ignore
+/// #[derive(Interact)]
+/// #[interact(methods)]
+/// struct Worker { ... }
+///
+/// #[interact::methods]
+/// impl Worker {
+///     /// Resize the queue of the worker.
+///     pub fn resize(&mut self, capacity: usize) { ... }
+///
+///     #[interact(skip)]
+///     pub fn internal(&self) { ... }
+/// }
+/// ```
+///
+/// Every method taking `&self` or `&mut self` is exposed, along with its doc comment, unless it
+/// has the `skip` attribute. Argument types need to implement `Deser`, and return types need to
+/// implement `Access`.
+#[proc_macro_attribute]
+pub fn methods(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item: syn::ItemImpl = match syn::parse(item) {
+        Ok(item) => item,
+        Err(_) => {
+            let message = "The `interact::methods` attribute is only supported on `impl` blocks";
+            return syn::Error::new(Span::call_site(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    match methods::impls_for_methods(&quote! { crate::interact }, item.clone()) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => {
+            // Keep the `impl` block and implement `Methods` without functions, so that the error
+            // is not followed by others about the missing methods and implementation.
+            let kr = quote! { crate::interact };
+            let item = methods::without_interact_attrs(item);
+            let fallback = methods::impls_for_no_methods(&kr, &item);
+            let err = err.to_compile_error();
+            quote!(#item #fallback #err).into()
+        }
+    }
+}
+
 fn derive_interact_inner(input: TokenStream, info: DeriveInfo) -> TokenStream {
-    let input: DeriveInput = syn::parse(input).unwrap();
+    let input: DeriveInput = match syn::parse(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let name = &input.ident;
 
-    let expanded = inner_derive_interact(&input, info);
+    let expanded = match inner_derive_interact(&input, info) {
+        Ok(expanded) => expanded,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     if let Some((_, value)) =
         std::env::vars().find(|(key, _)| key.as_str() == "INTERACT_DERIVE_SAVE_DIR")
@@ -115,7 +306,9 @@ fn derive_interact_inner(input: TokenStream, info: DeriveInfo) -> TokenStream {
     expanded.into()
 }
 
-fn inner_derive_interact(input: &DeriveInput, info: DeriveInfo) -> Tokens {
+fn inner_derive_interact(input: &DeriveInput, info: DeriveInfo) -> syn::Result<Tokens> {
+    check::check_input(input)?;
+
     let name = &input.ident;
     let kr = if info.from_interact {
         quote! { crate }
@@ -123,8 +316,24 @@ fn inner_derive_interact(input: &DeriveInput, info: DeriveInfo) -> Tokens {
         quote! { crate::interact }
     };
 
+    let render_impls = impls_for_render_with(&kr, input);
+    let check_impls = impls_for_checks(&kr, input);
+    let render_impls = quote! {
+        #render_impls
+        #check_impls
+    };
+    let fields_impls = impls_for_struct_fields(&kr, input, &info);
+
+    if let Some(remote) = remote_type(&input.attrs) {
+        return Ok(impls_for_remote(&kr, input, &remote, render_impls));
+    }
+
+    if let Some(named) = transparent_mode(&input.attrs) {
+        return Ok(impls_for_transparent(&kr, input, named, render_impls));
+    }
+
     let (mut_assign, deser_impls) = impls_for_deser(&kr, &input, &info);
-    let access_impls = impls_for_access(&input, mut_assign);
+    let access_impls = impls_for_access(&kr, &input, &info, mut_assign)?;
     let reflect_impls = impls_for_reflect(&input, &info);
     let uses = if info.basic {
         quote! {}
@@ -133,7 +342,10 @@ fn inner_derive_interact(input: &DeriveInput, info: DeriveInfo) -> Tokens {
     };
     let module_name = Ident::new(&format!("impls_for_{}", name), Span::call_site());
 
-    quote! {
+    Ok(quote! {
+        #render_impls
+        #fields_impls
+
         mod #module_name {
             #uses
 
@@ -143,33 +355,59 @@ fn inner_derive_interact(input: &DeriveInput, info: DeriveInfo) -> Tokens {
             #reflect_impls
             #deser_impls
         }
-    }
+    })
 }
 
 #[derive(Debug)]
 struct Function {
     mutability: Mutability,
+    is_async: bool,
+    ident: Ident,
+    args: Vec<FunctionArg>,
+    ret: Option<syn::Type>,
+    doc: String,
+}
+
+#[derive(Debug)]
+struct FunctionArg {
     name: String,
-    args: Vec<String>,
+    ty: Option<syn::Type>,
+    default: Option<String>,
 }
 
 type FuncMap = BTreeMap<String, Function>;
 
-fn fill_skip_bound_from_attr(attribute: Option<TokenTree>, skip_bound_set: &mut HashSet<String>) {
-    let tt = if let Some(tt) = attribute {
-        tt
-    } else {
-        panic!("Expected a token tree after skip_bound term");
+fn fill_skip_bound_from_attr(
+    term: &Ident,
+    attribute: Option<TokenTree>,
+    skip_bound_set: &mut HashSet<String>,
+) -> syn::Result<()> {
+    let group = match attribute {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        _ => {
+            return Err(syn::Error::new(
+                term.span(),
+                "Expected type parameters after `skip_bound`, as in `skip_bound(T)`",
+            ))
+        }
     };
 
-    if let TokenTree::Group(group) = tt {
-        let mut ts = group.stream().into_iter();
-        if let Some(TokenTree::Ident(s)) = ts.next() {
-            skip_bound_set.insert(s.to_string());
-        } else {
-            panic!("Expected term in attribute");
+    for tt in group.stream() {
+        match tt {
+            TokenTree::Ident(s) => {
+                skip_bound_set.insert(s.to_string());
+            }
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' => {}
+            tt => {
+                return Err(syn::Error::new(
+                    tt.span(),
+                    "Expected a type parameter in `skip_bound`",
+                ))
+            }
         }
     }
+
+    Ok(())
 }
 
 fn get_attr_info(
@@ -177,182 +415,229 @@ fn get_attr_info(
     map: &mut FuncMap,
     skip_bound_set: &mut HashSet<String>,
     mut_assign: &mut bool,
-) {
-    match attribute.path.segments.last() {
-        None => {
-            // Ignore this attribute
-            return;
-        }
-        Some(first_segment) => {
-            if first_segment.value().ident.to_string() != "interact" {
-                // Ignore this attribute, it does not relate to Interact
-                return;
-            }
-        }
-    }
-
-    for func in attribute.tts.clone().into_iter() {
-        let ts = match &func {
-            TokenTree::Literal { .. } | TokenTree::Punct { .. } => {
-                // Skip comments
-                continue;
-            }
-            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
-                group.stream()
-            }
-            _ => {
-                panic!("extend () in `interact` data type attribute `{:?}`", func);
-            }
-        };
-
-        let mut ts = ts.clone().into_iter();
-        let mutability_term = if let Some(TokenTree::Ident(term)) = ts.next() {
-            term.to_string()
-        } else {
-            panic!("expected mut_fn/immut_fn term in data type attribute")
+) -> syn::Result<()> {
+    for item in interact_items(attribute) {
+        let mut ts = item.clone().into_iter();
+        let mutability_term = match ts.next() {
+            Some(TokenTree::Ident(term)) => term,
+            _ => return Err(syn::Error::new_spanned(item, "Expected an attribute name")),
         };
 
-        let mutability = match mutability_term.as_str() {
-            "mut_fn" => Mutability::ModifyAccess,
-            "immut_fn" => Mutability::ReadAccess,
+        let (mutability, is_async) = match mutability_term.to_string().as_str() {
+            "mut_fn" => (Mutability::ModifyAccess, false),
+            "immut_fn" => (Mutability::ReadAccess, false),
+            "async_mut_fn" => (Mutability::ModifyAccess, true),
+            "async_fn" => (Mutability::ReadAccess, true),
             "skip_bound" => {
-                fill_skip_bound_from_attr(ts.next(), skip_bound_set);
+                fill_skip_bound_from_attr(&mutability_term, ts.next(), skip_bound_set)?;
                 continue;
             }
             "mut_assign" => {
                 *mut_assign = true;
                 continue;
             }
-            _ => panic!(
-                "Invalid term {} in `{}`",
-                mutability_term.as_str(),
-                attribute.tts.to_string()
-            ),
+            "remote" => {
+                // Handled by `remote_type`
+                continue;
+            }
+            "transparent" => {
+                // Handled by `transparent_mode`
+                continue;
+            }
+            "rename_all" => {
+                // Handled by `rename_all`
+                continue;
+            }
+            "getter" => {
+                // Handled by `getters`
+                continue;
+            }
+            "methods" => {
+                // Handled by `has_methods`
+                continue;
+            }
+            other => {
+                return Err(syn::Error::new(
+                    mutability_term.span(),
+                    format!("Unknown attribute `{}` for a type", other),
+                ))
+            }
         };
 
-        let fn_def = if let Some(fn_def) = ts.next() {
-            fn_def
-        } else {
-            panic!(
-                "Expected a token tree after mutability term in `{}`",
-                attribute.tts.to_string()
-            );
+        let fn_def = match ts.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+            _ => {
+                return Err(syn::Error::new(
+                    mutability_term.span(),
+                    format!(
+                        "Expected a function after `{}`, as in `{}(name(param: Type))`",
+                        mutability_term, mutability_term
+                    ),
+                ))
+            }
         };
+        if let Some(tt) = ts.next() {
+            return Err(syn::Error::new(
+                tt.span(),
+                format!("Unexpected token after `{}(...)`", mutability_term),
+            ));
+        }
 
-        if let TokenTree::Group(group) = fn_def {
-            if group.delimiter() == Delimiter::Parenthesis {
-                let mut ts = group.stream().into_iter();
-
-                let fn_name = if let Some(TokenTree::Ident(term)) = ts.next() {
-                    term.to_string()
-                } else {
-                    panic!("Expected term in attribute `{}`", attribute.tts.to_string());
-                };
-
-                let name = String::from(fn_name.as_str());
-                let mut func = Function {
-                    name: name.clone(),
-                    mutability,
-                    args: vec![],
-                };
+        let mut ts = fn_def.stream().into_iter();
+        let fn_name = match ts.next() {
+            Some(TokenTree::Ident(term)) => term,
+            _ => {
+                return Err(syn::Error::new(
+                    fn_def.span(),
+                    format!("Expected a function name in `{}`", mutability_term),
+                ))
+            }
+        };
 
-                let fn_def = if let Some(fn_def) = ts.next() {
-                    fn_def
-                } else {
-                    panic!(
-                        "Expected parameter specification () after {} in `{}`",
-                        fn_name.as_str(),
-                        attribute.tts.to_string()
-                    );
-                };
+        let params = match ts.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+            _ => {
+                return Err(syn::Error::new(
+                    fn_name.span(),
+                    format!(
+                        "Expected parameter specification () after `{}`, as in `{}(param: Type)`",
+                        fn_name, fn_name
+                    ),
+                ))
+            }
+        };
 
-                if let TokenTree::Group(group) = fn_def {
-                    if group.delimiter() == Delimiter::Parenthesis {
-                        for tt in group.stream().into_iter() {
-                            if let TokenTree::Ident(term) = tt {
-                                func.args.push(term.to_string());
-                            } else if let TokenTree::Punct(ref punc) = tt {
-                                if punc.as_char() == ',' {
-                                    continue;
-                                } else {
-                                    panic!("Unexpected parameter token {:?}", tt);
-                                }
-                            } else {
-                                panic!(
-                                    "Expected term in attribute `{}`",
-                                    attribute.tts.to_string()
-                                );
-                            }
-                        }
-                    }
-                }
+        let name = fn_name.to_string();
+        let mut func = Function {
+            ident: fn_name.clone(),
+            mutability,
+            is_async,
+            args: vec![],
+            ret: None,
+            doc: String::new(),
+        };
 
-                if map.get(&name).is_some() {
-                    panic!("Duplicate name {}", name.as_str());
-                }
+        let tokens: Vec<_> = params.stream().into_iter().collect();
+        for arg in split_top_level(&tokens, ',') {
+            if arg.is_empty() {
+                continue;
+            }
+            func.args.push(function_arg(arg)?);
+        }
 
-                map.insert(name, func);
+        let rest: Vec<_> = ts.collect();
+        let mut parts = split_top_level(&rest, ',').into_iter();
+        match parts.next().unwrap_or_default() {
+            [] => {}
+            [TokenTree::Punct(minus), TokenTree::Punct(gt), ret @ ..]
+                if minus.as_char() == '-' && gt.as_char() == '>' && !ret.is_empty() =>
+            {
+                let ret: Tokens = ret.iter().cloned().collect();
+                func.ret = Some(syn::parse2(ret.clone()).map_err(|_| {
+                    syn::Error::new_spanned(ret, format!("Invalid return type for `{}`", name))
+                })?);
             }
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other.iter().cloned().collect::<Tokens>(),
+                    format!(
+                        "Expected `-> ReturnType` after the parameters of `{}`",
+                        name
+                    ),
+                ))
+            }
+        }
+        for option in parts {
+            func.doc = function_doc(&name, option)?;
         }
+
+        if map.get(&name).is_some() {
+            return Err(syn::Error::new(
+                fn_name.span(),
+                format!("Duplicate name `{}`", name),
+            ));
+        }
+
+        map.insert(name, func);
     }
+
+    Ok(())
 }
 
-fn call_impls(fnmap: &FuncMap, mutability: Mutability) -> (Tokens, Vec<Tokens>) {
+fn call_impls(kr: &Tokens, fnmap: &FuncMap, mutability: Mutability) -> (Tokens, Vec<Tokens>) {
     let mut arms = vec![];
     let mut descs = vec![];
 
     for func in fnmap.values() {
-        let name = &func.name;
-        let name_ident = Ident::new(name.as_str(), Span::call_site());
-        let mut match_vec = vec![];
-        let mut arg_vec = vec![];
-        let mut arg_vec_unpack = vec![];
-        let mut arg_str_vec = vec![];
-
-        for arg in &func.args {
-            arg_str_vec.push(arg.as_str());
-            match_vec.push({
-                let v = syn::token::Underscore::default();
-                if func.args.len() == 1 {
-                    quote! { #v, }
-                } else {
-                    quote! { #v }
-                }
-            });
-            arg_vec.push(Ident::new(arg.as_str(), Span::call_site()));
-            arg_vec_unpack.push({
-                let v = Ident::new(arg.as_str(), Span::call_site());
-                if func.args.len() == 1 {
-                    quote! { #v, }
-                } else {
-                    quote! { #v }
-                }
+        // Calls use the name as given by the attribute, so that a missing method is reported there.
+        let name_ident = &func.ident;
+        let name = &format!("{}", name_ident);
+        let mut arg_descs = vec![];
+        let mut arg_decls = vec![];
+        let mut arg_deser = vec![];
+        let mut arg_values = vec![];
+
+        for (idx, arg) in func.args.iter().enumerate() {
+            let arg_name = arg.name.as_str();
+            let arg_ident = Ident::new(arg_name, Span::call_site());
+            let ty = arg.ty.as_ref().map(methods::type_name).unwrap_or_default();
+            let default = match &arg.default {
+                Some(default) => quote! { Some(#default) },
+                None => quote! { None },
+            };
+            arg_descs.push(quote! {
+                Argument { name: #arg_name, ty: #ty, default: #default }
             });
+            let (decl, deser, value) =
+                methods::arg_code(kr, idx, arg_name, &arg_ident, arg.ty.as_ref());
+            arg_decls.push(decl);
+            arg_deser.push(deser);
+            arg_values.push(value);
         }
-        let arg_vec_ref = &arg_vec;
-        let arg_vec_unpack_ref = &arg_vec_unpack;
-        let match_vec = &match_vec;
+        let arg_descs_ref = &arg_descs;
+        let arg_values = &arg_values;
+
+        let parse_args = quote! {
+            #(#arg_decls)*
+            let _objects = _climber.parse_args(&[#(#arg_descs_ref),*], &mut |_idx, _tracker| {
+                match _idx {
+                    #(#arg_deser,)*
+                    _ => {}
+                }
+                Ok(())
+            })?;
+        };
 
+        let call = if func.is_async {
+            quote! { executor::block_on(self.#name_ident(#(#arg_values),*)) }
+        } else {
+            quote! { self.#name_ident(#(#arg_values),*) }
+        };
         let mut call_impl = quote! {
-            <(#(#match_vec),*)>::deser(&mut _climber.borrow_tracker()).map(|(#(#arg_vec_unpack_ref),*)| {
-                if !_climber.is_probe_only() {
-                    let _retval = self.#name_ident(#(#arg_vec_ref),*);
+            #parse_args
+            if !_climber.is_probe_only() {
+                _objects.with(&mut |_refs| {
+                    let _retval = #call;
                     (_retcall)(&_retval, _climber);
-                }
-            }).map_err(CallError::Deser)
+                    Ok(())
+                })?;
+            }
+            Ok(())
         };
         if mutability == Mutability::ReadAccess {
             if func.mutability == Mutability::ModifyAccess {
                 call_impl = quote! {
-                    <(#(#match_vec),*)>::deser(&mut _climber.borrow_tracker()).map(|(#(#arg_vec_unpack_ref),*)| {
-                        if !_climber.is_probe_only() {
-                            // Non executing unsafe code only for coercing type checking
-                            if false {
-                                let mself : &mut Self = unsafe { std::mem::uninitialized() };
-                                let _ = mself.#name_ident(#(#arg_vec_ref),*);
-                            }
+                    #parse_args
+                    if !_climber.is_probe_only() {
+                        // Non executing unsafe code only for coercing type checking
+                        if false {
+                            let mself : &mut Self = unsafe { std::mem::uninitialized() };
+                            _objects.with(&mut |_refs| {
+                                let _ = mself.#name_ident(#(#arg_values),*);
+                                Ok(())
+                            })?;
                         }
-                    }).map_err(CallError::Deser)?;
+                    }
 
                     return Err(CallError::NeedMutable);
                 };
@@ -365,10 +650,18 @@ fn call_impls(fnmap: &FuncMap, mutability: Mutability) -> (Tokens, Vec<Tokens>)
             }
         });
 
+        let ret = func
+            .ret
+            .as_ref()
+            .map(methods::type_name)
+            .unwrap_or_default();
+        let doc = &func.doc;
         descs.push(quote! {
             Function {
                 name: #name,
-                args: &[#(#arg_str_vec),*],
+                args: &[#(#arg_descs),*],
+                ret: #ret,
+                doc: #doc,
             }
         });
     }
@@ -390,13 +683,18 @@ fn call_impls(fnmap: &FuncMap, mutability: Mutability) -> (Tokens, Vec<Tokens>)
     )
 }
 
-fn impls_for_access(input: &DeriveInput, mut mut_assign: bool) -> Tokens {
+fn impls_for_access(
+    kr: &Tokens,
+    input: &DeriveInput,
+    info: &DeriveInfo,
+    mut mut_assign: bool,
+) -> syn::Result<Tokens> {
     let name = &input.ident;
     let mut skip_bound_set = HashSet::new();
     let mut fnmap = BTreeMap::new();
 
     for attribute in &input.attrs {
-        get_attr_info(attribute, &mut fnmap, &mut skip_bound_set, &mut mut_assign);
+        get_attr_info(attribute, &mut fnmap, &mut skip_bound_set, &mut mut_assign)?;
     }
     let generics = add_trait_bounds(
         input.generics.clone(),
@@ -412,9 +710,29 @@ fn impls_for_access(input: &DeriveInput, mut mut_assign: bool) -> Tokens {
         quote! { ReflectMut::Direct(self) }
     };
 
-    let (immut_call_impls, immut_call_desc) = call_impls(&fnmap, Mutability::ReadAccess);
-    let (mut_call_impls, mut_call_desc) = call_impls(&fnmap, Mutability::ModifyAccess);
+    let (immut_call_impls, immut_call_desc) = call_impls(kr, &fnmap, Mutability::ReadAccess);
+    let (mut_call_impls, mut_call_desc) = call_impls(kr, &fnmap, Mutability::ModifyAccess);
+    let (immut_functions, mut_functions, immut_call_impls, mut_call_impls) =
+        if has_methods(&input.attrs) {
+            (
+                quote! { <Self as Methods>::functions() },
+                quote! { <Self as Methods>::functions() },
+                quote! { Methods::immut_call(self, func_name, _climber, _retcall) },
+                quote! { Methods::mut_call(self, func_name, _climber, _retcall) },
+            )
+        } else {
+            (
+                quote! { &[#(#immut_call_desc),*] },
+                quote! { &[#(#mut_call_desc),*] },
+                immut_call_impls,
+                mut_call_impls,
+            )
+        };
 
+    let on_change_paths = match &input.data {
+        Data::Struct(data) => on_change_paths(&data.fields),
+        _ => vec![],
+    };
     let mut_assign_code = if mut_assign {
         quote! {
             fn mut_assign<'a, 'b>(
@@ -422,26 +740,47 @@ fn impls_for_access(input: &DeriveInput, mut mut_assign: bool) -> Tokens {
                 _tracker: &mut deser::Tracker<'a, 'b>,
                 _probe_only: bool,
             ) -> Result<(), AssignError> {
-                deser_assign(self, _tracker, _probe_only)
+                deser_assign(self, _tracker, _probe_only)?;
+                if !_probe_only {
+                    #(#on_change_paths(self);)*
+                }
+                Ok(())
+            }
+
+            fn deser_new<'a, 'b>(_tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self> {
+                <Self as Deser>::deser(_tracker)
             }
         }
     } else {
         quote! {}
     };
 
-    quote! {
+    // Only types without generics are known to be `'static`, as `Any` requires.
+    let as_any_code = if input.generics.params.is_empty() {
+        quote! {
+            fn as_any(&self) -> Option<&dyn std::any::Any> {
+                Some(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let describe_code = impls_for_describe(input, info, &mut_functions);
+
+    Ok(quote! {
         impl #impl_generics Access for #name #ty_generics #where_clause {
             fn immut_access(&self) -> ImmutAccess {
                 ImmutAccess {
                     reflect: #code,
-                    functions: &[#(#immut_call_desc),*],
+                    functions: #immut_functions,
                 }
             }
 
             fn mut_access(&mut self) -> MutAccess {
                 MutAccess {
                     reflect: #code_mut,
-                    functions: &[#(#mut_call_desc),*],
+                    functions: #mut_functions,
                 }
             }
 
@@ -458,10 +797,104 @@ fn impls_for_access(input: &DeriveInput, mut mut_assign: bool) -> Tokens {
             }
 
             #mut_assign_code
+            #as_any_code
+            #describe_code
+        }
+    })
+}
+
+/// Split a list of tokens at the given punctuation, except within angle brackets.
+fn split_top_level(tokens: &[TokenTree], sep: char) -> Vec<&[TokenTree]> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, tt) in tokens.iter().enumerate() {
+        if let TokenTree::Punct(punct) = tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if depth > 0 => depth -= 1,
+                c if c == sep && depth == 0 => {
+                    parts.push(&tokens[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    parts.push(&tokens[start..]);
+
+    parts
+}
+
+/// Parse the description of a function given as `doc = "..."`, following its signature.
+fn function_doc(name: &str, tokens: &[TokenTree]) -> syn::Result<String> {
+    match tokens {
+        [TokenTree::Ident(ident), TokenTree::Punct(eq), TokenTree::Literal(lit)]
+            if ident == "doc" && eq.as_char() == '=' =>
+        {
+            let lit: syn::LitStr = syn::parse2(quote! { #lit })?;
+            Ok(lit.value())
         }
+        _ => Err(syn::Error::new_spanned(
+            tokens.iter().cloned().collect::<Tokens>(),
+            format!("Expected `doc = \"...\"` after the signature of `{}`", name),
+        )),
     }
 }
 
+/// Parse a function parameter given as `name`, `name: Type` or `name: Type = default`.
+fn function_arg(tokens: &[TokenTree]) -> syn::Result<FunctionArg> {
+    let name = match tokens.first() {
+        Some(TokenTree::Ident(term)) => term.to_string(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                tokens.iter().cloned().collect::<Tokens>(),
+                "Expected a parameter name",
+            ))
+        }
+    };
+
+    let (ty, default) = match tokens.get(1) {
+        None => (None, None),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+            let mut parts = split_top_level(&tokens[2..], '=').into_iter();
+            let ty: Tokens = parts.next().unwrap_or(&[]).iter().cloned().collect();
+            let default: Vec<_> = parts.collect();
+            let default = match default.as_slice() {
+                [] => None,
+                [default] if !default.is_empty() => {
+                    Some(default.iter().cloned().collect::<Tokens>().to_string())
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        punct.span(),
+                        format!("Invalid default value for parameter `{}`", name),
+                    ))
+                }
+            };
+            if ty.is_empty() {
+                return Err(syn::Error::new(
+                    punct.span(),
+                    format!("Expected a type for parameter `{}`", name),
+                ));
+            }
+            let ty = syn::parse2::<syn::Type>(ty.clone()).map_err(|_| {
+                syn::Error::new_spanned(ty, format!("Invalid type for parameter `{}`", name))
+            })?;
+            (Some(ty), default)
+        }
+        Some(tt) => {
+            return Err(syn::Error::new(
+                tt.span(),
+                format!("Expected `: Type` after parameter `{}`", name),
+            ))
+        }
+    };
+
+    Ok(FunctionArg { name, ty, default })
+}
+
 #[derive(Debug, Eq, PartialEq)]
 enum Mutability {
     ModifyAccess,
@@ -469,15 +902,23 @@ enum Mutability {
 }
 
 fn impls_by_mutability(
-    name: syn::Ident,
+    type_name: &syn::Ident,
+    name: &str,
+    this: &Tokens,
     data_fields: &Fields,
-    in_enum: bool,
+    variant: Option<&syn::Ident>,
+    rename_all: Option<&str>,
     mtype: Mutability,
-) -> (Tokens, Tokens, Tokens, Tokens) {
+) -> (Tokens, Tokens, Tokens, Tokens, Tokens) {
     let mut named_match_arms: Vec<Tokens> = vec![];
     let mut index_match_arms: Vec<Tokens> = vec![];
+    let mut hidden_match_arms: Vec<Tokens> = vec![];
     let desc: Tokens;
-    let name = format!("{}", format!("{}", name));
+    let in_enum = variant.is_some();
+    let owner = match variant {
+        Some(variant) => format!("{}_{}", type_name, variant),
+        None => format!("{}", type_name),
+    };
     let params;
 
     let qmut = match mtype {
@@ -499,10 +940,19 @@ fn impls_by_mutability(
                     let f_i = if in_enum {
                         quote! { #ident }
                     } else {
-                        quote! { & #qmut self.#ident }
+                        quote! { & #qmut #this.#ident }
                     };
 
-                    (f_i, format!("{}", ident.as_ref().unwrap()))
+                    let ident = ident.as_ref().unwrap();
+                    let fname = shown_name(ident, &f.attrs, rename_all);
+                    if is_skipped_if_default(&f.attrs) {
+                        hidden_match_arms.push(quote! {
+                            #fname => is_default(#f_i)
+                        });
+                    }
+
+                    let view = field_view(&f.attrs, &owner, &format!("{}", ident), f_i, &mtype);
+                    (view, fname)
                 })
                 .collect();
 
@@ -534,18 +984,22 @@ fn impls_by_mutability(
                 .unnamed
                 .iter()
                 .filter(|f| !is_skipped(&f.attrs))
-                .map(|_| {
+                .map(|f| {
                     let f_i = if in_enum {
                         let ident = Ident::new(&format!("f_{}", i), Span::call_site());
                         quote! { #ident }
                     } else {
                         let i = syn::Index::from(i);
-                        quote! { & #qmut self.#i }
+                        quote! { & #qmut #this.#i }
                     };
 
                     idents.push(f_i.clone());
 
-                    let r = (quote! { #i }, f_i);
+                    let fname = format!("{}", i);
+                    let r = (
+                        quote! { #i },
+                        field_view(&f.attrs, &owner, &fname, f_i, &mtype),
+                    );
                     i += 1;
                     r
                 })
@@ -590,25 +1044,460 @@ fn impls_by_mutability(
         quote! {}
     };
 
-    (named_match_arms, index_match_arms, desc, params)
+    let hidden_match_arms = if !hidden_match_arms.is_empty() {
+        quote! { #(#hidden_match_arms),*, }
+    } else {
+        quote! {}
+    };
+
+    (
+        named_match_arms,
+        index_match_arms,
+        hidden_match_arms,
+        desc,
+        params,
+    )
 }
 
-fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
-    if info.opaque {
-        return quote! {};
-    }
+/// Generate the match arms of `get_field_doc`, from the doc comments of the shown fields.
+fn field_doc_arms(fields: &Fields, rename_all: Option<&str>) -> Vec<Tokens> {
+    let mut arms = vec![];
 
-    let name = &input.ident;
-    let generics = add_trait_bounds(input.generics.clone(), &HashSet::new(), &["Access"]);
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let str_name = format!("{}", format!("{}", name));
+    for (i, field) in fields.iter().filter(|f| !is_skipped(&f.attrs)).enumerate() {
+        let doc = methods::doc_comment(&field.attrs);
+        if doc.is_empty() {
+            continue;
+        }
+        let fname = match &field.ident {
+            Some(ident) => shown_name(ident, &field.attrs, rename_all),
+            None => format!("{}", i),
+        };
+        arms.push(quote! { #fname => #doc, });
+    }
 
-    match &input.data {
-        Data::Struct(data) => {
-            let (named_match_arms, index_match_arms, desc, _) =
-                impls_by_mutability(name.clone(), &data.fields, false, Mutability::ReadAccess);
-            let (named_match_mut_arms, index_match_mut_arms, _, _) =
-                impls_by_mutability(name.clone(), &data.fields, false, Mutability::ModifyAccess);
+    arms
+}
+
+/// Generate the `get_doc` and `get_field_doc` methods of `ReflectStruct` for a struct, leaving
+/// out those that would return no doc comment.
+fn impls_for_docs(attrs: &[syn::Attribute], fields: &Fields, rename_all: Option<&str>) -> Tokens {
+    let doc = methods::doc_comment(attrs);
+    let doc_code = if doc.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn get_doc(&self) -> &'static str {
+                #doc
+            }
+        }
+    };
+
+    let arms = field_doc_arms(fields, rename_all);
+    let field_doc_code = if arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn get_field_doc(&self, name: &str) -> &'static str {
+                match name {
+                    #(#arms)*
+                    _ => "",
+                }
+            }
+        }
+    };
+
+    quote! {
+        #doc_code
+        #field_doc_code
+    }
+}
+
+/// Generate the accessors of the fields having the `flatten` attribute.
+fn impls_for_flatten(fields: &Fields, rename_all: Option<&str>) -> Tokens {
+    let fields = match fields {
+        Fields::Named(fields) => fields,
+        Fields::Unnamed(_) | Fields::Unit => return quote! {},
+    };
+
+    let flattened: Vec<_> = fields
+        .named
+        .iter()
+        .filter(|f| !is_skipped(&f.attrs) && is_flattened(&f.attrs))
+        .collect();
+    if flattened.is_empty() {
+        return quote! {};
+    }
+
+    let flattened_names: Vec<_> = flattened
+        .iter()
+        .map(|f| shown_name(f.ident.as_ref().unwrap(), &f.attrs, rename_all))
+        .collect();
+    let flattened: Vec<_> = flattened.iter().map(|f| &f.ident).collect();
+    let flattened_names = &flattened_names;
+    let flattened = &flattened;
+
+    quote! {
+        fn get_flattened(&self, name: &'static str) -> Option<&dyn ReflectStruct> {
+            match name {
+                #(#flattened_names => Some(&self.#flattened),)*
+                _ => None,
+            }
+        }
+
+        fn get_flattened_mut(&mut self, name: &'static str) -> Option<&mut dyn ReflectStruct> {
+            match name {
+                #(#flattened_names => Some(&mut self.#flattened),)*
+                _ => None,
+            }
+        }
+    }
+}
+
+/// The distinct functions given by the `on_change` attribute of the fields of a struct, all of
+/// which are called when the struct is assigned as a whole.
+fn on_change_paths(fields: &Fields) -> Vec<Tokens> {
+    let mut paths: Vec<Tokens> = vec![];
+    for field in fields.iter().filter(|f| !is_skipped(&f.attrs)) {
+        if let Some(path) = field_on_change(&field.attrs) {
+            let path = module_path(path);
+            if !paths.iter().any(|p| p.to_string() == path.to_string()) {
+                paths.push(path);
+            }
+        }
+    }
+    paths
+}
+
+/// Generate the body of `mut_climber` for a struct, calling the functions given by the
+/// `on_change` attribute of its fields after they are modified.
+fn impls_for_on_change(fields: &Fields, rename_all: Option<&str>) -> Tokens {
+    let climb = quote! { climber.check_field_access_mut(EnumOrStructMut::Struct(self)) };
+
+    let mut arms = vec![];
+    for field in fields.iter().filter(|f| !is_skipped(&f.attrs)) {
+        let path = match field_on_change(&field.attrs) {
+            Some(path) => module_path(path),
+            None => continue,
+        };
+        let ident = match &field.ident {
+            Some(ident) => ident,
+            None => continue,
+        };
+
+        let fname = shown_name(ident, &field.attrs, rename_all);
+        arms.push(quote! { Some(#fname) => #path(self), });
+    }
+
+    if arms.is_empty() {
+        return climb;
+    }
+
+    quote! {
+        let field = climber.peek_field();
+        let changes = climber.changes();
+        let res = #climb;
+        if climber.changes() != changes {
+            match field.as_deref() {
+                #(#arms)*
+                _ => {}
+            }
+        }
+        res
+    }
+}
+
+/// Generate the schemas of the shown fields of a struct or of an enum variant, describing each
+/// field by the type via which it is accessed. As the types of the fields are not necessarily
+/// in scope of the generated code, each field is reached from a value of type `this_ty`, either
+/// directly or by matching the given variant.
+fn describe_fields(
+    owner: &str,
+    this_ty: &Tokens,
+    variant: Option<&Tokens>,
+    fields: &Fields,
+    rename_all: Option<&str>,
+) -> Tokens {
+    let mut schemas = vec![];
+    let shown = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !is_skipped(&f.attrs));
+
+    for (i, (idx, field)) in shown.enumerate() {
+        let fname = match &field.ident {
+            Some(ident) => format!("{}", ident),
+            None => format!("{}", i),
+        };
+        let view = field_view(
+            &field.attrs,
+            owner,
+            &fname,
+            quote! { field },
+            &Mutability::ReadAccess,
+        );
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let idx = syn::Index::from(idx);
+                quote! { #idx }
+            }
+        };
+        let project = match variant {
+            Some(variant) => quote! {
+                match this {
+                    #variant { #member: field, .. } => #view,
+                    _ => unreachable!(),
+                }
+            },
+            None => quote! {{
+                let field = &this.#member;
+                #view
+            }},
+        };
+        let schema = quote! {
+            schema::describe_field(|this: &#this_ty| #project, schemas)
+        };
+
+        schemas.push(match &field.ident {
+            Some(ident) => {
+                let name = shown_name(ident, &field.attrs, rename_all);
+                let doc = methods::doc_comment(&field.attrs);
+                let flatten = is_flattened(&field.attrs);
+                quote! {
+                    schema::Field {
+                        name: #name,
+                        doc: #doc,
+                        flatten: #flatten,
+                        schema: #schema,
+                    }
+                }
+            }
+            None => schema,
+        });
+    }
+
+    match fields {
+        Fields::Named(_) => quote! { schema::Fields::Named(vec![#(#schemas),*]) },
+        Fields::Unnamed(_) => quote! { schema::Fields::Tuple(vec![#(#schemas),*]) },
+        Fields::Unit => quote! { schema::Fields::Unit },
+    }
+}
+
+/// Generate the `describe` method of `Access`. Types deriving `Interact` are defined in the
+/// given `Schemas`, except for those of Interact itself which are described in place.
+fn impls_for_describe(input: &DeriveInput, info: &DeriveInfo, functions: &Tokens) -> Tokens {
+    let name = &input.ident;
+    let rename_all = rename_all(&input.attrs);
+    let rename_all = rename_all.as_deref();
+
+    let kind = if info.opaque {
+        if info.basic {
+            quote! { schema::SchemaKind::Leaf }
+        } else {
+            quote! { schema::SchemaKind::Opaque }
+        }
+    } else {
+        match &input.data {
+            Data::Struct(data) => {
+                let fields = describe_fields(
+                    &format!("{}", name),
+                    &quote! { Self },
+                    None,
+                    &data.fields,
+                    rename_all,
+                );
+                quote! { schema::SchemaKind::Struct(#fields) }
+            }
+            Data::Enum(data) => {
+                let variants = data
+                    .variants
+                    .iter()
+                    .filter(|v| !is_skipped(&v.attrs))
+                    .map(|v| {
+                        let ident = &v.ident;
+                        let variant_name = shown_name(ident, &v.attrs, rename_all);
+                        let doc = methods::doc_comment(&v.attrs);
+                        let owner = format!("{}_{}", name, ident);
+                        let variant = quote! { Self::#ident };
+                        let fields = describe_fields(
+                            &owner,
+                            &quote! { Self },
+                            Some(&variant),
+                            &v.fields,
+                            None,
+                        );
+                        quote! {
+                            schema::Variant {
+                                name: #variant_name,
+                                doc: #doc,
+                                fields: #fields,
+                            }
+                        }
+                    });
+                quote! { schema::SchemaKind::Enum(vec![#(#variants),*]) }
+            }
+            Data::Union(_) => quote! { schema::SchemaKind::Opaque },
+        }
+    };
+
+    let doc = methods::doc_comment(&input.attrs);
+    let schema = quote! {
+        Schema::of::<Self>(#kind)
+            .with_doc(#doc)
+            .with_functions(#functions)
+    };
+
+    if info.opaque {
+        quote! {
+            fn describe(_schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                #schema
+            }
+        }
+    } else if info.from_interact {
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn describe(schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                #schema
+            }
+        }
+    } else {
+        quote! {
+            #[allow(unreachable_patterns)]
+            fn describe(schemas: &mut Schemas) -> Schema
+            where
+                Self: Sized,
+            {
+                schemas.define::<Self, _>(|schemas| #schema)
+            }
+        }
+    }
+}
+
+/// Generate the `StructFields` implementation of a struct having named fields. For types
+/// without generics having flattened fields, a constant assertion makes colliding field names a
+/// compile error.
+fn impls_for_struct_fields(kr: &Tokens, input: &DeriveInput, info: &DeriveInfo) -> Tokens {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !info.opaque => fields,
+            _ => return quote! {},
+        },
+        _ => return quote! {},
+    };
+
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone(), &HashSet::new(), &["Access"]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut names = vec![];
+    let mut flattened = vec![];
+    let rename_all = rename_all(&input.attrs);
+    for field in fields.named.iter().filter(|f| !is_skipped(&f.attrs)) {
+        if is_flattened(&field.attrs) {
+            let ty = &field.ty;
+            flattened.push(quote! { &<#ty as #kr::StructFields>::FIELDS });
+        } else {
+            let ident = field.ident.as_ref().unwrap();
+            names.push(shown_name(ident, &field.attrs, rename_all.as_deref()));
+        }
+    }
+
+    let check_code = if generics.params.is_empty() && !flattened.is_empty() {
+        let message = format!(
+            "Field names of `{}` collide with those of its flattened fields",
+            name
+        );
+        quote! {
+            const _: () = assert!(
+                #kr::has_unique_fields(&<#name as #kr::StructFields>::FIELDS),
+                #message
+            );
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #impl_generics #kr::StructFields for #name #ty_generics #where_clause {
+            const FIELDS: #kr::FieldSet = #kr::FieldSet {
+                names: &[#(#names),*],
+                flattened: &[#(#flattened),*],
+            };
+        }
+
+        #check_code
+    }
+}
+
+fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
+    if info.opaque {
+        return quote! {};
+    }
+
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone(), &HashSet::new(), &["Access"]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let str_name = format!("{}", format!("{}", name));
+
+    match &input.data {
+        Data::Struct(data) => {
+            let rename_all = rename_all(&input.attrs);
+            let rename_all = rename_all.as_deref();
+
+            let (named_match_arms, index_match_arms, hidden_match_arms, desc, _) =
+                impls_by_mutability(
+                    name,
+                    &str_name,
+                    &quote! { self },
+                    &data.fields,
+                    None,
+                    rename_all,
+                    Mutability::ReadAccess,
+                );
+            let (named_match_mut_arms, index_match_mut_arms, _, _, _) = impls_by_mutability(
+                name,
+                &str_name,
+                &quote! { self },
+                &data.fields,
+                None,
+                rename_all,
+                Mutability::ModifyAccess,
+            );
+
+            let flattened_code = impls_for_flatten(&data.fields, rename_all);
+            let docs_code = impls_for_docs(&input.attrs, &data.fields, rename_all);
+            let mut_climber_code = impls_for_on_change(&data.fields, rename_all);
+
+            let getters = getters(&input.attrs);
+            let getter_names: Vec<_> = getters.iter().map(|g| format!("{}", g)).collect();
+            let getter_names = &getter_names;
+            let getters_code = if getters.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    fn get_getters(&self) -> &'static [&'static str] {
+                        &[#(#getter_names),*]
+                    }
+
+                    fn with_getter(
+                        &self,
+                        name: &'static str,
+                        f: &mut dyn FnMut(&dyn Access),
+                    ) -> bool {
+                        match name {
+                            #(#getter_names => f(&self.#getters()),)*
+                            _ => return false,
+                        }
+                        true
+                    }
+                }
+            };
 
             quote! {
                 impl #impl_generics ReflectStruct for #name #ty_generics #where_clause {
@@ -643,6 +1532,17 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                             _ => None,
                         }
                     }
+
+                    fn is_field_hidden(&self, name: &'static str) -> bool {
+                        match name {
+                            #hidden_match_arms
+                            _ => false,
+                        }
+                    }
+
+                    #getters_code
+                    #flattened_code
+                    #docs_code
                 }
 
                 impl #impl_generics ReflectDirect for #name #ty_generics #where_clause {
@@ -661,7 +1561,7 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                         &mut self,
                         climber: &mut Climber<'a>,
                     ) -> Result<Option<NodeTree>, ClimbError> {
-                        climber.check_field_access_mut(EnumOrStructMut::Struct(self))
+                        #mut_climber_code
                     }
                 }
             }
@@ -671,24 +1571,70 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
             let mut field_by_idx_match_arms = vec![];
             let mut field_by_name_match_mut_arms = vec![];
             let mut field_by_idx_match_mut_arms = vec![];
+            let mut field_hidden_match_arms = vec![];
             let mut desc_match_arms = vec![];
+            let mut doc_match_arms = vec![];
+            let mut field_doc_match_arms = vec![];
             let mut opt_names = vec![];
 
+            let mut skipped = vec![];
+            let rename_all = rename_all(&input.attrs);
+            let rename_all = rename_all.as_deref();
+
             for variant in data.variants.iter() {
                 let ident = &variant.ident;
-                let variant_name = format!("{}", variant.ident);
-                opt_names.push(variant_name);
+                let variant_name = shown_name(ident, &variant.attrs, rename_all);
 
-                let (named_match_arms, index_match_arms, desc, params) = impls_by_mutability(
-                    variant.ident.clone(),
-                    &variant.fields,
-                    true,
-                    Mutability::ReadAccess,
-                );
-                let (named_match_mut_arms, index_match_mut_arms, _, _) = impls_by_mutability(
-                    variant.ident.clone(),
+                let doc = methods::doc_comment(&variant.attrs);
+                if !doc.is_empty() {
+                    doc_match_arms.push(quote! { #name::#ident {..} => #doc, });
+                }
+
+                if is_skipped(&variant.attrs) {
+                    // Shown by name only, without giving access to the values it holds.
+                    field_by_name_match_arms.push(quote! { #name::#ident {..} => None });
+                    field_by_idx_match_arms.push(quote! { #name::#ident {..} => None });
+                    field_by_name_match_mut_arms.push(quote! { #name::#ident {..} => None });
+                    field_by_idx_match_mut_arms.push(quote! { #name::#ident {..} => None });
+                    field_hidden_match_arms.push(quote! { #name::#ident {..} => false });
+                    desc_match_arms.push(quote! {
+                        #name::#ident {..} => Struct {
+                            name: #variant_name,
+                            kind: StructKind::Unit,
+                        }
+                    });
+                    skipped.push(quote! { #name::#ident {..} });
+                    continue;
+                }
+                opt_names.push(variant_name.clone());
+
+                let field_doc_arms = field_doc_arms(&variant.fields, None);
+                if !field_doc_arms.is_empty() {
+                    field_doc_match_arms.push(quote! {
+                        #name::#ident {..} => match name {
+                            #(#field_doc_arms)*
+                            _ => "",
+                        },
+                    });
+                }
+
+                let (named_match_arms, index_match_arms, hidden_match_arms, desc, params) =
+                    impls_by_mutability(
+                        name,
+                        &variant_name,
+                        &quote! { self },
+                        &variant.fields,
+                        Some(ident),
+                        None,
+                        Mutability::ReadAccess,
+                    );
+                let (named_match_mut_arms, index_match_mut_arms, _, _, _) = impls_by_mutability(
+                    name,
+                    &variant_name,
+                    &quote! { self },
                     &variant.fields,
-                    true,
+                    Some(ident),
+                    None,
                     Mutability::ModifyAccess,
                 );
 
@@ -728,6 +1674,15 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                     }
                 });
 
+                field_hidden_match_arms.push(quote! {
+                    #name::#ident #params => {
+                        match name {
+                            #hidden_match_arms
+                            _ => false,
+                        }
+                    }
+                });
+
                 desc_match_arms.push(quote! {
                     #name::#ident #params => {
                         #desc
@@ -759,12 +1714,66 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                 quote! { "_ => None"}
             };
 
+            let field_hidden_match_arms = if !field_hidden_match_arms.is_empty() {
+                quote! { #(#field_hidden_match_arms),*, }
+            } else {
+                quote! { "_ => false"}
+            };
+
             let desc_match_arms = if desc_match_arms.len() != 0 {
                 quote! { #(#desc_match_arms),*, }
             } else {
                 quote! { "_ => panic!()"}
             };
 
+            let doc_code = if doc_match_arms.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    fn get_doc(&self) -> &'static str {
+                        match self {
+                            #(#doc_match_arms)*
+                            _ => "",
+                        }
+                    }
+                }
+            };
+
+            let field_doc_code = if field_doc_match_arms.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    fn get_field_doc(&self, name: &str) -> &'static str {
+                        match self {
+                            #(#field_doc_match_arms)*
+                            _ => "",
+                        }
+                    }
+                }
+            };
+
+            let enum_doc = methods::doc_comment(&input.attrs);
+            let enum_doc_code = if enum_doc.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    fn get_enum_doc(&self) -> &'static str {
+                        #enum_doc
+                    }
+                }
+            };
+
+            // Skipped variants cannot be climbed into, nor completed as a field access.
+            let skipped_check = if !skipped.is_empty() {
+                quote! {
+                    if matches!(self, #(#skipped)|*) {
+                        return Ok(None);
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
             quote! {
                 impl #impl_generics ReflectStruct for #name #ty_generics #where_clause {
                     fn get_desc(&self) -> Struct {
@@ -796,6 +1805,16 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                             #field_by_idx_match_mut_arms
                         }
                     }
+
+                    #[allow(unused_variables)]
+                    fn is_field_hidden(&self, name: &'static str) -> bool {
+                        match self {
+                            #field_hidden_match_arms
+                        }
+                    }
+
+                    #doc_code
+                    #field_doc_code
                 }
 
                 impl #impl_generics ReflectEnum for #name #ty_generics #where_clause {
@@ -813,6 +1832,8 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                     fn get_variant_struct_mut(&mut self) -> &mut dyn ReflectStruct {
                         self
                     }
+
+                    #enum_doc_code
                 }
 
                 impl #impl_generics ReflectDirect for #name #ty_generics #where_clause {
@@ -828,6 +1849,7 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                         &self,
                         climber: &mut Climber<'a>,
                     ) -> Result<Option<NodeTree>, ClimbError> {
+                        #skipped_check
                         climber.check_field_access_immut(&EnumOrStruct::Enum(self))
                     }
 
@@ -835,19 +1857,24 @@ fn impls_for_reflect(input: &DeriveInput, info: &DeriveInfo) -> Tokens {
                         &mut self,
                         climber: &mut Climber<'a>,
                     ) -> Result<Option<NodeTree>, ClimbError> {
+                        #skipped_check
                         climber.check_field_access_mut(EnumOrStructMut::Enum(self))
                     }
                 }
             }
         }
-        _ => {
-            panic!();
-        }
+        Data::Union(_) => unreachable!("checked by `check::check_input`"),
     }
 }
 
-fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (Tokens, bool) {
-    let name_str = format!("{}", format!("{}", name));
+fn impl_struct_for_deser(
+    name_str: &str,
+    name: Tokens,
+    owner: &str,
+    data_fields: &Fields,
+    in_enum: bool,
+    rename_all: Option<&str>,
+) -> (Tokens, bool) {
     let parse_name = if in_enum {
         quote! {}
     } else {
@@ -866,7 +1893,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
             let mut names = vec![];
 
             for field in fields.named.iter() {
-                if is_skipped(&field.attrs) {
+                if !is_buildable(&field.attrs) {
                     return (
                         quote! {
                             return Err(deser::DeserError::Unbuildable);
@@ -875,11 +1902,13 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
                     );
                 }
                 let ident = &field.ident;
+                let field_deser =
+                    checked_deser(field, owner, &format!("{}", ident.as_ref().unwrap()));
                 let ident_name = Ident::new(
                     &format!("_assign_{}", ident.as_ref().unwrap()),
                     Span::call_site(),
                 );
-                let ident_str = format!("{}", ident.as_ref().unwrap());
+                let ident_str = shown_name(ident.as_ref().unwrap(), &field.attrs, rename_all);
 
                 lets.push(quote! {
                     let mut #ident_name = None;
@@ -892,7 +1921,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
                         }
                         tracker.step();
                         tracker.try_token(&_colon)?;
-                        #ident_name = Some(Deser::deser(tracker)?);
+                        #ident_name = Some(#field_deser);
                     }
                 });
                 names.push(quote! { (#ident_str, #ident_name.is_some()) });
@@ -984,7 +2013,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
             let mut assigns = vec![];
 
             for (idx, field) in fields.unnamed.iter().enumerate() {
-                if is_skipped(&field.attrs) {
+                if !is_buildable(&field.attrs) {
                     return (
                         quote! {
                             return Err(deser::DeserError::Unbuildable);
@@ -992,6 +2021,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
                         false,
                     );
                 }
+                let field_deser = checked_deser(field, owner, &format!("{}", idx));
 
                 let comma = if idx > 0 {
                     quote! { tracker.try_token(&_comma)?; }
@@ -999,10 +2029,11 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
                     quote! {}
                 };
 
+                let index = syn::Index::from(idx);
                 assigns.push(quote! {
-                    {
+                    #index: {
                         #comma;
-                        Deser::deser(tracker)?
+                        #field_deser
                     }
                 });
             }
@@ -1023,7 +2054,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
                     #parse_name
 
                     tracker.try_token(&_open)?;
-                    let rval = Ok(#name( #assigns ));
+                    let rval = Ok(#name { #assigns });
                     tracker.try_token(&_close)?;
 
                     rval
@@ -1034,7 +2065,7 @@ fn impl_struct_for_deser(name: Tokens, data_fields: &Fields, in_enum: bool) -> (
         Fields::Unit => (
             quote! {
                 #parse_name
-                Ok(#name)
+                Ok(#name {})
             },
             true,
         ),
@@ -1051,21 +2082,36 @@ fn impls_for_deser(kr: &Tokens, input: &DeriveInput, info: &DeriveInfo) -> (bool
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let (res, active) = match &input.data {
-        Data::Struct(data) => impl_struct_for_deser(quote! {#name}, &data.fields, false),
+        Data::Struct(data) => impl_struct_for_deser(
+            &format!("{}", name),
+            quote! {#name},
+            &format!("{}", name),
+            &data.fields,
+            false,
+            rename_all(&input.attrs).as_deref(),
+        ),
         Data::Enum(data) => {
             let mut names = vec![];
             let mut match_arms = vec![];
+            let rename_all = rename_all(&input.attrs);
 
-            for variant in data.variants.iter() {
+            for variant in data.variants.iter().filter(|v| !is_skipped(&v.attrs)) {
                 let ident = &variant.ident;
-                let variant_name = format!("{}", variant.ident);
+                let variant_name = shown_name(ident, &variant.attrs, rename_all.as_deref());
                 let variant_access = if info.from_interact {
                     quote! {#ident}
                 } else {
                     quote! {#name::#ident}
                 };
 
-                let (code, _) = impl_struct_for_deser(variant_access, &variant.fields, true);
+                let (code, _) = impl_struct_for_deser(
+                    &variant_name,
+                    variant_access,
+                    &format!("{}_{}", name, ident),
+                    &variant.fields,
+                    true,
+                    None,
+                );
                 match_arms.push(quote! {
                     #variant_name => {
                         return {
@@ -1111,9 +2157,7 @@ fn impls_for_deser(kr: &Tokens, input: &DeriveInput, info: &DeriveInfo) -> (bool
                 true,
             )
         }
-        _ => {
-            panic!();
-        }
+        Data::Union(_) => unreachable!("checked by `check::check_input`"),
     };
 
     (
@@ -1129,21 +2173,1107 @@ fn impls_for_deser(kr: &Tokens, input: &DeriveInput, info: &DeriveInfo) -> (bool
     )
 }
 
-fn get_interact_meta_items(attr: &syn::Attribute) -> Option<Vec<syn::NestedMeta>> {
-    if attr.path.segments.len() == 1 && attr.path.segments[0].ident == "interact" {
-        match attr.interpret_meta() {
-            Some(List(ref meta)) => Some(meta.nested.iter().cloned().collect()),
-            _ => {
-                // TODO: produce an error
-                None
+/// Whether the methods of the type are given by `#[interact::methods]` on an `impl` block.
+fn has_methods(attrs: &[syn::Attribute]) -> bool {
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "methods" => return true,
+                _ => continue,
             }
         }
-    } else {
-        None
+    }
+    false
+}
+
+/// The methods given by the `getter` attributes of a type, shown as computed pseudo-fields.
+fn getters(attrs: &[syn::Attribute]) -> Vec<Ident> {
+    let mut getters = vec![];
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(List(list)) if list.ident == "getter" => {
+                    for nested in list.nested.iter() {
+                        if let Meta(Word(word)) = nested {
+                            getters.push(word.clone());
+                        }
+                    }
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    getters
+}
+
+/// The type given by the `remote` attribute of a mirror type.
+fn remote_type(attrs: &[syn::Attribute]) -> Option<syn::Type> {
+    let mut remote = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "remote" => {
+                    remote = lit_str(&nv.lit).and_then(|lit| lit.parse().ok());
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    remote
+}
+
+/// Whether the type has the `transparent` attribute, and if so, whether it keeps its name as a
+/// prefix of the reflected value, as given by `transparent = "named"`, the only mode accepted by
+/// `check::check_input`.
+fn transparent_mode(attrs: &[syn::Attribute]) -> Option<bool> {
+    let mut mode = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "transparent" => mode = Some(false),
+                Meta(NameValue(nv)) if nv.ident == "transparent" => mode = Some(true),
+                _ => continue,
+            }
+        }
+    }
+
+    mode
+}
+
+/// Generate the implementations for a single-field struct having the `transparent` attribute,
+/// which is accessed, assigned and deserialized as the value of its field.
+fn impls_for_transparent(
+    kr: &Tokens,
+    input: &DeriveInput,
+    named: bool,
+    render_impls: Tokens,
+) -> Tokens {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => unreachable!("checked by `check::check_input`"),
+    };
+
+    let (field, construct) = match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            let ident = fields.named[0].ident.clone().unwrap();
+            (
+                quote! { #ident },
+                quote! { #name { #ident: Deser::deser(tracker)? } },
+            )
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            (quote! { 0 }, quote! { #name(Deser::deser(tracker)?) })
+        }
+        _ => unreachable!("checked by `check::check_input`"),
+    };
+
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        &HashSet::new(),
+        &["Access", "Deser"],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let module_name = Ident::new(&format!("impls_for_{}", name), Span::call_site());
+    let name_str = format!("{}", name);
+
+    let as_any_code = if input.generics.params.is_empty() {
+        quote! {
+            fn as_any(&self) -> Option<&dyn std::any::Any> {
+                Some(self)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let (access_code, reflect_code) = if named {
+        (
+            quote! {
+                fn immut_access(&self) -> ImmutAccess<'_> {
+                    ImmutAccess {
+                        reflect: Reflect::Direct(self),
+                        functions: self.#field.immut_access().functions,
+                    }
+                }
+
+                fn mut_access(&mut self) -> MutAccess<'_> {
+                    let functions = self.#field.mut_access().functions;
+                    MutAccess {
+                        reflect: ReflectMut::Direct(self),
+                        functions,
+                    }
+                }
+            },
+            quote! {
+                impl #impl_generics ReflectDirect for #name #ty_generics #where_clause {
+                    fn immut_reflector(&self, reflector: &std::sync::Arc<Reflector>) -> NodeTree {
+                        NodeInfo::named(#name_str, Reflector::reflect(reflector, &self.#field))
+                            .into_node()
+                    }
+
+                    fn immut_climber<'a>(
+                        &self,
+                        climber: &mut Climber<'a>,
+                    ) -> Result<Option<NodeTree>, ClimbError> {
+                        climber.newtype_immut(&self.#field)
+                    }
+
+                    fn mut_climber<'a>(
+                        &mut self,
+                        climber: &mut Climber<'a>,
+                    ) -> Result<Option<NodeTree>, ClimbError> {
+                        climber.newtype_mut(&mut self.#field)
+                    }
+                }
+            },
+        )
+    } else {
+        (
+            quote! {
+                fn immut_access(&self) -> ImmutAccess<'_> {
+                    self.#field.immut_access()
+                }
+
+                fn mut_access(&mut self) -> MutAccess<'_> {
+                    self.#field.mut_access()
+                }
+            },
+            quote! {},
+        )
+    };
+
+    quote! {
+        #render_impls
+
+        mod #module_name {
+            use super::#name;
+
+            use #kr::*;
+
+            impl #impl_generics Access for #name #ty_generics #where_clause {
+                #access_code
+
+                fn immut_call<'a>(
+                    &self,
+                    func_name: &'static str,
+                    climber: &mut Climber<'a>,
+                    retcall: RetValCallback<'a>,
+                ) -> Result<(), CallError> {
+                    self.#field.immut_call(func_name, climber, retcall)
+                }
+
+                fn mut_call<'a>(
+                    &mut self,
+                    func_name: &'static str,
+                    climber: &mut Climber<'a>,
+                    retcall: RetValCallback<'a>,
+                ) -> Result<(), CallError> {
+                    self.#field.mut_call(func_name, climber, retcall)
+                }
+
+                fn mut_assign<'a, 'b>(
+                    &mut self,
+                    tracker: &mut deser::Tracker<'a, 'b>,
+                    probe_only: bool,
+                ) -> Result<(), AssignError> {
+                    self.#field.mut_assign(tracker, probe_only)
+                }
+
+                fn deser_new<'a, 'b>(tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self> {
+                    <Self as Deser>::deser(tracker)
+                }
+
+                fn describe(schemas: &mut Schemas) -> Schema
+                where
+                    Self: Sized,
+                {
+                    schema::describe_field(|this: &Self| &this.#field, schemas)
+                }
+
+                #as_any_code
+            }
+
+            #reflect_code
+
+            impl #impl_generics Deser for #name #ty_generics #where_clause {
+                fn deser<'a, 'b>(tracker: &mut deser::Tracker<'a, 'b>) -> deser::Result<Self> {
+                    Ok(#construct)
+                }
+            }
+        }
+    }
+}
+
+/// Generate the `Mirror` implementation of a mirror type having the `remote` attribute. The
+/// remote type is aliased beside the mirror type, so that its path resolves as it does for the
+/// mirror type.
+fn impls_for_remote(
+    kr: &Tokens,
+    input: &DeriveInput,
+    remote: &syn::Type,
+    render_impls: Tokens,
+) -> Tokens {
+    let name = &input.ident;
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => unreachable!("checked by `check::check_input`"),
+    };
+
+    let remote_name = match remote {
+        syn::Type::Path(type_path) => match type_path.path.segments.last() {
+            Some(segment) => segment.value().ident.clone(),
+            None => name.clone(),
+        },
+        _ => unreachable!("checked by `check::check_input`"),
+    };
+
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        &HashSet::new(),
+        &["Access", "Deser"],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let alias = Ident::new(&format!("RemoteFor_{}", name), Span::call_site());
+    let module_name = Ident::new(&format!("impls_for_{}", name), Span::call_site());
+    let this = quote! { this };
+
+    let remote_name = format!("{}", remote_name);
+    let rename_all = rename_all(&input.attrs);
+    let rename_all = rename_all.as_deref();
+
+    let (named_match_arms, index_match_arms, hidden_match_arms, desc, _) = impls_by_mutability(
+        name,
+        &remote_name,
+        &this,
+        &data.fields,
+        None,
+        rename_all,
+        Mutability::ReadAccess,
+    );
+    let (named_match_mut_arms, index_match_mut_arms, _, _, _) = impls_by_mutability(
+        name,
+        &remote_name,
+        &this,
+        &data.fields,
+        None,
+        rename_all,
+        Mutability::ModifyAccess,
+    );
+    let doc = methods::doc_comment(&input.attrs);
+    let field_doc_arms = field_doc_arms(&data.fields, rename_all);
+    let described_fields = describe_fields(
+        &format!("{}", name),
+        &quote! { super::#alias #ty_generics },
+        None,
+        &data.fields,
+        rename_all,
+    );
+    let (deser, _) = impl_struct_for_deser(
+        &remote_name,
+        quote! { super::#alias },
+        &format!("{}", name),
+        &data.fields,
+        false,
+        rename_all,
+    );
+
+    quote! {
+        #[allow(non_camel_case_types)]
+        #[doc(hidden)]
+        type #alias #ty_generics = #remote;
+
+        #render_impls
+
+        mod #module_name {
+            use super::#name;
+
+            use #kr::*;
+
+            #[allow(unused_variables)]
+            impl #impl_generics Mirror<super::#alias #ty_generics> for #name #ty_generics #where_clause {
+                fn get_desc(this: &super::#alias #ty_generics) -> Struct {
+                    #desc
+                }
+
+                fn get_field_by_name<'a>(
+                    this: &'a super::#alias #ty_generics,
+                    name: &'static str,
+                ) -> Option<&'a dyn Access> {
+                    match name {
+                        #named_match_arms
+                        _ => None,
+                    }
+                }
+
+                fn get_field_by_idx(
+                    this: &super::#alias #ty_generics,
+                    idx: usize,
+                ) -> Option<&dyn Access> {
+                    match idx {
+                        #index_match_arms
+                        _ => None,
+                    }
+                }
+
+                fn get_field_by_name_mut<'a>(
+                    this: &'a mut super::#alias #ty_generics,
+                    name: &'static str,
+                ) -> Option<&'a mut dyn Access> {
+                    match name {
+                        #named_match_mut_arms
+                        _ => None,
+                    }
+                }
+
+                fn get_field_by_idx_mut(
+                    this: &mut super::#alias #ty_generics,
+                    idx: usize,
+                ) -> Option<&mut dyn Access> {
+                    match idx {
+                        #index_match_mut_arms
+                        _ => None,
+                    }
+                }
+
+                fn is_field_hidden(this: &super::#alias #ty_generics, name: &'static str) -> bool {
+                    match name {
+                        #hidden_match_arms
+                        _ => false,
+                    }
+                }
+
+                fn get_doc() -> &'static str {
+                    #doc
+                }
+
+                fn get_field_doc(name: &str) -> &'static str {
+                    match name {
+                        #(#field_doc_arms)*
+                        _ => "",
+                    }
+                }
+
+                fn describe(schemas: &mut Schemas) -> Schema {
+                    schemas.define::<super::#alias #ty_generics, _>(|schemas| {
+                        Schema::of::<super::#alias #ty_generics>(
+                            schema::SchemaKind::Struct(#described_fields),
+                        )
+                        .with_doc(#doc)
+                    })
+                }
+
+                fn deser<'a, 'b>(
+                    tracker: &mut deser::Tracker<'a, 'b>,
+                ) -> deser::Result<super::#alias #ty_generics> {
+                    use #kr::deser::*;
+                    #deser
+                }
+            }
+        }
+    }
+}
+
+fn is_interact_attr(attr: &syn::Attribute) -> bool {
+    attr.path.segments.len() == 1 && attr.path.segments[0].ident == "interact"
+}
+
+/// The items of an `interact` attribute, such as `skip` and `rename = "name"` in
+/// `#[interact(skip, rename = "name")]`. Attributes of other forms have no items, and are
+/// reported by `check::check_input`.
+fn interact_items(attr: &syn::Attribute) -> Vec<Tokens> {
+    if !is_interact_attr(attr) {
+        return vec![];
+    }
+
+    let tts: Vec<_> = attr.tts.clone().into_iter().collect();
+    let group = match tts.as_slice() {
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => group,
+        _ => return vec![],
+    };
+
+    let tokens: Vec<_> = group.stream().into_iter().collect();
+    split_top_level(&tokens, ',')
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .map(|item| item.iter().cloned().collect())
+        .collect()
+}
+
+/// The items of an `interact` attribute that are meta items. Others, such as `range(1..=8)` and
+/// the functions given by `mut_fn`, are parsed separately.
+fn get_interact_meta_items(attr: &syn::Attribute) -> Option<Vec<syn::NestedMeta>> {
+    if !is_interact_attr(attr) {
+        return None;
+    }
+
+    let items = interact_items(attr).into_iter();
+    Some(items.filter_map(|item| syn::parse2(item).ok()).collect())
+}
+
+/// Wrap the reference to a field in a `View`, if the field has a rendering attribute.
+fn field_view(
+    attrs: &[syn::Attribute],
+    owner: &str,
+    fname: &str,
+    field: Tokens,
+    mtype: &Mutability,
+) -> Tokens {
+    let mut render = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "bytes" => {
+                    let mode = lit_str(&nv.lit).map(syn::LitStr::value);
+                    let marker = match mode.as_deref() {
+                        Some("hexdump") => "HexDump",
+                        Some("hex") => "Hex",
+                        Some("base64") => "Base64",
+                        Some("utf8") => "Utf8Lossy",
+                        _ => continue,
+                    };
+                    let marker = Ident::new(marker, Span::call_site());
+                    render = Some(quote! { access::bytes::#marker });
+                }
+                Meta(NameValue(nv)) if nv.ident == "format" => {
+                    let format = lit_str(&nv.lit).map(syn::LitStr::value);
+                    let marker = match format.as_deref() {
+                        Some("hex") => "Hex",
+                        Some("binary") => "Binary",
+                        Some("octal") => "Octal",
+                        Some("debug") => "Debug",
+                        Some("display") => "Display",
+                        _ => continue,
+                    };
+                    let marker = Ident::new(marker, Span::call_site());
+                    render = Some(quote! { access::format::#marker });
+                }
+                Meta(NameValue(nv)) if nv.ident == "with" => {
+                    let marker = with_marker(owner, fname);
+                    render = Some(quote! { super::#marker });
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    if let Some(write_only) = field_redact(attrs) {
+        let parse = if write_only {
+            quote! { access::leaf::ViaDeser }
+        } else {
+            quote! { access::leaf::NoParse }
+        };
+        return match mtype {
+            Mutability::ReadAccess => {
+                quote! { Leaf::<_, access::leaf::Redacted, #parse>::wrap(#field) }
+            }
+            Mutability::ModifyAccess => {
+                quote! { Leaf::<_, access::leaf::Redacted, #parse>::wrap_mut(#field) }
+            }
+        };
+    }
+
+    if let Some(mirror) = field_mirror(attrs) {
+        return match mtype {
+            Mutability::ReadAccess => quote! { Remote::<_, #mirror>::wrap(#field) },
+            Mutability::ModifyAccess => quote! { Remote::<_, #mirror>::wrap_mut(#field) },
+        };
+    }
+
+    if is_serde(attrs) {
+        return match mtype {
+            Mutability::ReadAccess => quote! { Serde::wrap(#field) },
+            Mutability::ModifyAccess => quote! { Serde::wrap_mut(#field) },
+        };
+    }
+
+    if let Some((leaf_render, from_str)) = field_leaf(attrs) {
+        let render = leaf_render
+            .or(render)
+            .unwrap_or(quote! { access::format::Display });
+        let parse = if from_str {
+            quote! { access::leaf::FromStr }
+        } else {
+            quote! { access::leaf::NoParse }
+        };
+
+        return match mtype {
+            Mutability::ReadAccess => quote! { Leaf::<_, #render, #parse>::wrap(#field) },
+            Mutability::ModifyAccess => quote! { Leaf::<_, #render, #parse>::wrap_mut(#field) },
+        };
+    }
+
+    if let Mutability::ModifyAccess = mtype {
+        if is_checked(attrs) {
+            let marker = check_marker(owner, fname);
+            return quote! { Checked::<_, super::#marker>::wrap_mut(#field) };
+        }
+    }
+
+    match (render, mtype) {
+        (None, _) => field,
+        (Some(render), Mutability::ReadAccess) => quote! { View::<_, #render>::wrap(#field) },
+        (Some(render), Mutability::ModifyAccess) => {
+            quote! { View::<_, #render>::wrap_mut(#field) }
+        }
+    }
+}
+
+/// For fields whose type does not implement `Access`, return the `Render` marker given by the
+/// `debug` or `display` attributes, and whether the field is assignable via `from_str`.
+fn field_leaf(attrs: &[syn::Attribute]) -> Option<(Option<Tokens>, bool)> {
+    let mut leaf = false;
+    let mut render = None;
+    let mut from_str = false;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "debug" => {
+                    render = Some(quote! { access::format::Debug });
+                }
+                Meta(Word(word)) if word == "display" => {
+                    render = Some(quote! { access::format::Display });
+                }
+                Meta(Word(word)) if word == "from_str" => from_str = true,
+                _ => continue,
+            }
+            leaf = true;
+        }
+    }
+
+    if leaf {
+        Some((render, from_str))
+    } else {
+        None
+    }
+}
+
+/// The string literal given as the value of an attribute, such as `rename = "name"`. Other
+/// literals are reported by `check::check_input`.
+fn lit_str(lit: &syn::Lit) -> Option<&syn::LitStr> {
+    match lit {
+        syn::Lit::Str(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// The path given as the value of an attribute, such as `validate = "check_name"`. Its tokens
+/// take the span of the literal, so that errors about the path point at the attribute.
+fn lit_path(lit: &syn::Lit) -> Option<syn::Path> {
+    lit_str(lit).and_then(|lit| lit.parse().ok())
+}
+
+/// The name of the `Render` marker generated for a field having the `with` attribute.
+fn with_marker(owner: &str, fname: &str) -> Ident {
+    Ident::new(
+        &format!("RenderWith_{}_{}", owner, fname),
+        Span::call_site(),
+    )
+}
+
+fn with_render_fn(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    let mut path = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "with" => path = lit_path(&nv.lit),
+                _ => continue,
+            }
+        }
+    }
+
+    path
+}
+
+fn collect_idents(tokens: Tokens, idents: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                idents.insert(format!("{}", ident));
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// Generate the `Render` markers for fields that have the `with` attribute. The markers are
+/// placed beside the type, so that both the type of the field and the path to the function
+/// resolve as they do for the type. Each marker only carries the generic parameters that appear
+/// in the type of its field.
+fn impls_for_render_with(kr: &Tokens, input: &DeriveInput) -> Tokens {
+    let name = &input.ident;
+    let mut all_fields = vec![];
+
+    match &input.data {
+        Data::Struct(data) => all_fields.push((format!("{}", name), &data.fields)),
+        Data::Enum(data) => {
+            for variant in data.variants.iter().filter(|v| !is_skipped(&v.attrs)) {
+                all_fields.push((format!("{}_{}", name, variant.ident), &variant.fields));
+            }
+        }
+        _ => {}
+    }
+
+    let mut impls = vec![];
+
+    for (owner, fields) in all_fields {
+        let fields: Vec<_> = fields.iter().filter(|f| !is_skipped(&f.attrs)).collect();
+
+        for (i, field) in fields.into_iter().enumerate() {
+            let path = match with_render_fn(&field.attrs) {
+                Some(path) => path,
+                None => continue,
+            };
+
+            let fname = match &field.ident {
+                Some(ident) => format!("{}", ident),
+                None => format!("{}", i),
+            };
+            let marker = with_marker(&owner, &fname);
+            let ty = &field.ty;
+            let generics = marker_generics(input, ty);
+            let (impl_generics, _, _) = generics.split_for_impl();
+
+            impls.push(quote! {
+                #[allow(non_camel_case_types)]
+                #[doc(hidden)]
+                struct #marker;
+
+                impl #impl_generics #kr::Render<#ty> for #marker {
+                    fn render(value: &#ty) -> #kr::NodeInfo {
+                        #kr::NodeInfo::Leaf(std::borrow::Cow::Owned(#path(value)))
+                    }
+                }
+            });
+        }
+    }
+
+    quote! { #(#impls)* }
+}
+
+/// The generic parameters of a type that appear in the type of one of its fields, for the
+/// markers generated for the field.
+fn marker_generics(input: &DeriveInput, ty: &syn::Type) -> Generics {
+    let mut used = HashSet::new();
+    collect_idents(quote! { #ty }, &mut used);
+
+    let mut generics = input.generics.clone();
+    generics.where_clause = None;
+    generics.params = generics
+        .params
+        .into_iter()
+        .filter(|param| match param {
+            GenericParam::Type(t) => used.contains(&format!("{}", t.ident)),
+            GenericParam::Lifetime(l) => used.contains(&format!("{}", l.lifetime.ident)),
+            GenericParam::Const(c) => used.contains(&format!("{}", c.ident)),
+        })
+        .collect();
+
+    generics
+}
+
+/// Generate the `Check` markers for fields that have the `validate` or `range` attributes. Like
+/// the `Render` markers, they are placed beside the type.
+fn impls_for_checks(kr: &Tokens, input: &DeriveInput) -> Tokens {
+    let name = &input.ident;
+    let mut all_fields = vec![];
+
+    match &input.data {
+        Data::Struct(data) => all_fields.push((format!("{}", name), &data.fields)),
+        Data::Enum(data) => {
+            for variant in data.variants.iter().filter(|v| !is_skipped(&v.attrs)) {
+                all_fields.push((format!("{}_{}", name, variant.ident), &variant.fields));
+            }
+        }
+        _ => {}
+    }
+
+    let mut impls = vec![];
+
+    for (owner, fields) in all_fields {
+        let fields: Vec<_> = fields.iter().filter(|f| !is_skipped(&f.attrs)).collect();
+
+        for (i, field) in fields.into_iter().enumerate() {
+            if !is_checked(&field.attrs) {
+                continue;
+            }
+
+            let fname = match &field.ident {
+                Some(ident) => format!("{}", ident),
+                None => format!("{}", i),
+            };
+            let marker = check_marker(&owner, &fname);
+            let ty = &field.ty;
+            let generics = marker_generics(input, ty);
+            let (impl_generics, _, _) = generics.split_for_impl();
+
+            let (range_check, hint) = match field_range(&field.attrs) {
+                Some(range) => {
+                    let hint: String = range.clone().into_iter().map(|tt| tt.to_string()).collect();
+                    let message = format!("Expected a value within {}", hint);
+                    (
+                        quote! {
+                            if !#kr::in_range(#range, value) {
+                                return Err(String::from(#message));
+                            }
+                        },
+                        quote! {
+                            fn hint() -> Option<&'static str> {
+                                Some(#hint)
+                            }
+                        },
+                    )
+                }
+                None => (quote! {}, quote! {}),
+            };
+
+            let validate = match field_validate(&field.attrs) {
+                Some(path) => quote! { #path(value)?; },
+                None => quote! {},
+            };
+
+            impls.push(quote! {
+                #[allow(non_camel_case_types)]
+                #[doc(hidden)]
+                struct #marker;
+
+                impl #impl_generics #kr::Check<#ty> for #marker {
+                    fn check(value: &#ty) -> Result<(), String> {
+                        #range_check
+                        #validate
+                        Ok(())
+                    }
+
+                    #hint
+                }
+            });
+        }
+    }
+
+    quote! { #(#impls)* }
+}
+
+fn is_skipped_if_default(attrs: &[syn::Attribute]) -> bool {
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "skip_if_default" => return true,
+                _ => continue,
+            }
+        }
+    }
+    false
+}
+
+/// Skipped fields, redacted fields that are not write-only, and fields that are only rendered
+/// using `debug` or `display`, make their containing type unbuildable.
+fn is_buildable(attrs: &[syn::Attribute]) -> bool {
+    if field_redact(attrs) == Some(false) {
+        return false;
+    }
+
+    match field_leaf(attrs) {
+        Some((_, from_str)) => from_str && !is_skipped(attrs),
+        None => !is_skipped(attrs),
+    }
+}
+
+/// Deserialize the value of a field, checking it if the field has the `validate` or `range`
+/// attributes.
+fn checked_deser(field: &syn::Field, owner: &str, fname: &str) -> Tokens {
+    let deser = field_deser(field);
+    if !is_checked(&field.attrs) {
+        return deser;
+    }
+
+    let marker = check_marker(owner, fname);
+    quote! {
+        {
+            let value = #deser;
+            <super::#marker as Check<_>>::check(&value).map_err(deser::DeserError::Custom)?;
+            value
+        }
+    }
+}
+
+fn field_deser(field: &syn::Field) -> Tokens {
+    if let Some(mirror) = field_mirror(&field.attrs) {
+        return quote! { <Remote<_, #mirror> as Deser>::deser(tracker)?.into_inner() };
+    }
+
+    if is_serde(&field.attrs) {
+        return quote! { <Serde<_> as Deser>::deser(tracker)?.0 };
+    }
+
+    match field_leaf(&field.attrs) {
+        Some(_) => quote! {
+            <access::leaf::FromStr as access::leaf::Parse<_>>::deser(tracker)?
+        },
+        None => quote! { Deser::deser(tracker)? },
+    }
+}
+
+/// The mirror type given by the `remote` attribute of a field, as seen from the generated
+/// module. Relative paths are resolved from the module of the type.
+fn field_mirror(attrs: &[syn::Attribute]) -> Option<Tokens> {
+    let mut mirror = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "remote" => mirror = lit_path(&nv.lit),
+                _ => continue,
+            }
+        }
+    }
+
+    Some(module_path(mirror?))
+}
+
+/// A path given by an attribute, as seen from the generated module. Relative paths are resolved
+/// from the module of the type, and paths starting with `Self` from the implementations of the
+/// type.
+fn module_path(mut path: syn::Path) -> Tokens {
+    if path.leading_colon.is_some() {
+        return quote! { #path };
+    }
+
+    let first = path
+        .segments
+        .first()
+        .map(|s| format!("{}", s.value().ident));
+    match first.as_deref() {
+        Some("crate") | Some("Self") => quote! { #path },
+        Some("self") => {
+            path.segments = path.segments.into_iter().skip(1).collect();
+            quote! { super::#path }
+        }
+        _ => quote! { super::#path },
+    }
+}
+
+/// Whether a field has the `redact` attribute, and if so, whether it can be assigned, as given by
+/// `redact = "write_only"`, the only mode accepted by `check::check_input`.
+fn field_redact(attrs: &[syn::Attribute]) -> Option<bool> {
+    let mut mode = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "redact" => mode = Some(false),
+                Meta(NameValue(nv)) if nv.ident == "redact" => mode = Some(true),
+                _ => continue,
+            }
+        }
+    }
+
+    mode
+}
+
+/// The function given by the `validate` attribute of a field.
+fn field_validate(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    let mut path = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "validate" => path = lit_path(&nv.lit),
+                _ => continue,
+            }
+        }
+    }
+
+    path
+}
+
+/// The function given by the `on_change` attribute of a field.
+fn field_on_change(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    let mut path = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "on_change" => path = lit_path(&nv.lit),
+                _ => continue,
+            }
+        }
+    }
+
+    path
+}
+
+/// The range given by the `range` attribute of a field, e.g. `range(1..=1024)`. Being an
+/// expression, it is taken from the tokens of the attribute rather than its meta items.
+fn field_range(attrs: &[syn::Attribute]) -> Option<Tokens> {
+    let mut range = None;
+
+    for item in attrs.iter().flat_map(interact_items) {
+        let mut ts = item.into_iter();
+        match (ts.next(), ts.next()) {
+            (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(args)))
+                if ident == "range" && args.delimiter() == Delimiter::Parenthesis =>
+            {
+                range = Some(args.stream());
+            }
+            _ => {}
+        }
+    }
+
+    range
+}
+
+/// Whether assignments to a field are checked, as given by the `validate` and `range` attributes.
+fn is_checked(attrs: &[syn::Attribute]) -> bool {
+    field_validate(attrs).is_some() || field_range(attrs).is_some()
+}
+
+/// The name of the `Check` marker generated for a field having the `validate` or `range`
+/// attributes.
+fn check_marker(owner: &str, fname: &str) -> Ident {
+    Ident::new(&format!("CheckFor_{}_{}", owner, fname), Span::call_site())
+}
+
+fn is_serde(attrs: &[syn::Attribute]) -> bool {
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "serde" => return true,
+                _ => continue,
+            }
+        }
+    }
+    false
+}
+
+fn is_flattened(attrs: &[syn::Attribute]) -> bool {
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(Word(word)) if word == "flatten" => return true,
+                _ => continue,
+            }
+        }
+    }
+    false
+}
+
+/// The name given by the `rename` attribute of a field or variant.
+fn renamed(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut name = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "rename" => {
+                    name = lit_str(&nv.lit).map(syn::LitStr::value);
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    name
+}
+
+/// The cases supported by the `rename_all` attribute.
+const CASES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+];
+
+/// The case given by the `rename_all` attribute of a container, applying to the fields of a
+/// struct, or to the variants of an enum.
+fn rename_all(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut case = None;
+
+    for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
+        for meta_item in meta_items {
+            match &meta_item {
+                Meta(NameValue(nv)) if nv.ident == "rename_all" => {
+                    let value = lit_str(&nv.lit).map(syn::LitStr::value);
+                    case = value.filter(|value| CASES.contains(&value.as_str()));
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    case
+}
+
+/// Convert an identifier to one of the cases supported by `rename_all`. Words are delimited by
+/// underscores, and by uppercase letters following lowercase letters or digits.
+fn convert_case(ident: &str, case: &str) -> String {
+    let mut words: Vec<String> = vec![];
+    let mut prev_lower = false;
+
+    for c in ident.chars() {
+        if c == '_' {
+            words.push(String::new());
+            prev_lower = false;
+            continue;
+        }
+        if words.is_empty() || (c.is_uppercase() && prev_lower) {
+            words.push(String::new());
+        }
+        words.last_mut().unwrap().push(c);
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+    words.retain(|w| !w.is_empty());
+
+    let capitalize = |w: &String| {
+        let lower = w.to_lowercase();
+        let mut chars = lower.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    };
+
+    match case {
+        "lowercase" => words.iter().map(|w| w.to_lowercase()).collect(),
+        "UPPERCASE" => words.iter().map(|w| w.to_uppercase()).collect(),
+        "PascalCase" => words.iter().map(capitalize).collect(),
+        "camelCase" => {
+            let mut words = words.iter();
+            let first = words.next().map(|w| w.to_lowercase()).unwrap_or_default();
+            first + &words.map(capitalize).collect::<String>()
+        }
+        "snake_case" => {
+            let words: Vec<_> = words.iter().map(|w| w.to_lowercase()).collect();
+            words.join("_")
+        }
+        "SCREAMING_SNAKE_CASE" => {
+            let words: Vec<_> = words.iter().map(|w| w.to_uppercase()).collect();
+            words.join("_")
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// The name under which a field or variant is shown and accessed from the prompt.
+fn shown_name(ident: &syn::Ident, attrs: &[syn::Attribute], rename_all: Option<&str>) -> String {
+    match (renamed(attrs), rename_all) {
+        (Some(name), _) => name,
+        (None, Some(case)) => convert_case(&format!("{}", ident), case),
+        (None, None) => format!("{}", ident),
     }
 }
 
-fn is_skipped(attrs: &Vec<syn::Attribute>) -> bool {
+fn is_skipped(attrs: &[syn::Attribute]) -> bool {
     for meta_items in attrs.iter().filter_map(get_interact_meta_items) {
         for meta_item in meta_items {
             match &meta_item {
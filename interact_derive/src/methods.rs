@@ -0,0 +1,462 @@
+use proc_macro2::TokenStream as Tokens;
+use proc_macro2::{Ident, Span};
+use syn::Meta::NameValue;
+use syn::NestedMeta::Meta;
+use syn::{FnArg, ImplItem, ImplItemMethod, ItemImpl, Pat, ReturnType};
+
+use crate::check;
+use crate::{is_interact_attr, is_skipped};
+
+struct Method {
+    name: Ident,
+    mutable: bool,
+    is_async: bool,
+    args: Vec<MethodArg>,
+    ret: String,
+    doc: String,
+}
+
+struct MethodArg {
+    name: String,
+    ty: syn::Type,
+    default: Option<String>,
+}
+
+/// Generate the `Methods` implementation for the type of an `impl` block, exposing each of its
+/// methods that take `&self` or `&mut self`, unless marked with `#[interact(skip)]`.
+pub fn impls_for_methods(kr: &Tokens, mut item: ItemImpl) -> syn::Result<Tokens> {
+    let mut methods = vec![];
+
+    for impl_item in item.items.iter_mut() {
+        if let ImplItem::Method(method) = impl_item {
+            check_attrs(&method.attrs)?;
+            let skip = is_skipped(&method.attrs);
+            let defaults = defaults(&method.attrs)?;
+            method.attrs.retain(|attr| !is_interact_attr(attr));
+            if skip {
+                continue;
+            }
+            if let Some(method) = method_desc(method, defaults)? {
+                methods.push(method);
+            }
+        }
+    }
+
+    let mut descs = vec![];
+    let mut immut_arms = vec![];
+    let mut mut_arms = vec![];
+
+    for method in &methods {
+        let name = &method.name;
+        let name_str = format!("{}", name);
+        let doc = &method.doc;
+        let ret = &method.ret;
+        let arg_idents: Vec<_> = (0..method.args.len())
+            .map(|i| Ident::new(&format!("arg_{}", i), Span::call_site()))
+            .collect();
+        let arg_idents = &arg_idents;
+        let mut arg_descs = vec![];
+        let mut arg_decls = vec![];
+        let mut arg_deser = vec![];
+        let mut arg_values = vec![];
+
+        for (idx, (arg, ident)) in method.args.iter().zip(arg_idents.iter()).enumerate() {
+            let arg_name = &arg.name;
+            let ty = type_name(&arg.ty);
+            let default = match &arg.default {
+                Some(default) => quote! { Some(#default) },
+                None => quote! { None },
+            };
+            arg_descs.push(quote! {
+                #kr::Argument { name: #arg_name, ty: #ty, default: #default }
+            });
+            let (decl, deser, value) = arg_code(kr, idx, arg_name, ident, Some(&arg.ty));
+            arg_decls.push(decl);
+            arg_deser.push(deser);
+            arg_values.push(value);
+        }
+        let arg_descs = &arg_descs;
+
+        descs.push(quote! {
+            #kr::Function {
+                name: #name_str,
+                args: &[#(#arg_descs),*],
+                ret: #ret,
+                doc: #doc,
+            }
+        });
+
+        let parse = quote! {
+            #(#arg_decls)*
+            let objects = climber.parse_args(&[#(#arg_descs),*], &mut |idx, _tracker| {
+                match idx {
+                    #(#arg_deser,)*
+                    _ => {}
+                }
+                Ok(())
+            })?;
+        };
+        let mut invoke = quote! { self.#name(#(#arg_values),*) };
+        if method.is_async {
+            invoke = quote! { #kr::executor::block_on(#invoke) };
+        }
+        let call = quote! {
+            #parse
+            if !climber.is_probe_only() {
+                objects.with(&mut |_refs| {
+                    let retval = #invoke;
+                    retcall(&retval, climber);
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        };
+
+        if method.mutable {
+            immut_arms.push(quote! {
+                #name_str => {
+                    #parse
+                    Err(#kr::CallError::NeedMutable)
+                }
+            });
+        } else {
+            immut_arms.push(quote! {
+                #name_str => {
+                    #call
+                }
+            });
+        }
+
+        mut_arms.push(quote! {
+            #name_str => {
+                #call
+            }
+        });
+    }
+
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #kr::Methods for #self_ty #where_clause {
+            fn functions() -> &'static [#kr::Function] {
+                &[#(#descs),*]
+            }
+
+            #[allow(unused_variables, unused_mut)]
+            fn immut_call<'a>(
+                &self,
+                func_name: &'static str,
+                climber: &mut #kr::Climber<'a>,
+                mut retcall: #kr::RetValCallback<'a>,
+            ) -> Result<(), #kr::CallError> {
+                use #kr::{CloneArg, DeserArg, NoCloneArg, NoDeserArg};
+
+                match func_name {
+                    #(#immut_arms)*
+                    _ => Err(#kr::CallError::NoSuchFunction),
+                }
+            }
+
+            #[allow(unused_variables, unused_mut)]
+            fn mut_call<'a>(
+                &mut self,
+                func_name: &'static str,
+                climber: &mut #kr::Climber<'a>,
+                mut retcall: #kr::RetValCallback<'a>,
+            ) -> Result<(), #kr::CallError> {
+                use #kr::{CloneArg, DeserArg, NoCloneArg, NoDeserArg};
+
+                match func_name {
+                    #(#mut_arms)*
+                    _ => Err(#kr::CallError::NoSuchFunction),
+                }
+            }
+        }
+    })
+}
+
+/// Generate a `Methods` implementation exposing no methods, for an `impl` block that cannot be
+/// exposed.
+pub fn impls_for_no_methods(kr: &Tokens, item: &ItemImpl) -> Tokens {
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #kr::Methods for #self_ty #where_clause {
+            fn functions() -> &'static [#kr::Function] {
+                &[]
+            }
+
+            fn immut_call<'a>(
+                &self,
+                _: &'static str,
+                _: &mut #kr::Climber<'a>,
+                _: #kr::RetValCallback<'a>,
+            ) -> Result<(), #kr::CallError> {
+                Err(#kr::CallError::NoSuchFunction)
+            }
+
+            fn mut_call<'a>(
+                &mut self,
+                _: &'static str,
+                _: &mut #kr::Climber<'a>,
+                _: #kr::RetValCallback<'a>,
+            ) -> Result<(), #kr::CallError> {
+                Err(#kr::CallError::NoSuchFunction)
+            }
+        }
+    }
+}
+
+/// Remove the `interact` attributes from the methods of an `impl` block, which are only
+/// understood by `interact::methods`.
+pub fn without_interact_attrs(mut item: ItemImpl) -> ItemImpl {
+    for impl_item in item.items.iter_mut() {
+        if let ImplItem::Method(method) = impl_item {
+            method.attrs.retain(|attr| !is_interact_attr(attr));
+        }
+    }
+    item
+}
+
+/// Check that the `interact` attributes of a method are `skip` and `default`.
+fn check_attrs(attrs: &[syn::Attribute]) -> syn::Result<()> {
+    for item in check::items(attrs)? {
+        match item.key.to_string().as_str() {
+            "skip" => check::word(&item)?,
+            "default" => {}
+            other => {
+                return Err(syn::Error::new(
+                    item.key.span(),
+                    format!("Unknown attribute `{}` for a method", other),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Describe a method, if it takes `&self` or `&mut self`.
+fn method_desc(
+    method: &ImplItemMethod,
+    mut defaults: Vec<(Ident, String)>,
+) -> syn::Result<Option<Method>> {
+    let decl = &method.sig.decl;
+    let name = method.sig.ident.clone();
+
+    let mutable = match decl.inputs.iter().next() {
+        Some(FnArg::SelfRef(self_ref)) => self_ref.mutability.is_some(),
+        _ => return Ok(None),
+    };
+
+    if let Some(param) = decl.generics.type_params().next() {
+        return Err(syn::Error::new_spanned(
+            param,
+            format!(
+                "Generic method `{}` cannot be exposed by `interact::methods`, \
+                 mark it with `#[interact(skip)]`",
+                name
+            ),
+        ));
+    }
+
+    let mut args = vec![];
+    for (i, input) in decl.inputs.iter().skip(1).enumerate() {
+        match input {
+            FnArg::Captured(captured) => {
+                let arg_name = match &captured.pat {
+                    Pat::Ident(pat) => format!("{}", pat.ident),
+                    _ => format!("arg_{}", i),
+                };
+                let default = defaults
+                    .iter()
+                    .position(|(name, _)| *name == arg_name)
+                    .map(|pos| defaults.remove(pos).1);
+                args.push(MethodArg {
+                    name: arg_name,
+                    ty: captured.ty.clone(),
+                    default,
+                });
+            }
+            input => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    format!("Unsupported argument in method `{}`", name),
+                ))
+            }
+        }
+    }
+
+    if let Some((arg_name, _)) = defaults.first() {
+        return Err(syn::Error::new(
+            arg_name.span(),
+            format!("Method `{}` has no argument named `{}`", name, arg_name),
+        ));
+    }
+
+    let ret = match &decl.output {
+        ReturnType::Type(_, ty) => {
+            if let syn::Type::ImplTrait(_) = **ty {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    format!(
+                        "Method `{}` returning `impl Trait` cannot be exposed by \
+                         `interact::methods`, mark it with `#[interact(skip)]`",
+                        name
+                    ),
+                ));
+            }
+            type_name(ty)
+        }
+        ReturnType::Default => String::from("()"),
+    };
+
+    Ok(Some(Method {
+        name,
+        mutable,
+        is_async: method.sig.asyncness.is_some(),
+        args,
+        ret,
+        doc: doc_comment(&method.attrs),
+    }))
+}
+
+/// Collect the default argument values given by `#[interact(default(name = value))]`.
+///
+/// A string literal is taken verbatim as the Interact expression for the value, so that
+/// `default(name = "\"text\"")` gives a string value.
+fn defaults(attrs: &[syn::Attribute]) -> syn::Result<Vec<(Ident, String)>> {
+    let mut defaults = vec![];
+
+    for item in check::items(attrs)? {
+        if item.key != "default" {
+            continue;
+        }
+
+        let list = match syn::parse2::<syn::MetaList>(item.tokens.clone()) {
+            Ok(list) => list,
+            Err(_) => {
+                return Err(syn::Error::new_spanned(
+                    &item.tokens,
+                    "Expected `default(name = value)`",
+                ))
+            }
+        };
+        for nested in list.nested.iter() {
+            match nested {
+                Meta(NameValue(nv)) => {
+                    let value = match &nv.lit {
+                        syn::Lit::Str(s) => s.value(),
+                        lit => quote!(#lit).to_string(),
+                    };
+                    defaults.push((nv.ident.clone(), value));
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "Expected `name = value` in the `default` attribute",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(defaults)
+}
+
+/// Generate the code for an argument of a called function: the declaration of the variable
+/// holding its parsed value, the match arm that parses it in the callback of
+/// `Climber::parse_args`, and the expression that passes it to the function, taking the object
+/// given for it instead if there was one.
+///
+/// Arguments taken by reference can only be objects. Arguments taken by value are parsed using
+/// `Deser`, or cloned from an object, depending on the traits their type implements. Without a
+/// known type, the argument can only be parsed.
+pub fn arg_code(
+    kr: &Tokens,
+    idx: usize,
+    name: &str,
+    ident: &Ident,
+    ty: Option<&syn::Type>,
+) -> (Tokens, Tokens, Tokens) {
+    let decl = quote! { let mut #ident = None; };
+
+    match ty {
+        Some(syn::Type::Reference(reference)) if reference.mutability.is_none() => {
+            let elem = &reference.elem;
+            (
+                quote! {},
+                quote! { #idx => return Err(#kr::CallError::ObjectRequired(#name)) },
+                quote! { _refs.get_ref::<#elem>(#idx)? },
+            )
+        }
+        Some(ty) => (
+            decl,
+            quote! {
+                #idx => #ident = Some((&#kr::Arg::<#ty>::new()).deser_arg(#name, _tracker)?)
+            },
+            quote! {
+                match #ident.take() {
+                    Some(value) => value,
+                    None => (&#kr::Arg::<#ty>::new()).clone_arg(_refs, #idx)?,
+                }
+            },
+        ),
+        None => (
+            decl,
+            quote! { #idx => #ident = Some(#kr::Deser::deser(_tracker)?) },
+            quote! {
+                match #ident.take() {
+                    Some(value) => value,
+                    None => return Err(#kr::CallError::ObjectType(#name)),
+                }
+            },
+        ),
+    }
+}
+
+/// A compact name for a type, such as `Vec<u32>` or `&str`.
+pub fn type_name<T: quote::ToTokens>(ty: &T) -> String {
+    let tokens = quote!(#ty).to_string();
+    let mut name = String::new();
+
+    for token in tokens.split_whitespace() {
+        let spaced = match (name.chars().last(), token.chars().next()) {
+            (Some(','), _) => true,
+            (Some(a), Some(b)) => is_word_char(a) && is_word_char(b),
+            _ => false,
+        };
+        if spaced {
+            name.push(' ');
+        }
+        name.push_str(token);
+    }
+
+    name
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Collect the lines of the `///` doc comment from a list of attributes.
+pub fn doc_comment(attrs: &[syn::Attribute]) -> String {
+    let mut lines = vec![];
+
+    for attr in attrs {
+        if !attr.path.is_ident("doc") {
+            continue;
+        }
+        if let Some(syn::Meta::NameValue(nv)) = attr.interpret_meta() {
+            if let syn::Lit::Str(s) = &nv.lit {
+                let line = s.value();
+                lines.push(String::from(line.strip_prefix(' ').unwrap_or(&line)));
+            }
+        }
+    }
+
+    lines.join("\n").trim().to_owned()
+}
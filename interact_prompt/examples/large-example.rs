@@ -1,7 +1,10 @@
 extern crate interact;
 extern crate structopt_derive;
 
-use interact_prompt::{LocalRegistry, SendRegistry, Settings};
+use interact::{Assist, Root};
+use interact_prompt::{
+    Command, CommandError, Commands, LocalRegistry, Output, SendRegistry, Settings,
+};
 
 mod common;
 use common::Rand;
@@ -19,27 +22,80 @@ pub struct Opt {
 
     #[structopt(short = "h", long = "history-file")]
     history_file: Option<String>,
+
+    #[structopt(short = "r", long = "redact")]
+    redact_patterns: Vec<String>,
+}
+
+/// An application-defined command, printing the number of elements of a collection.
+struct Len;
+
+impl Command for Len {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
+        let expr = params.join(" ");
+
+        match root.access(&format!("{}.len()", expr)).0 {
+            Ok(len) => out.writeln(&format!("{} has {} elements", expr, len.info)),
+            Err(err) => out.error(CommandError::Climb(err)),
+        }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[":len <expr>     Prints the number of elements of the collection given by expr"]
+    }
+
+    fn name(&self) -> &'static str {
+        ":len"
+    }
+
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        root.probe(line).1
+    }
 }
 
 fn main() -> Result<(), interact_prompt::PromptError> {
     let seed = 42;
     let mut rng: rand::StdRng = rand::SeedableRng::seed_from_u64(seed);
 
-    use common::{Basic, Complex, LocalRcLoop};
+    use common::{
+        Account, Basic, Buffers, Cache, Complex, Credentials, Deployment, Fallback, Formatted,
+        Inventory, LocalRcLoop, Pipeline, Pool, Queue, Scheduler, ServerConfig, Worker,
+    };
 
     SendRegistry::insert("complex", Box::new(Complex::new_random(&mut rng)));
     SendRegistry::insert("basic", Box::new(Basic::new_random(&mut rng)));
+    SendRegistry::insert("buffers", Box::new(Buffers::new()));
+    SendRegistry::insert("formatted", Box::new(Formatted::new()));
+    SendRegistry::insert("fallback", Box::new(Fallback::new()));
+    SendRegistry::insert("deployment", Box::new(Deployment::new()));
+    SendRegistry::insert("queue", Box::new(Queue::new()));
+    SendRegistry::insert("worker", Box::new(Worker::new()));
+    SendRegistry::insert("cache", Box::new(Cache::new()));
+    SendRegistry::insert("scheduler", Box::new(Scheduler::new()));
+    SendRegistry::insert("inventory", Box::new(Inventory::new()));
+    SendRegistry::insert("account", Box::new(Account::new()));
+    SendRegistry::insert("server_config", Box::new(ServerConfig::new()));
+    SendRegistry::insert("pipeline", Box::new(Pipeline::new()));
+    SendRegistry::insert("pool", Box::new(Pool::new()));
+    SendRegistry::insert("credentials", Box::new(Credentials::new()));
     LocalRegistry::insert("rc_loops", Box::new(LocalRcLoop::new_random(&mut rng)));
+    SendRegistry::insert_fn("scale", &["value", "factor"], |value: u32, factor: u32| {
+        value * factor
+    });
+    SendRegistry::insert_command(Box::new(Len));
 
     let Opt {
         history_file,
         initial_command,
+        redact_patterns,
     } = Opt::from_args();
 
     interact_prompt::direct(
         Settings {
             initial_command,
             history_file,
+            redact_patterns,
+            ..Settings::default()
         },
         (),
     )?;
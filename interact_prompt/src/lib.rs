@@ -36,6 +36,8 @@
 //! }
 //! ```
 //!
+//! To embed Interact in another user interface, use `Session` instead of the prompt.
+//!
 //! NOTE: Currently only the `SendRegistry` is supported for the background `spawn` variant of
 //! Interact. Supporting LocalRegistry is planned for the future.
 //!
@@ -56,18 +58,29 @@ use rustyline::{CompletionType, Config, Context, EditMode, Editor};
 use rustyline::validate::Validator;
 use std::borrow::Cow::{self, Borrowed, Owned};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use std::thread;
 
-use interact::{Assist, NextOptions, NodeTree};
+use interact::{Assist, BytesMode, NextOptions, Root, Schemas};
 
 mod print;
 pub mod registry;
+pub mod session;
+mod sigint;
 pub use crate::registry::{LocalRegistry, SendRegistry};
+pub use crate::session::{CommandError, Output, Session};
 
 #[derive(Clone)]
 pub struct Settings {
     pub history_file: Option<String>,
     pub initial_command: Option<String>,
+
+    /// Patterns of the names of fields to show as `<redacted>`, such as `*password*`, in addition
+    /// to the fields having the `redact` attribute. See `interact::redact`.
+    pub redact_patterns: Vec<String>,
+
+    /// What happens when the prompt is ended, by `:exit` or Ctrl-D.
+    pub on_exit: OnExit,
 }
 
 impl Default for Settings {
@@ -75,10 +88,27 @@ impl Default for Settings {
         Self {
             history_file: None,
             initial_command: None,
+            redact_patterns: vec![],
+            on_exit: OnExit::EndPrompt,
         }
     }
 }
 
+/// What happens when the prompt is ended. Ctrl-C never ends the prompt, but only cancels the line
+/// being edited or interrupts the evaluation in progress. An interruption cannot stop an `async`
+/// method that the executor is blocking on, or a wait for a `Mutex` lock.
+///
+/// The default is `EndPrompt`. Earlier versions exited the process on `:exit`, Ctrl-D and Ctrl-C,
+/// so programs relying on that with `Settings::default()` need to set `ExitProcess`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnExit {
+    /// Return from `direct`, leaving the rest of the program running.
+    EndPrompt,
+
+    /// Exit the process.
+    ExitProcess,
+}
+
 #[derive(Clone)]
 pub enum Interaction {
     Line(String),
@@ -93,52 +123,63 @@ pub enum Response {
     Exit,
 }
 
-struct Commands {
-    handlers: BTreeMap<&'static str, Box<dyn Command>>,
-    def_handler: Box<dyn Command>,
-}
+/// Lends a root to the given callback, such as the roots of the registries.
+pub(crate) type WithRoot = dyn Fn(&mut dyn FnMut(&mut Root));
 
-fn print(elem: &NodeTree) {
-    print::pretty_format(
-        elem,
-        &print::NodePrinterSettings {
-            max_line_length: 120,
-            indent_step: 4,
-        },
-    )
+/// The table of the commands of the prompt, being the built-in ones such as `:help`, and those
+/// registered by the application via `SendRegistry::insert_command`.
+pub struct Commands {
+    handlers: BTreeMap<&'static str, Arc<dyn Command + Send + Sync>>,
+    def_handler: Box<dyn Command>,
+    root: Box<WithRoot>,
 }
 
-trait Command {
-    fn handle(&self, commands: &Commands, params: Vec<String>);
+/// A command of the prompt, such as `:help`, given on a line by its name followed by its
+/// parameters.
+///
+/// Applications can add their own commands using `SendRegistry::insert_command`.
+pub trait Command {
+    /// Run the command with the parameters following its name, as split by spaces, writing
+    /// what it prints to the given output.
+    fn handle(&self, commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output);
+
+    /// The lines describing the command, as printed by `:help`. The first line starts with the
+    /// usage of the command, and the description is aligned to the 17th column.
     fn help(&self) -> &'static [&'static str];
+
+    /// The name of the command, including its leading `:`, e.g. `:help`.
     fn name(&self) -> &'static str;
-    fn get_completions(&self, line: &str) -> Assist<String>;
+
+    /// Complete the parameters of the command, given the line following its name. Commands
+    /// taking an expression can use `Root::probe` for this.
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String>;
 }
 
 struct Help;
 
 impl Command for Help {
-    fn handle(&self, commands: &Commands, _params: Vec<String>) {
-        println!();
-        println!("The following are the valid commands:");
-        println!();
-
-        for command in &commands.handlers {
-            for line in command.1.help() {
-                println!("      {}", line);
+    fn handle(&self, commands: &Commands, root: &mut Root, _params: Vec<String>, out: &mut Output) {
+        out.writeln("");
+        out.writeln("The following are the valid commands:");
+        out.writeln("");
+
+        for command in commands.iter() {
+            for line in command.help() {
+                out.writeln(&format!("      {}", line));
             }
         }
 
-        println!();
+        out.writeln("");
 
-        registry::with_root(|root| {
-            println!("Possible nodes to evaluate from:");
-            println!();
-            for k in root.keys() {
-                println!("      {}", k);
+        out.writeln("Possible nodes to evaluate from:");
+        out.writeln("");
+        for k in root.keys() {
+            match root.function(k) {
+                Some(function) => out.writeln(&format!("      {}", function)),
+                None => out.writeln(&format!("      {}", k)),
             }
-            println!();
-        })
+        }
+        out.writeln("");
     }
 
     fn help(&self) -> &'static [&'static str] {
@@ -147,25 +188,35 @@ impl Command for Help {
     fn name(&self) -> &'static str {
         ":help"
     }
-    fn get_completions(&self, _line: &str) -> Assist<String> {
+    fn get_completions(&self, _root: &mut Root, _line: &str) -> Assist<String> {
         Assist::default()
     }
 }
 
-struct Exit;
+/// Ends the prompt, described as taking the action configured for it.
+struct Exit(OnExit);
 
 impl Command for Exit {
-    fn handle(&self, _commands: &Commands, _params: Vec<String>) {
-        std::process::exit(0);
+    fn handle(
+        &self,
+        _commands: &Commands,
+        _root: &mut Root,
+        _params: Vec<String>,
+        out: &mut Output,
+    ) {
+        out.exit = true;
     }
 
     fn help(&self) -> &'static [&'static str] {
-        &[":exit           Terminate the program"]
+        match self.0 {
+            OnExit::EndPrompt => &[":exit           End the prompt"],
+            OnExit::ExitProcess => &[":exit           End the prompt and exit the program"],
+        }
     }
     fn name(&self) -> &'static str {
         ":exit"
     }
-    fn get_completions(&self, _line: &str) -> Assist<String> {
+    fn get_completions(&self, _root: &mut Root, _line: &str) -> Assist<String> {
         Assist::default()
     }
 }
@@ -173,20 +224,18 @@ impl Command for Exit {
 struct Access;
 
 impl Command for Access {
-    fn handle(&self, _commands: &Commands, params: Vec<String>) {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
         let rest_of_string = params.join(" ");
 
-        registry::with_root(|root| {
-            let res = root.access(&rest_of_string).0;
-            match res {
-                Ok(read_value) => {
-                    print(&read_value);
-                }
-                Err(err) => {
-                    println!("{:?}", err);
-                }
+        let res = root.access(&rest_of_string).0;
+        match res {
+            Ok(read_value) => {
+                out.node(&read_value);
             }
-        })
+            Err(err) => {
+                out.error(CommandError::Climb(err));
+            }
+        }
     }
 
     fn help(&self) -> &'static [&'static str] {
@@ -197,44 +246,266 @@ impl Command for Access {
         "<expr>"
     }
 
-    fn get_completions(&self, line: &str) -> Assist<String> {
-        registry::with_root(|root| root.probe(line).1)
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        root.probe(line).1
     }
 }
 
-impl Commands {
-    fn new() -> Self {
-        let mut handlers = BTreeMap::new();
+struct Bytes;
+
+impl Command for Bytes {
+    fn handle(
+        &self,
+        _commands: &Commands,
+        root: &mut Root,
+        mut params: Vec<String>,
+        out: &mut Output,
+    ) {
+        let bytes_mode = match params.first().and_then(|name| BytesMode::from_name(name)) {
+            Some(bytes_mode) => bytes_mode,
+            None => {
+                let names: Vec<_> = BytesMode::names().collect();
+                let message = format!("Expected one of the modes: {}", names.join(", "));
+                out.error(CommandError::Usage(message));
+                return;
+            }
+        };
+        params.remove(0);
+        let rest_of_string = params.join(" ");
+
+        let res = root.access_as(&rest_of_string, bytes_mode).0;
+        match res {
+            Ok(read_value) => {
+                out.node(&read_value);
+            }
+            Err(err) => {
+                out.error(CommandError::Climb(err));
+            }
+        }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            ":bytes <mode> <expr>",
+            "                Access the value of expr, rendering byte buffers in the given mode",
+            "                (hexdump, hex, base64, utf8, list)",
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        ":bytes"
+    }
+
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        let mode_len = line.find(' ').unwrap_or(line.len());
+        let (mode, rest) = line.split_at(mode_len);
 
-        for command in vec![
-            Box::new(Help) as Box<dyn Command>,
-            Box::new(Exit) as Box<dyn Command>,
+        if rest.is_empty() {
+            let matching: Vec<_> = BytesMode::names()
+                .filter(|name| name.starts_with(mode))
+                .map(String::from)
+                .collect();
+            let mut assist = Assist::default();
+            if !matching.is_empty() {
+                assist.pend(mode.len());
+            }
+            return assist.next_options(NextOptions::Avail(0, matching));
+        }
+
+        if BytesMode::from_name(mode).is_none() {
+            return Assist::default();
+        }
+
+        let nospace = rest.chars().position(|c| c != ' ').unwrap_or(rest.len());
+        Access
+            .get_completions(root, &rest[nospace..])
+            .with_valid(mode_len + nospace)
+    }
+}
+
+struct Doc;
+
+impl Command for Doc {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
+        let rest_of_string = params.join(" ");
+
+        match root.doc(&rest_of_string) {
+            Ok(Some(doc)) => out.writeln(&doc),
+            Ok(None) => out.writeln(&format!("No documentation for {}", rest_of_string)),
+            Err(err) => out.error(CommandError::Climb(err)),
+        }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            ":doc <expr>     Prints the doc comments of the field, value or function given by",
+            "                expr",
         ]
-        .into_iter()
-        {
-            handlers.insert(command.name(), command);
+    }
+
+    fn name(&self) -> &'static str {
+        ":doc"
+    }
+
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        Access.get_completions(root, line)
+    }
+}
+
+struct Type;
+
+impl Command for Type {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
+        let rest_of_string = params.join(" ");
+
+        match root.type_info(&rest_of_string) {
+            Ok(Some(type_info)) => out.writeln(type_info.name()),
+            Ok(None) => out.writeln(&format!(
+                "The type of {} is not known without a call",
+                rest_of_string
+            )),
+            Err(err) => out.error(CommandError::Climb(err)),
         }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[":type <expr>    Prints the type of the value given by expr"]
+    }
+
+    fn name(&self) -> &'static str {
+        ":type"
+    }
 
-        Commands {
-            handlers,
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        Access.get_completions(root, line)
+    }
+}
+
+struct Schema;
+
+impl Command for Schema {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
+        let rest_of_string = params.join(" ");
+
+        match root.type_info(&rest_of_string) {
+            Ok(Some(type_info)) => {
+                let mut schemas = Schemas::new();
+                let schema = type_info.describe(&mut schemas);
+                out.writeln(&schemas.resolve(&schema).to_string())
+            }
+            Ok(None) => out.writeln(&format!(
+                "The type of {} is not known without a call",
+                rest_of_string
+            )),
+            Err(err) => out.error(CommandError::Climb(err)),
+        }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[
+            ":schema <expr>  Prints the fields, variants and functions of the type of the value",
+            "                given by expr",
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        ":schema"
+    }
+
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        Access.get_completions(root, line)
+    }
+}
+
+impl Commands {
+    /// The built-in commands, along with the commands registered by the application, which
+    /// take precedence over the built-in commands having the same names. They are evaluated on
+    /// the roots of the registries. `:exit` is described as taking the given action.
+    fn new(on_exit: OnExit) -> Self {
+        let mut commands = Self::builtin(Box::new(|f| registry::with_root(|root| f(root))));
+        commands.insert(Arc::new(Exit(on_exit)));
+        for command in SendRegistry::commands() {
+            commands.insert(command);
+        }
+        commands
+    }
+
+    /// The built-in commands, evaluated on the root lent by `root`.
+    fn builtin(root: Box<WithRoot>) -> Self {
+        let mut commands = Commands {
+            handlers: BTreeMap::new(),
             def_handler: Box::new(Access) as Box<dyn Command>,
+            root,
+        };
+
+        for command in &[
+            Arc::new(Help) as Arc<dyn Command + Send + Sync>,
+            Arc::new(Exit(OnExit::EndPrompt)) as Arc<dyn Command + Send + Sync>,
+            Arc::new(Bytes) as Arc<dyn Command + Send + Sync>,
+            Arc::new(Doc) as Arc<dyn Command + Send + Sync>,
+            Arc::new(Type) as Arc<dyn Command + Send + Sync>,
+            Arc::new(Schema) as Arc<dyn Command + Send + Sync>,
+        ] {
+            commands.insert(command.clone());
         }
+
+        commands
+    }
+
+    /// Add a command, replacing any command having the same name.
+    fn insert(&mut self, command: Arc<dyn Command + Send + Sync>) {
+        self.handlers.insert(command.name(), command);
+    }
+
+    fn with_root<R, F: FnOnce(&mut Root) -> R>(&self, f: F) -> R {
+        let mut f = Some(f);
+        let mut ret = None;
+        (self.root)(&mut |root| {
+            if let Some(f) = f.take() {
+                ret = Some(f(root));
+            }
+        });
+        ret.expect("the root was not lent")
+    }
+
+    /// The command having the given name, e.g. `:help`.
+    pub fn get(&self, name: &str) -> Option<&dyn Command> {
+        self.handlers
+            .get(name)
+            .map(|command| &**command as &dyn Command)
     }
 
-    fn handle_cmd(&self, line: &str) {
+    /// The commands, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Command> {
+        self.handlers
+            .values()
+            .map(|command| &**command as &dyn Command)
+    }
+
+    fn handle_cmd(&self, line: &str, out: &mut Output) {
         let mut params: Vec<String> = line.split(' ').map(|x| x.to_owned()).collect();
         if params == ["?"] {
-            Help.handle(&self, params);
+            self.with_root(|root| Help.handle(self, root, params, out));
         } else if params != [""] {
             if let Some(command) = self.handlers.get(params[0].as_str()) {
                 params.remove(0);
-                (**command).handle(self, params);
+                self.with_root(|root| command.handle(self, root, params, out));
             } else {
-                self.def_handler.handle(self, params)
+                self.with_root(|root| self.def_handler.handle(self, root, params, out))
             }
         }
     }
 
+    /// The signature of the function being called at the end of an expression line, or the
+    /// values accepted by the field being assigned, if any.
+    fn get_hint(&self, line: &str) -> Option<String> {
+        if line.starts_with(':') || line.starts_with('?') {
+            return None;
+        }
+
+        self.with_root(|root| root.hint(line))
+    }
+
     fn get_next_options(&self, line: &str, pos: usize) -> Assist<String> {
         if line == "?" || line == ":" {
             let mut assist = Assist::default();
@@ -242,6 +513,7 @@ impl Commands {
             return assist;
         }
 
+        let pos = session::char_boundary(line, pos);
         let split: Vec<String> = line[..pos].split(' ').map(|x| x.to_owned()).collect();
         let prefix = split
             .iter()
@@ -283,16 +555,17 @@ impl Commands {
                         .chars()
                         .position(|c| c != ' ')
                         .unwrap_or_else(|| deeper.len());
-                    let sub_access = (**handler).get_completions(&deeper[nospace..]);
+                    let sub_access =
+                        self.with_root(|root| handler.get_completions(root, &deeper[nospace..]));
                     sub_access.with_valid(reconstruct.len() + nospace)
                 } else {
                     Assist::default()
                 }
             } else {
-                Access.get_completions(line)
+                self.with_root(|root| Access.get_completions(root, line))
             }
         } else if split != [""] {
-            Access.get_completions(line)
+            self.with_root(|root| Access.get_completions(root, line))
         } else {
             Assist::default()
         }
@@ -301,24 +574,40 @@ impl Commands {
 
 /// This trait defines an optional handler for prompt commands. This allows to
 /// override the behavior of the handler for `()`.
+///
+/// The handler for `()` evaluates lines, and ends the prompt on `:exit` or Ctrl-D. While a line
+/// is being handled, Ctrl-C interrupts the evaluations made by the handler, which then fail with
+/// `ClimbError::Interrupted`.
 pub trait Handler {
     fn receive_interaction(&self, intr: Interaction) -> Response {
-        match intr {
-            Interaction::Line(string) => {
-                Commands::new().handle_cmd(&string);
-            }
-            Interaction::CtrlC | Interaction::CtrlD => {
-                std::process::exit(0);
+        eval_interaction(&Session::new(), intr)
+    }
+
+    /// Handle an interaction of the prompt, whose session is the one used for completing, hinting
+    /// and highlighting lines. By default, this calls `receive_interaction`.
+    fn receive_session_interaction(&self, _session: &Session, intr: Interaction) -> Response {
+        self.receive_interaction(intr)
+    }
+}
+
+fn eval_interaction(session: &Session, intr: Interaction) -> Response {
+    match intr {
+        Interaction::Line(string) => {
+            let output = session.eval(&string);
+            print!("{}", output.to_ansi());
+            if output.exit {
+                return Response::Exit;
             }
-            _ => {}
         }
-        Response::Continue
+        Interaction::CtrlD => return Response::Exit,
+        Interaction::CtrlC | Interaction::Err => {}
     }
+    Response::Continue
 }
 
 // InteractPromptHelper
 
-struct InteractPromptHelper<'a, H>((), &'a H)
+struct InteractPromptHelper<'a, H>(&'a Session, &'a H)
 where
     H: 'a;
 
@@ -329,24 +618,16 @@ where
     type Candidate = String;
 
     fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Result<(usize, Vec<String>), ReadlineError> {
-        let (valid, _, _, options) = Commands::new().get_next_options(line, pos).dismantle();
-        Ok(options.into_position(valid))
+        let completions = self.0.complete(line, pos);
+        Ok((completions.start, completions.candidates))
     }
 }
 
 impl<'a, H> Hinter for InteractPromptHelper<'a, H> {
     fn hint(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Option<String> {
-        let (valid, _, _, options) = Commands::new().get_next_options(line, pos).dismantle();
-        let (from_pos, v) = options.into_position(valid);
-        if v.len() == 1 {
-            if from_pos < pos {
-                let v0_len = v[0].len();
-                Some(v[0][std::cmp::min(pos - from_pos, v0_len)..].to_owned())
-            } else {
-                Some(v[0].to_owned())
-            }
-        } else {
-            None
+        match self.0.hint(line, pos)? {
+            session::Hint::Completion(rest) => Some(rest),
+            session::Hint::Info(info) => Some(format!("    {}", info)),
         }
     }
 }
@@ -357,29 +638,13 @@ impl<'a, H> Highlighter for InteractPromptHelper<'a, H> {
     }
 
     fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
-        let s = format!("{}", Color::Fixed(240).paint(hint));
-        Owned(s.to_owned())
+        let mut output = Output::new();
+        output.write(hint, session::Style::Hint);
+        Owned(output.to_ansi())
     }
 
     fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
-        let (valid, pending, pending_valid, _) =
-            Commands::new().get_next_options(line, pos).dismantle();
-        let yellow_cutoff = std::cmp::min(valid, line.len());
-        let green_cutoff = std::cmp::min(valid + pending - pending_valid, line.len());
-        let red_cutoff = std::cmp::min(valid + pending, line.len());
-
-        let ok = &line[..yellow_cutoff].to_string();
-        let pending = format!(
-            "{}",
-            Color::Yellow.paint(&line[yellow_cutoff..green_cutoff])
-        );
-        let pending_valid = format!(
-            "{}",
-            Color::Green.bold().paint(&line[green_cutoff..red_cutoff])
-        );
-        let err = format!("{}", Color::Red.paint(&line[red_cutoff..]));
-
-        Owned(format!("{}{}{}{}", ok, pending, pending_valid, err))
+        Owned(session::to_ansi(&self.0.highlight(line, pos)))
     }
 
     fn highlight_char(&self, _grapheme: &str, _pos: usize) -> bool {
@@ -391,7 +656,11 @@ impl<'a, H> Helper for InteractPromptHelper<'a, H> {}
 
 impl<'a, H> Validator for InteractPromptHelper<'a, H> {}
 
-impl Handler for () {}
+impl Handler for () {
+    fn receive_session_interaction(&self, session: &Session, intr: Interaction) -> Response {
+        eval_interaction(session, intr)
+    }
+}
 
 #[derive(Debug)]
 pub enum PromptError {
@@ -399,10 +668,27 @@ pub enum PromptError {
 }
 
 /// Use the current thread for an interactive `Interact` prompt.
+///
+/// While the prompt runs, it handles `SIGINT` in order to interrupt evaluations on Ctrl-C. The
+/// handler is process-wide, even when the prompt runs on a thread of its own: it passes the signal
+/// on to the handler that was installed before it, and restores that handler when the prompt
+/// ends. The program should therefore not replace the handler of `SIGINT` while the prompt runs.
 pub fn direct<H>(settings: Settings, handler: H) -> Result<(), PromptError>
 where
     H: Handler
 {
+    let Settings {
+        history_file,
+        initial_command,
+        redact_patterns,
+        on_exit,
+    } = settings;
+    interact::redact::set_patterns(&redact_patterns);
+
+    // The commands registered by now are the ones available in the prompt.
+    let session = Session::for_prompt(on_exit);
+    let _sigint = sigint::Guard::install();
+
     let config = Config::builder()
         .history_ignore_space(true)
         .completion_type(CompletionType::List)
@@ -410,11 +696,7 @@ where
         .build();
     let mut rl = Editor::with_config(config);
 
-    let Settings {
-        history_file,
-        initial_command,
-    } = settings;
-    let h = InteractPromptHelper((), &handler);
+    let h = InteractPromptHelper(&session, &handler);
     rl.set_helper(Some(h));
 
     println!("Rust `interact`, type '?' for more information");
@@ -424,19 +706,23 @@ where
             .map_err(PromptError::ReadLine)?;
     }
 
+    let mut running = true;
+
     match initial_command {
         None => {}
         Some(initial_command) => {
             println!("{}", initial_command);
-            let response = handler.receive_interaction(Interaction::Line(initial_command));
+            let response = sigint::interruptible(|| {
+                handler.receive_session_interaction(&session, Interaction::Line(initial_command))
+            });
             match response {
-                Response::Exit => return Ok(()),
+                Response::Exit => running = false,
                 Response::Continue => {}
             }
         }
     }
 
-    loop {
+    while running {
         let prompt = format!("{} ", Color::Fixed(240).bold().paint(">>>"));
         let line = rl.readline(&prompt);
 
@@ -452,9 +738,14 @@ where
             }
         };
 
-        let response = handler.receive_interaction(interaction);
+        let response = match interaction {
+            Interaction::Line(_) => {
+                sigint::interruptible(|| handler.receive_session_interaction(&session, interaction))
+            }
+            _ => handler.receive_session_interaction(&session, interaction),
+        };
         match response {
-            Response::Exit => break,
+            Response::Exit => running = false,
             Response::Continue => {}
         }
     }
@@ -464,10 +755,15 @@ where
             .map_err(PromptError::ReadLine)?;
     }
 
+    if on_exit == OnExit::ExitProcess {
+        std::process::exit(0);
+    }
+
     Ok(())
 }
 
-/// Spawn `Interact` in a new thread.
+/// Spawn `Interact` in a new thread. The prompt handles `SIGINT` of the whole process, like
+/// `direct` describes.
 pub fn spawn<H>(settings: Settings, handler: H) -> std::thread::JoinHandle<()>
 where
     H: Handler + Send + Sync + 'static,
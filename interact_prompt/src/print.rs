@@ -1,8 +1,9 @@
-use ansi_term::Color;
 use interact::NodeTree;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 
+use crate::session::{Output, Style};
+
 pub struct NodePrinterSettings {
     pub max_line_length: u16,
     pub indent_step: u16,
@@ -10,6 +11,7 @@ pub struct NodePrinterSettings {
 
 struct Printer<'a> {
     settings: &'a NodePrinterSettings,
+    out: &'a mut Output,
     indent: usize,
     indent_string: String,
     line_used: usize,
@@ -19,16 +21,16 @@ struct Printer<'a> {
 }
 
 impl<'a> Printer<'a> {
-    fn write(&mut self, s: &str) {
+    fn write(&mut self, s: &str, style: Style) {
         if self.line_used == 0 {
-            print!("{}", self.indent_string);
+            self.out.write(&self.indent_string, Style::Plain);
         }
-        print!("{}", s);
+        self.out.write(s, style);
         self.line_used += s.len();
     }
 
     fn end_line(&mut self) {
-        println!();
+        self.out.write("\n", Style::Plain);
         self.line_used = 0;
     }
 
@@ -77,17 +79,14 @@ impl<'a> Printer<'a> {
                         }
                     };
 
-                    self.write(&format!(
-                        "{}",
-                        Color::Green.paint(format!("[#{}] ", seen_idx))
-                    ));
+                    self.write(&format!("[#{}] ", seen_idx), Style::Reference);
                 }
             }
         }
 
         match &elem.info {
             Grouped(prefix, sub, end) => {
-                self.write(&format!("{}", Color::Cyan.paint(format!("{}", prefix))));
+                self.write(&prefix.to_string(), Style::Punctuation);
                 let item_linebreak = self.item_linebreak;
                 let mut indented = false;
 
@@ -105,7 +104,7 @@ impl<'a> Printer<'a> {
                 } else {
                     self.item_linebreak = false;
                     if has_space {
-                        self.write(&" ");
+                        self.write(" ", Style::Plain);
                     }
                 }
 
@@ -117,19 +116,19 @@ impl<'a> Printer<'a> {
                     self.down_indent();
                     self.end_line();
                 } else if has_space {
-                    self.write(&" ");
+                    self.write(" ", Style::Plain);
                 }
 
-                self.write(&format!("{}", Color::Cyan.paint(&format!("{}", end))));
+                self.write(&end.to_string(), Style::Punctuation);
             }
             Delimited(delimiter, v) => {
                 for (idx, i) in v.iter().enumerate() {
                     if idx > 0 {
                         if self.item_linebreak {
-                            self.write(&format!("{}", delimiter));
+                            self.write(&delimiter.to_string(), Style::Plain);
                             self.end_line();
                         } else {
-                            self.write(&format!("{} ", delimiter));
+                            self.write(&format!("{} ", delimiter), Style::Plain);
                         }
                     }
 
@@ -138,43 +137,52 @@ impl<'a> Printer<'a> {
             }
             Tuple(key, sep, value) => {
                 self.inner_pretty_print(key);
-                self.write(&format!("{} ", Color::Cyan.paint(*sep)));
+                self.write(sep, Style::Punctuation);
+                self.write(" ", Style::Plain);
                 self.inner_pretty_print(value);
             }
             Named(item, next) => {
                 self.inner_pretty_print(item);
-                self.write(&" ");
+                self.write(" ", Style::Plain);
                 self.inner_pretty_print(next);
             }
             Limited => {
-                self.write(&format!("{}", Color::Yellow.bold().paint("...<<<>>>...")));
+                self.write("...<<<>>>...", Style::Limited);
             }
             Hole(_) => {
-                self.write(&format!(
-                    "{}",
-                    Color::Red.bold().paint(format!("< - hole - >"))
-                ));
+                self.write("< - hole - >", Style::Unavailable);
             }
             Repeated => {
-                self.write(&format!(
-                    "{}",
-                    Color::Green.paint(format!("[#{}]", repeated_idx.unwrap_or(0)))
-                ));
+                self.write(
+                    &format!("[#{}]", repeated_idx.unwrap_or(0)),
+                    Style::Reference,
+                );
             }
             BorrowedMut => {
-                self.write(&format!(
-                    "{}",
-                    Color::Red.bold().paint(format!("< borrowed-mut >"))
-                ));
+                self.write("< borrowed-mut >", Style::Unavailable);
             }
             Locked => {
-                self.write(&format!(
-                    "{}",
-                    Color::Red.bold().paint(format!("< locked >"))
-                ));
+                self.write("< locked >", Style::Unavailable);
             }
             Leaf(s) => {
-                self.write(s);
+                self.write(s, Style::Plain);
+            }
+            Lines(lines) => {
+                let inline = self.line_used > 0;
+                if inline {
+                    self.up_indent();
+                }
+
+                for line in lines {
+                    if self.line_used > 0 {
+                        self.end_line();
+                    }
+                    self.write(line, Style::Plain);
+                }
+
+                if inline {
+                    self.down_indent();
+                }
             }
         };
     }
@@ -188,9 +196,10 @@ impl<'a> Printer<'a> {
     }
 }
 
-pub fn pretty_format(elem: &NodeTree, settings: &NodePrinterSettings) {
+pub fn pretty_format(elem: &NodeTree, settings: &NodePrinterSettings, out: &mut Output) {
     let mut state = Printer {
         settings,
+        out,
         indent: 0,
         line_used: 0,
         item_linebreak: true,
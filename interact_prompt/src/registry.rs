@@ -1,19 +1,24 @@
 //! Interact Prompt registry for accessible state.
 
 use std::cell::RefCell;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use interact::{Access, Root, RootLocal, RootSend};
+use interact::{Access, CallDeser, Root, RootLocal, RootSend};
 
-/// The `Send` Registry manages state roots of the whole process.
+use crate::Command;
+
+/// The `Send` Registry manages state roots of the whole process, and the prompt commands added
+/// by the application.
 pub struct SendRegistry {
     root: Mutex<RootSend>,
+    commands: Mutex<Vec<Arc<dyn Command + Send + Sync>>>,
 }
 
 lazy_static! {
     static ref REGISTRY: SendRegistry = {
         SendRegistry {
             root: Mutex::new(RootSend::new()),
+            commands: Mutex::new(vec![]),
         }
     };
 }
@@ -23,7 +28,33 @@ impl SendRegistry {
     pub fn insert(string: &'static str, item: Box<dyn Access + Send>) {
         let mut root = REGISTRY.root.lock().unwrap();
 
-        root.owned.insert(string, item);
+        root.insert(string, item);
+    }
+
+    /// Insert a function that is independent of any state into the root, along with the names of
+    /// its arguments.
+    pub fn insert_fn<F, Args>(string: &'static str, arg_names: &[&'static str], f: F)
+    where
+        F: CallDeser<Args> + Send + 'static,
+        Args: 'static,
+    {
+        let mut root = REGISTRY.root.lock().unwrap();
+
+        root.insert_fn(string, arg_names, f);
+    }
+
+    /// Add a command to the prompt, such as `:conns`, replacing any command having the same
+    /// name. It needs to be added before the prompt starts. The root is locked while the command
+    /// is handled, so the command should not insert into this registry.
+    pub fn insert_command(command: Box<dyn Command + Send + Sync>) {
+        let mut commands = REGISTRY.commands.lock().unwrap();
+
+        commands.retain(|other| other.name() != command.name());
+        commands.push(Arc::from(command));
+    }
+
+    pub(crate) fn commands() -> Vec<Arc<dyn Command + Send + Sync>> {
+        REGISTRY.commands.lock().unwrap().clone()
     }
 
     #[doc(hidden)]
@@ -54,7 +85,19 @@ impl LocalRegistry {
     /// Insert new states into the root.
     pub fn insert(string: &'static str, item: Box<dyn Access>) {
         LOCAL_REGISTRY.with(|reg| {
-            reg.borrow_mut().root.owned.insert(string, item);
+            reg.borrow_mut().root.insert(string, item);
+        });
+    }
+
+    /// Insert a function that is independent of any state into the root, along with the names of
+    /// its arguments.
+    pub fn insert_fn<F, Args>(string: &'static str, arg_names: &[&'static str], f: F)
+    where
+        F: CallDeser<Args> + 'static,
+        Args: 'static,
+    {
+        LOCAL_REGISTRY.with(|reg| {
+            reg.borrow_mut().root.insert_fn(string, arg_names, f);
         });
     }
 }
@@ -67,10 +110,7 @@ where
     LOCAL_REGISTRY.with(|local_reg| {
         SendRegistry::with_root(|send_reg| {
             let mut local_reg = local_reg.borrow_mut();
-            let mut root = Root {
-                send: Some(send_reg),
-                local: Some(&mut local_reg.root),
-            };
+            let mut root = Root::new(Some(send_reg), Some(&mut local_reg.root));
             f(&mut root)
         })
     })
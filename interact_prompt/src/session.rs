@@ -0,0 +1,291 @@
+//! An Interact prompt session that is independent of any terminal.
+//!
+//! A `Session` evaluates lines and assists with editing them like the prompt does, but returns
+//! the results instead of printing them, so that Interact can be embedded into other user
+//! interfaces, such as a web console, or be driven from tests.
+
+use std::sync::Arc;
+
+use ansi_term::{Color, Style as AnsiStyle};
+use interact::{ClimbError, NodeTree, Root};
+
+use crate::print;
+use crate::{Command, Commands, OnExit};
+
+/// The meaning of a span of text, by which it is colored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+
+    /// Brackets, delimiters and separators of a printed value.
+    Punctuation,
+
+    /// A reference to a value that is printed more than once, e.g. `[#1]`.
+    Reference,
+
+    /// Marks where a printed value was cut short.
+    Limited,
+
+    /// A value that could not be printed, such as one behind a locked `Mutex`.
+    Unavailable,
+
+    /// The message of an error.
+    Error,
+
+    /// The part of an edited line that may still become valid.
+    Pending,
+
+    /// The part of an edited line that is valid so far, but is not complete.
+    PendingValid,
+
+    /// The part of an edited line that cannot become valid.
+    Invalid,
+
+    /// A hint shown after the cursor.
+    Hint,
+}
+
+impl Style {
+    fn ansi(self) -> Option<AnsiStyle> {
+        match self {
+            Style::Plain | Style::Error => None,
+            Style::Punctuation => Some(Color::Cyan.normal()),
+            Style::Reference => Some(Color::Green.normal()),
+            Style::Limited => Some(Color::Yellow.bold()),
+            Style::Unavailable => Some(Color::Red.bold()),
+            Style::Pending => Some(Color::Yellow.normal()),
+            Style::PendingValid => Some(Color::Green.bold()),
+            Style::Invalid => Some(Color::Red.normal()),
+            Style::Hint => Some(Color::Fixed(240).normal()),
+        }
+    }
+}
+
+/// A piece of text having a single style.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+/// Render the spans as text colored using ANSI escape codes.
+pub fn to_ansi(spans: &[Span]) -> String {
+    let mut s = String::new();
+
+    for span in spans {
+        match span.style.ansi() {
+            Some(style) => s.push_str(&style.paint(span.text.as_str()).to_string()),
+            None => s.push_str(&span.text),
+        }
+    }
+
+    s
+}
+
+/// An error of a command.
+#[derive(Debug)]
+pub enum CommandError {
+    /// Evaluating an expression failed.
+    Climb(ClimbError),
+
+    /// The parameters of the command are not valid.
+    Usage(String),
+}
+
+/// The output of a command, being text made of styled spans, along with the errors that were
+/// reported.
+#[derive(Debug, Default)]
+pub struct Output {
+    pub spans: Vec<Span>,
+    pub errors: Vec<CommandError>,
+
+    /// Whether the command asked to end the prompt, as `:exit` does.
+    pub exit: bool,
+}
+
+impl Output {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Append text of the given style.
+    pub fn write(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(text),
+            _ => self.spans.push(Span {
+                text: String::from(text),
+                style,
+            }),
+        }
+    }
+
+    /// Append a line of plain text.
+    pub fn writeln(&mut self, text: &str) {
+        self.write(text, Style::Plain);
+        self.write("\n", Style::Plain);
+    }
+
+    /// Append a value, pretty printed as the prompt does.
+    pub fn node(&mut self, node: &NodeTree) {
+        print::pretty_format(
+            node,
+            &print::NodePrinterSettings {
+                max_line_length: 120,
+                indent_step: 4,
+            },
+            self,
+        )
+    }
+
+    /// Report an error, appending its message as a line.
+    pub fn error(&mut self, error: CommandError) {
+        let message = match &error {
+            CommandError::Climb(err) => format!("{:?}", err),
+            CommandError::Usage(message) => message.clone(),
+        };
+        self.write(&message, Style::Error);
+        self.write("\n", Style::Plain);
+        self.errors.push(error);
+    }
+
+    /// The text without styles.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// The text colored using ANSI escape codes, as printed by the prompt.
+    pub fn to_ansi(&self) -> String {
+        to_ansi(&self.spans)
+    }
+}
+
+/// The completions of an edited line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Completions {
+    /// The position in the line from which the candidates replace its text.
+    pub start: usize,
+    pub candidates: Vec<String>,
+}
+
+/// A hint about an edited line, to be shown after the cursor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hint {
+    /// The rest of the only possible completion.
+    Completion(String),
+
+    /// A description of what is being typed, such as the signature of the function being
+    /// called.
+    Info(String),
+}
+
+/// Evaluates prompt lines on a root, using the built-in commands and those added by the
+/// application.
+pub struct Session {
+    commands: Commands,
+}
+
+impl Session {
+    /// A session on the roots of the registries, having the commands registered via
+    /// `SendRegistry::insert_command` by the time it is created.
+    pub fn new() -> Self {
+        Self::for_prompt(OnExit::EndPrompt)
+    }
+
+    /// A session like `new` gives, for a prompt taking the given action on `:exit`.
+    pub(crate) fn for_prompt(on_exit: OnExit) -> Self {
+        Session {
+            commands: Commands::new(on_exit),
+        }
+    }
+
+    /// A session on the root lent by `with_root` to the callback given to it, having only the
+    /// built-in commands.
+    ///
+    /// ```ignore
+    /// let root = Mutex::new(root_send);
+    /// let session = Session::with_root(move |f| f(&mut root.lock().unwrap().as_root()));
+    /// ```
+    pub fn with_root<F>(with_root: F) -> Self
+    where
+        F: Fn(&mut dyn FnMut(&mut Root)) + 'static,
+    {
+        Session {
+            commands: Commands::builtin(Box::new(with_root)),
+        }
+    }
+
+    /// Add a command to the session, replacing any command having the same name.
+    pub fn insert_command(&mut self, command: Box<dyn Command + Send + Sync>) {
+        self.commands.insert(Arc::from(command));
+    }
+
+    /// Evaluate a line, being either a command such as `:help` or an expression.
+    pub fn eval(&self, line: &str) -> Output {
+        let mut output = Output::new();
+        self.commands.handle_cmd(line, &mut output);
+        output
+    }
+
+    /// The completions of the line at the given cursor position.
+    pub fn complete(&self, line: &str, pos: usize) -> Completions {
+        let pos = char_boundary(line, pos);
+        let (valid, _, _, options) = self.commands.get_next_options(line, pos).dismantle();
+        let (start, candidates) = options.into_position(valid);
+        Completions { start, candidates }
+    }
+
+    /// A hint about the line at the given cursor position, if there is one.
+    pub fn hint(&self, line: &str, pos: usize) -> Option<Hint> {
+        let pos = char_boundary(line, pos);
+        let (valid, _, _, options) = self.commands.get_next_options(line, pos).dismantle();
+        let (from_pos, v) = options.into_position(valid);
+        if v.len() == 1 {
+            if from_pos < pos {
+                let typed = char_boundary(&v[0], pos - from_pos);
+                Some(Hint::Completion(v[0][typed..].to_owned()))
+            } else {
+                Some(Hint::Completion(v[0].to_owned()))
+            }
+        } else if pos == line.len() {
+            self.commands.get_hint(line).map(Hint::Info)
+        } else {
+            None
+        }
+    }
+
+    /// Split the line into spans by how valid its parts are, for the cursor at the given
+    /// position.
+    pub fn highlight(&self, line: &str, pos: usize) -> Vec<Span> {
+        let pos = char_boundary(line, pos);
+        let (valid, pending, pending_valid, _) =
+            self.commands.get_next_options(line, pos).dismantle();
+        let yellow_cutoff = char_boundary(line, valid);
+        let green_cutoff = char_boundary(line, valid + pending - pending_valid);
+        let red_cutoff = char_boundary(line, valid + pending);
+
+        let mut output = Output::new();
+        output.write(&line[..yellow_cutoff], Style::Plain);
+        output.write(&line[yellow_cutoff..green_cutoff], Style::Pending);
+        output.write(&line[green_cutoff..red_cutoff], Style::PendingValid);
+        output.write(&line[red_cutoff..], Style::Invalid);
+        output.spans
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clamp a position in the text to its length, rounding it down to the start of a character.
+pub(crate) fn char_boundary(text: &str, pos: usize) -> usize {
+    let mut pos = std::cmp::min(pos, text.len());
+    while !text.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
@@ -0,0 +1,142 @@
+//! Interrupting the evaluation of a prompt line on Ctrl-C.
+//!
+//! While a line is being edited, the terminal is in raw mode and Ctrl-C reaches the line editor
+//! as a key. While a line is being evaluated, Ctrl-C raises `SIGINT` instead, which by default
+//! terminates the process.
+//!
+//! The handler of `SIGINT` is process-wide, so it is installed once for as long as a prompt runs,
+//! including prompts running on threads of a program having its own handler. It interrupts the
+//! evaluation in progress, and then passes the signal on to the handler it replaced. The default
+//! handling, which terminates the process, only takes place when no line is being evaluated. The
+//! replaced handler is restored once the last running prompt ends.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use interact::Interrupt;
+
+lazy_static! {
+    static ref INTERRUPT: Interrupt = Interrupt::new();
+}
+
+/// The number of lines being evaluated.
+static EVALUATING: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) use self::imp::Guard;
+
+/// Run `f` as the evaluation of a line, interrupting the evaluations it makes on Ctrl-C while a
+/// `Guard` is installed.
+pub(crate) fn interruptible<R, F: FnOnce() -> R>(f: F) -> R {
+    struct Evaluating;
+
+    impl Drop for Evaluating {
+        fn drop(&mut self) {
+            EVALUATING.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    let interrupt: &Interrupt = &INTERRUPT;
+    EVALUATING.fetch_add(1, Ordering::SeqCst);
+    let _evaluating = Evaluating;
+    interrupt.scope(f)
+}
+
+#[cfg(unix)]
+mod imp {
+    use std::mem;
+    use std::ptr;
+    use std::sync::atomic::{AtomicPtr, Ordering};
+    use std::sync::Mutex;
+
+    use super::{EVALUATING, INTERRUPT};
+
+    /// The number of prompts running, which share the installed handler.
+    static PROMPTS: Mutex<usize> = Mutex::new(0);
+
+    /// The handler that was replaced by `on_sigint`. It is never freed, as a signal arriving while
+    /// it is restored may still be reading it.
+    static PREV: AtomicPtr<libc::sigaction> = AtomicPtr::new(ptr::null_mut());
+
+    type SigInfoHandler = extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void);
+    type SigHandler = extern "C" fn(libc::c_int);
+
+    extern "C" fn on_sigint(
+        signum: libc::c_int,
+        info: *mut libc::siginfo_t,
+        context: *mut libc::c_void,
+    ) {
+        let evaluating = EVALUATING.load(Ordering::SeqCst) > 0;
+        if evaluating {
+            INTERRUPT.interrupt();
+        }
+
+        let prev = PREV.load(Ordering::SeqCst);
+        if prev.is_null() {
+            return;
+        }
+
+        unsafe {
+            let prev = &*prev;
+            if prev.sa_flags & libc::SA_SIGINFO != 0 {
+                let handler: SigInfoHandler = mem::transmute(prev.sa_sigaction);
+                handler(signum, info, context);
+            } else if prev.sa_sigaction == libc::SIG_DFL {
+                if !evaluating {
+                    // Terminate the process as if there was no handler, once this one returns.
+                    libc::signal(signum, libc::SIG_DFL);
+                    libc::raise(signum);
+                }
+            } else if prev.sa_sigaction != libc::SIG_IGN {
+                let handler: SigHandler = mem::transmute(prev.sa_sigaction);
+                handler(signum);
+            }
+        }
+    }
+
+    /// Keeps the handler of `SIGINT` installed for as long as a prompt runs.
+    pub struct Guard(());
+
+    impl Guard {
+        pub fn install() -> Self {
+            let mut prompts = PROMPTS.lock().unwrap();
+            if *prompts == 0 {
+                unsafe {
+                    let mut action: libc::sigaction = mem::zeroed();
+                    action.sa_sigaction = on_sigint as *const () as libc::sighandler_t;
+                    action.sa_flags = libc::SA_RESTART | libc::SA_SIGINFO;
+                    libc::sigemptyset(&mut action.sa_mask);
+
+                    let prev = Box::into_raw(Box::new(mem::zeroed::<libc::sigaction>()));
+                    libc::sigaction(libc::SIGINT, ptr::null(), prev);
+                    PREV.store(prev, Ordering::SeqCst);
+                    libc::sigaction(libc::SIGINT, &action, ptr::null_mut());
+                }
+            }
+            *prompts += 1;
+            Guard(())
+        }
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let mut prompts = PROMPTS.lock().unwrap();
+            *prompts -= 1;
+            if *prompts == 0 {
+                let prev = PREV.load(Ordering::SeqCst);
+                unsafe {
+                    libc::sigaction(libc::SIGINT, prev, ptr::null_mut());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod imp {
+    pub struct Guard(());
+
+    impl Guard {
+        pub fn install() -> Self {
+            Guard(())
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Tests of `Session`, driving the prompt without a terminal.
+
+extern crate interact;
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use interact::{
+    Access, Assist, ClimbError, ImmutAccess, Interact, Interrupt, MutAccess, Reflect,
+    ReflectIndirect, ReflectMut, Root, RootSend,
+};
+use interact_prompt::session::{Completions, Hint, Span, Style};
+use interact_prompt::{Command, CommandError, Commands, Output, Session};
+
+#[derive(Interact)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+type PendingAccess = Box<dyn FnMut(&dyn Access) + Send>;
+
+/// A value whose owner never gets to serve accesses to it.
+#[derive(Default)]
+struct Stalled {
+    pending: Mutex<Vec<PendingAccess>>,
+}
+
+impl ReflectIndirect for Stalled {
+    fn indirect(&self, fnc: Box<dyn FnMut(&dyn Access) + Send>) {
+        self.pending.lock().unwrap().push(fnc);
+    }
+
+    fn indirect_mut(&mut self, _fnc: Box<dyn FnMut(&mut dyn Access) + Send>) {
+        unreachable!()
+    }
+}
+
+impl Access for Stalled {
+    fn immut_access(&self) -> ImmutAccess<'_> {
+        ImmutAccess::no_funcs(Reflect::Indirect(self))
+    }
+
+    fn mut_access(&mut self) -> MutAccess<'_> {
+        MutAccess::no_funcs(ReflectMut::Indirect(self))
+    }
+}
+
+struct Sum;
+
+impl Command for Sum {
+    fn handle(&self, _commands: &Commands, root: &mut Root, params: Vec<String>, out: &mut Output) {
+        let expr = params.join(" ");
+        let x = root.access(&format!("{}.x", expr)).0;
+        let y = root.access(&format!("{}.y", expr)).0;
+
+        match (x, y) {
+            (Ok(x), Ok(y)) => out.writeln(&format!("{} + {}", x.info, y.info)),
+            (Err(err), _) | (_, Err(err)) => out.error(CommandError::Climb(err)),
+        }
+    }
+
+    fn help(&self) -> &'static [&'static str] {
+        &[":sum <expr>     Prints the sum of the coordinates of a point"]
+    }
+
+    fn name(&self) -> &'static str {
+        ":sum"
+    }
+
+    fn get_completions(&self, root: &mut Root, line: &str) -> Assist<String> {
+        root.probe(line).1
+    }
+}
+
+fn session() -> Session {
+    let mut root = RootSend::new();
+    root.insert("point", Box::new(Point { x: 3, y: 4 }));
+    root.insert("stalled", Box::new(Stalled::default()));
+
+    let root = Mutex::new(root);
+    let mut session = Session::with_root(move |f| f(&mut root.lock().unwrap().as_root()));
+    session.insert_command(Box::new(Sum));
+    session
+}
+
+#[test]
+fn eval() {
+    let session = session();
+
+    let output = session.eval("point");
+    assert_eq!(output.text(), "Point { x: 3, y: 4 }\n");
+    assert!(output.errors.is_empty());
+    assert_eq!(
+        output.spans[..2],
+        [
+            Span {
+                text: String::from("Point "),
+                style: Style::Plain,
+            },
+            Span {
+                text: String::from("{"),
+                style: Style::Punctuation,
+            },
+        ]
+    );
+
+    let output = session.eval("point.z");
+    assert_eq!(output.text(), "UnexpectedToken\n");
+    match output.errors.as_slice() {
+        [CommandError::Climb(ClimbError::UnexpectedToken)] => {}
+        errors => panic!("unexpected errors: {:?}", errors),
+    }
+
+    assert!(session.eval(":exit").exit);
+    assert!(!session.eval("point").exit);
+}
+
+#[test]
+fn commands() {
+    let session = session();
+
+    assert_eq!(session.eval(":sum point").text(), "3 + 4\n");
+    assert!(session.eval(":help").text().contains(":sum <expr>"));
+    assert_eq!(
+        session.complete(":sum poi", 8),
+        Completions {
+            start: 5,
+            candidates: vec![String::from("point")],
+        }
+    );
+}
+
+#[test]
+fn assist() {
+    let session = session();
+
+    assert_eq!(
+        session.complete("point.", 6),
+        Completions {
+            start: 6,
+            candidates: vec![String::from("x"), String::from("y")],
+        }
+    );
+    assert_eq!(
+        session.hint("poin", 4),
+        Some(Hint::Completion(String::from("t")))
+    );
+    assert_eq!(
+        session.highlight("point.z", 7),
+        [
+            Span {
+                text: String::from("point"),
+                style: Style::Plain,
+            },
+            Span {
+                text: String::from("."),
+                style: Style::Pending,
+            },
+            Span {
+                text: String::from("z"),
+                style: Style::Invalid,
+            },
+        ]
+    );
+}
+
+#[test]
+fn cursor_positions() {
+    let session = session();
+
+    // Positions past the end or inside a character are moved back to a character boundary.
+    assert_eq!(session.complete("é", 99), session.complete("é", 2));
+    assert_eq!(session.hint("pé", 2), session.hint("pé", 1));
+    assert_eq!(session.highlight("poïnt", 3), session.highlight("poïnt", 2));
+    assert_eq!(
+        session.highlight("point", 99),
+        session.highlight("point", 5)
+    );
+}
+
+#[test]
+fn interrupt() {
+    let session = session();
+
+    let interrupt = Interrupt::new();
+    let handle = interrupt.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        handle.interrupt();
+    });
+
+    let output = interrupt.scope(|| session.eval("stalled"));
+    assert_eq!(output.text(), "Interrupted\n");
+    match output.errors.as_slice() {
+        [CommandError::Climb(ClimbError::Interrupted)] => {}
+        errors => panic!("unexpected errors: {:?}", errors),
+    }
+
+    // A new scope clears the interruption.
+    assert_eq!(
+        interrupt.scope(|| session.eval("point")).text(),
+        "Point { x: 3, y: 4 }\n"
+    );
+}